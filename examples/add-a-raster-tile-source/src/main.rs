@@ -1,4 +1,7 @@
-use maplibre_gl_js::interface::MapOptions;
+use maplibre_gl_js::interface::{
+    MapOptions, layers::RasterLayerSpecification, sources::RasterSourceSpecification,
+    style::StyleSpecification,
+};
 use yew::{Html, function_component, html, use_effect, use_state};
 
 #[function_component(App)]
@@ -9,27 +12,23 @@ fn app() -> Html {
             return;
         }
         MapOptions::new("map")
-            .with_style(serde_json::json!({
-                "version": 8,
-                "sources": {
-                    "raster-tiles": {
-                        "type": "raster",
-                        "tiles": ["https://tile.openstreetmap.org/{z}/{x}/{y}.png"],
-                        "tileSize": 256,
-                        "minzoom": 0,
-                        "maxzoom": 19
-                    }
-                },
-                "layers": [
-                    {
-                        "id": "simple-tiles",
-                        "type": "raster",
-                        "source": "raster-tiles",
-                        "attribution": "© OpenStreetMap contributors",
-                    }
-                ],
-                "id": "blank"
-            }))
+            .with_style(
+                StyleSpecification::new()
+                    .with_source(
+                        "raster-tiles",
+                        RasterSourceSpecification::from_tiles([
+                            "https://tile.openstreetmap.org/{z}/{x}/{y}.png",
+                        ])
+                        .with_tile_size(256)
+                        .with_minzoom(0)
+                        .with_maxzoom(19)
+                        .with_attribution("© OpenStreetMap contributors"),
+                    )
+                    .with_layer(RasterLayerSpecification::new(
+                        "simple-tiles",
+                        "raster-tiles",
+                    )),
+            )
             .with_center([0., 0.])
             .with_zoom(0.)
             .build()
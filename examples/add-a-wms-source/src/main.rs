@@ -1,4 +1,7 @@
-use maplibre_gl_js::interface::MapOptions;
+use maplibre_gl_js::interface::{
+    MapOptions, layers::RasterLayerSpecification, sources::RasterSourceSpecification,
+    style::StyleSpecification,
+};
 use yew::{Html, function_component, html, use_effect, use_state};
 
 #[function_component(App)]
@@ -9,26 +12,22 @@ fn app() -> Html {
             return;
         }
         MapOptions::new("map")
-            .with_style(serde_json::json!({
-                "version": 8,
-                "sources": {
-                    "wms-test-source": {
-                        "type": "raster",
+            .with_style(
+                StyleSpecification::new()
+                    .with_source(
+                        "wms-test-source",
                         // use the tiles option to specify a WMS tile source URL
                         // https://maplibre.org/maplibre-style-spec/sources/
-                        "tiles": [
-                            "https://ows.terrestris.de/osm/service?service=WMS&request=GetMap&version=1.1.1&layers=TOPO-WMS%2COSM-Overlay-WMS&styles=&format=image%2Fpng&transparent=true&info_format=text%2Fhtml&tiled=false&srs=EPSG:3857&bbox={bbox-epsg-3857}&width=256&height=256"
-                        ],
-                        "tileSize": 256
-                    }
-                },
-                "layers": [{
-                    "id": "wms-test-layer",
-                    "type": "raster",
-                    "source": "wms-test-source",
-                    "paint": {}
-                }]
-            }))
+                        RasterSourceSpecification::from_tiles([
+                            "https://ows.terrestris.de/osm/service?service=WMS&request=GetMap&version=1.1.1&layers=TOPO-WMS%2COSM-Overlay-WMS&styles=&format=image%2Fpng&transparent=true&info_format=text%2Fhtml&tiled=false&srs=EPSG:3857&bbox={bbox-epsg-3857}&width=256&height=256",
+                        ])
+                        .with_tile_size(256),
+                    )
+                    .with_layer(RasterLayerSpecification::new(
+                        "wms-test-layer",
+                        "wms-test-source",
+                    )),
+            )
             .with_center([-74.5447, 40.6892])
             .with_zoom(8.)
             .build()
@@ -7,7 +7,7 @@ fn is_webgl_supported() -> bool {
     let document = window.document().expect("The document should be available");
 
     // Check if the class is directly missing
-    if let Err(_) = Reflect::get(&window, &"WebGLRenderingContext".into()) {
+    if Reflect::get(&window, &"WebGLRenderingContext".into()).is_err() {
         return false;
     }
 
@@ -0,0 +1,41 @@
+//! # Bindings to the `CameraOptions` and related camera transition JS objects from MapLibre
+
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    /// The options to position the camera of a `Map`
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/type-aliases/CameraOptions/>
+    #[wasm_bindgen(js_namespace = maplibregl)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub type CameraOptions;
+
+    /// The options common to all the animated camera transitions of a `Map`
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/type-aliases/AnimationOptions/>
+    #[wasm_bindgen(js_namespace = maplibregl)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub type AnimationOptions;
+
+    /// The options for an animated transition with easing of a `Map` camera
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/type-aliases/EaseToOptions/>
+    #[wasm_bindgen(js_namespace = maplibregl)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub type EaseToOptions;
+
+    /// The options for a flying animated transition of a `Map` camera
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/type-aliases/FlyToOptions/>
+    #[wasm_bindgen(js_namespace = maplibregl)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub type FlyToOptions;
+
+    /// The options for a transition of a `Map` camera to fit a bounding box
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/type-aliases/FitBoundsOptions/>
+    #[wasm_bindgen(js_namespace = maplibregl)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub type FitBoundsOptions;
+}
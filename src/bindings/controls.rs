@@ -0,0 +1,142 @@
+//! # Bindings to the built-in controls from MapLibre
+
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    /// `IControl` interface implemented by every control which can be added
+    /// to a map
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/interfaces/IControl/>
+    #[derive(Debug, Clone, PartialEq)]
+    pub type IControl;
+
+    /// `NavigationControl` object containing zoom buttons and a compass
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/NavigationControl/>
+    #[wasm_bindgen(js_namespace = maplibregl, extends = IControl)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub type NavigationControl;
+
+    /// Create a `NavigationControl` object calling its constructor with the
+    /// given options
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/NavigationControl/#constructor>
+    #[wasm_bindgen(constructor, js_namespace = maplibregl, catch)]
+    pub fn new(options: &JsValue) -> Result<NavigationControl, JsValue>;
+
+    /// `GeolocateControl` object containing a button that locates the user on
+    /// the map
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/GeolocateControl/>
+    #[wasm_bindgen(js_namespace = maplibregl, extends = IControl)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub type GeolocateControl;
+
+    /// Create a `GeolocateControl` object calling its constructor with the
+    /// given options
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/GeolocateControl/#constructor>
+    #[wasm_bindgen(constructor, js_namespace = maplibregl, catch)]
+    pub fn new(options: &JsValue) -> Result<GeolocateControl, JsValue>;
+
+    /// Programmatically requests and moves the map to the user's location,
+    /// returning `false` if the control hasn't been added to a map yet
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/GeolocateControl/#trigger>
+    #[wasm_bindgen(method)]
+    pub fn trigger(this: &GeolocateControl) -> bool;
+
+    /// `AttributionControl` object presenting the attribution of the map
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/AttributionControl/>
+    #[wasm_bindgen(js_namespace = maplibregl, extends = IControl)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub type AttributionControl;
+
+    /// Create an `AttributionControl` object calling its constructor with the
+    /// given options
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/AttributionControl/#constructor>
+    #[wasm_bindgen(constructor, js_namespace = maplibregl, catch)]
+    pub fn new(options: &JsValue) -> Result<AttributionControl, JsValue>;
+
+    /// `ScaleControl` object displaying the ratio of a distance on the map to
+    /// the corresponding distance on the ground
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/ScaleControl/>
+    #[wasm_bindgen(js_namespace = maplibregl, extends = IControl)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub type ScaleControl;
+
+    /// Create a `ScaleControl` object calling its constructor with the given
+    /// options
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/ScaleControl/#constructor>
+    #[wasm_bindgen(constructor, js_namespace = maplibregl, catch)]
+    pub fn new(options: &JsValue) -> Result<ScaleControl, JsValue>;
+
+    /// Sets the unit of the distance displayed by the scale
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/ScaleControl/#setunit>
+    #[wasm_bindgen(method, js_name=setUnit)]
+    pub fn set_unit(this: &ScaleControl, unit: &str);
+
+    /// `FullscreenControl` object containing a button to toggle the map in
+    /// and out of fullscreen mode
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/FullscreenControl/>
+    #[wasm_bindgen(js_namespace = maplibregl, extends = IControl)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub type FullscreenControl;
+
+    /// Create a `FullscreenControl` object calling its constructor with the
+    /// given options
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/FullscreenControl/#constructor>
+    #[wasm_bindgen(constructor, js_namespace = maplibregl, catch)]
+    pub fn new(options: &JsValue) -> Result<FullscreenControl, JsValue>;
+
+    /// `TerrainControl` object containing a button to turn the terrain on and
+    /// off
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/TerrainControl/>
+    #[wasm_bindgen(js_namespace = maplibregl, extends = IControl)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub type TerrainControl;
+
+    /// Create a `TerrainControl` object calling its constructor with the
+    /// given terrain specification
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/TerrainControl/#constructor>
+    #[wasm_bindgen(constructor, js_namespace = maplibregl, catch)]
+    pub fn new(options: &JsValue) -> Result<TerrainControl, JsValue>;
+
+    /// `GlobeControl` object containing a button to toggle the map projection
+    /// between mercator and globe
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/GlobeControl/>
+    #[wasm_bindgen(js_namespace = maplibregl, extends = IControl)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub type GlobeControl;
+
+    /// Create a `GlobeControl` object calling its constructor
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/GlobeControl/#constructor>
+    #[wasm_bindgen(constructor, js_namespace = maplibregl, catch)]
+    pub fn new() -> Result<GlobeControl, JsValue>;
+
+    /// `LogoControl` object displaying the MapLibre logo
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/LogoControl/>
+    #[wasm_bindgen(js_namespace = maplibregl, extends = IControl)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub type LogoControl;
+
+    /// Create a `LogoControl` object calling its constructor with the given
+    /// options
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/LogoControl/#constructor>
+    #[wasm_bindgen(constructor, js_namespace = maplibregl, catch)]
+    pub fn new(options: &JsValue) -> Result<LogoControl, JsValue>;
+}
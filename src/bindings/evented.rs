@@ -0,0 +1,40 @@
+//! # Bindings to the `Evented` JS object from MapLibre
+
+use wasm_bindgen::prelude::*;
+use web_sys::js_sys::Function;
+
+#[wasm_bindgen]
+extern "C" {
+    /// `Evented` object representing anything that fires events, such as a
+    /// `Marker` or a `Popup`
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Evented/>
+    #[wasm_bindgen(js_namespace = maplibregl)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub type Evented;
+
+    /// Adds a listener for events of the given type
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Evented/#on>
+    #[wasm_bindgen(method, js_name=on)]
+    pub fn on(this: &Evented, r#type: &str, listener: &Function);
+
+    /// Adds a listener that will be called only once for events of the given
+    /// type
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Evented/#once>
+    #[wasm_bindgen(method, js_name=once)]
+    pub fn once(this: &Evented, r#type: &str, listener: &Function);
+
+    /// Removes a listener previously added with `on` or `once`
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Evented/#off>
+    #[wasm_bindgen(method, js_name=off)]
+    pub fn off(this: &Evented, r#type: &str, listener: &Function);
+
+    /// Fires an event of the given type with the given properties
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Evented/#fire>
+    #[wasm_bindgen(method)]
+    pub fn fire(this: &Evented, r#type: &str, properties: &JsValue) -> Evented;
+}
@@ -0,0 +1,227 @@
+//! # Bindings to the event objects fired by the `Map` of MapLibre
+
+use wasm_bindgen::prelude::*;
+use web_sys::{Event, MouseEvent, TouchEvent, WheelEvent, js_sys::Error};
+
+use super::{LngLat, Point};
+
+#[wasm_bindgen]
+extern "C" {
+    /// `MapLibreEvent` object representing a generic event fired by the map
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/type-aliases/MapLibreEvent/>
+    #[wasm_bindgen(js_namespace = maplibregl)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub type MapLibreEvent;
+
+    /// Get the `type` property of `MapLibreEvent`
+    #[wasm_bindgen(method, getter, js_name=type)]
+    pub fn event_type(this: &MapLibreEvent) -> String;
+
+    /// Get the `originalEvent` property of `MapLibreEvent`, the DOM event
+    /// which caused the map event, if any
+    #[wasm_bindgen(method, getter, js_name=originalEvent)]
+    pub fn original_event(this: &MapLibreEvent) -> Option<Event>;
+
+    /// `MapMouseEvent` object representing a mouse related event fired by the
+    /// map
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/MapMouseEvent/>
+    #[wasm_bindgen(js_namespace = maplibregl)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub type MapMouseEvent;
+
+    /// Get the `type` property of `MapMouseEvent`
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/MapMouseEvent/#type>
+    #[wasm_bindgen(method, getter, js_name=type)]
+    pub fn event_type(this: &MapMouseEvent) -> String;
+
+    /// Get the `originalEvent` property of `MapMouseEvent`
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/MapMouseEvent/#originalevent>
+    #[wasm_bindgen(method, getter, js_name=originalEvent)]
+    pub fn original_event(this: &MapMouseEvent) -> MouseEvent;
+
+    /// Get the `point` property of `MapMouseEvent`
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/MapMouseEvent/#point>
+    #[wasm_bindgen(method, getter)]
+    pub fn point(this: &MapMouseEvent) -> Point;
+
+    /// Get the `lngLat` property of `MapMouseEvent`
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/MapMouseEvent/#lnglat>
+    #[wasm_bindgen(method, getter, js_name=lngLat)]
+    pub fn lng_lat(this: &MapMouseEvent) -> LngLat;
+
+    /// Prevents the subsequent default processing of the event by the map
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/MapMouseEvent/#preventdefault>
+    #[wasm_bindgen(method, js_name=preventDefault)]
+    pub fn prevent_default(this: &MapMouseEvent);
+
+    /// Get the `defaultPrevented` property of `MapMouseEvent`
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/MapMouseEvent/#defaultprevented>
+    #[wasm_bindgen(method, getter, js_name=defaultPrevented)]
+    pub fn default_prevented(this: &MapMouseEvent) -> bool;
+
+    /// `MapTouchEvent` object representing a touch related event fired by the
+    /// map
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/MapTouchEvent/>
+    #[wasm_bindgen(js_namespace = maplibregl)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub type MapTouchEvent;
+
+    /// Get the `type` property of `MapTouchEvent`
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/MapTouchEvent/#type>
+    #[wasm_bindgen(method, getter, js_name=type)]
+    pub fn event_type(this: &MapTouchEvent) -> String;
+
+    /// Get the `originalEvent` property of `MapTouchEvent`
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/MapTouchEvent/#originalevent>
+    #[wasm_bindgen(method, getter, js_name=originalEvent)]
+    pub fn original_event(this: &MapTouchEvent) -> TouchEvent;
+
+    /// Get the `point` property of `MapTouchEvent`, the center of the touch
+    /// points
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/MapTouchEvent/#point>
+    #[wasm_bindgen(method, getter)]
+    pub fn point(this: &MapTouchEvent) -> Point;
+
+    /// Get the `lngLat` property of `MapTouchEvent`, the center of the touch
+    /// points
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/MapTouchEvent/#lnglat>
+    #[wasm_bindgen(method, getter, js_name=lngLat)]
+    pub fn lng_lat(this: &MapTouchEvent) -> LngLat;
+
+    /// Get the `points` property of `MapTouchEvent`
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/MapTouchEvent/#points>
+    #[wasm_bindgen(method, getter)]
+    pub fn points(this: &MapTouchEvent) -> Vec<Point>;
+
+    /// Get the `lngLats` property of `MapTouchEvent`
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/MapTouchEvent/#lnglats>
+    #[wasm_bindgen(method, getter, js_name=lngLats)]
+    pub fn lng_lats(this: &MapTouchEvent) -> Vec<LngLat>;
+
+    /// Prevents the subsequent default processing of the event by the map
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/MapTouchEvent/#preventdefault>
+    #[wasm_bindgen(method, js_name=preventDefault)]
+    pub fn prevent_default(this: &MapTouchEvent);
+
+    /// Get the `defaultPrevented` property of `MapTouchEvent`
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/MapTouchEvent/#defaultprevented>
+    #[wasm_bindgen(method, getter, js_name=defaultPrevented)]
+    pub fn default_prevented(this: &MapTouchEvent) -> bool;
+
+    /// `MapWheelEvent` object representing a wheel event fired by the map
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/MapWheelEvent/>
+    #[wasm_bindgen(js_namespace = maplibregl)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub type MapWheelEvent;
+
+    /// Get the `type` property of `MapWheelEvent`
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/MapWheelEvent/#type>
+    #[wasm_bindgen(method, getter, js_name=type)]
+    pub fn event_type(this: &MapWheelEvent) -> String;
+
+    /// Get the `originalEvent` property of `MapWheelEvent`
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/MapWheelEvent/#originalevent>
+    #[wasm_bindgen(method, getter, js_name=originalEvent)]
+    pub fn original_event(this: &MapWheelEvent) -> WheelEvent;
+
+    /// Prevents the subsequent default processing of the event by the map
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/MapWheelEvent/#preventdefault>
+    #[wasm_bindgen(method, js_name=preventDefault)]
+    pub fn prevent_default(this: &MapWheelEvent);
+
+    /// Get the `defaultPrevented` property of `MapWheelEvent`
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/MapWheelEvent/#defaultprevented>
+    #[wasm_bindgen(method, getter, js_name=defaultPrevented)]
+    pub fn default_prevented(this: &MapWheelEvent) -> bool;
+
+    /// `MapLibreZoomEvent` object representing a box zoom event fired by the
+    /// map
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/type-aliases/MapLibreZoomEvent/>
+    #[wasm_bindgen(js_namespace = maplibregl)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub type MapLibreZoomEvent;
+
+    /// Get the `type` property of `MapLibreZoomEvent`
+    #[wasm_bindgen(method, getter, js_name=type)]
+    pub fn event_type(this: &MapLibreZoomEvent) -> String;
+
+    /// Get the `originalEvent` property of `MapLibreZoomEvent`
+    #[wasm_bindgen(method, getter, js_name=originalEvent)]
+    pub fn original_event(this: &MapLibreZoomEvent) -> MouseEvent;
+
+    /// `MapDataEvent` object representing an event fired by the map when the
+    /// map, its style or one of its sources loads or changes
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/type-aliases/MapDataEvent/>
+    #[wasm_bindgen(js_namespace = maplibregl)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub type MapDataEvent;
+
+    /// Get the `type` property of `MapDataEvent`
+    #[wasm_bindgen(method, getter, js_name=type)]
+    pub fn event_type(this: &MapDataEvent) -> String;
+
+    /// Get the `dataType` property of `MapDataEvent`, either `source` or
+    /// `style`
+    #[wasm_bindgen(method, getter, js_name=dataType)]
+    pub fn data_type(this: &MapDataEvent) -> String;
+
+    /// Get the `isSourceLoaded` property of `MapDataEvent`, only present on
+    /// source events
+    #[wasm_bindgen(method, getter, js_name=isSourceLoaded)]
+    pub fn is_source_loaded(this: &MapDataEvent) -> Option<bool>;
+
+    /// Get the `sourceId` property of `MapDataEvent`, only present on source
+    /// events
+    #[wasm_bindgen(method, getter, js_name=sourceId)]
+    pub fn source_id(this: &MapDataEvent) -> Option<String>;
+
+    /// Get the `sourceDataType` property of `MapDataEvent`, only present on
+    /// source events
+    #[wasm_bindgen(method, getter, js_name=sourceDataType)]
+    pub fn source_data_type(this: &MapDataEvent) -> Option<String>;
+
+    /// `ErrorEvent` object representing an error fired by the map, such as a
+    /// style or a resource failing to load
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/type-aliases/ErrorEvent/>
+    #[wasm_bindgen(js_namespace = maplibregl)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub type ErrorEvent;
+
+    /// Get the `type` property of `ErrorEvent`
+    #[wasm_bindgen(method, getter, js_name=type)]
+    pub fn event_type(this: &ErrorEvent) -> String;
+
+    /// Get the `error` property of `ErrorEvent`
+    #[wasm_bindgen(method, getter)]
+    pub fn error(this: &ErrorEvent) -> Error;
+
+    /// Get the `sourceId` property of `ErrorEvent`, set when the error was
+    /// raised by a source, for example when one of its tiles failed to load
+    #[wasm_bindgen(method, getter, js_name=sourceId)]
+    pub fn source_id(this: &ErrorEvent) -> Option<String>;
+}
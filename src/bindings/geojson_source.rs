@@ -0,0 +1,60 @@
+//! # Bindings to the `GeoJSONSource` JS object from MapLibre
+
+use wasm_bindgen::prelude::*;
+use web_sys::js_sys::Promise;
+
+use super::Source;
+
+#[wasm_bindgen]
+extern "C" {
+    /// `GeoJSONSource` object representing a source of GeoJSON data
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/GeoJSONSource/>
+    #[wasm_bindgen(js_namespace = maplibregl, extends = Source)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub type GeoJSONSource;
+
+    /// Sets the GeoJSON data of the source, either a GeoJSON object or the URL of one
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/GeoJSONSource/#setdata>
+    #[wasm_bindgen(method, catch, js_name=setData)]
+    pub fn set_data(this: &GeoJSONSource, data: &JsValue) -> Result<(), JsValue>;
+
+    /// Updates the GeoJSON data of the source with a diff adding, removing and updating features
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/GeoJSONSource/#updatedata>
+    #[wasm_bindgen(method, catch, js_name=updateData)]
+    pub fn update_data(this: &GeoJSONSource, diff: &JsValue) -> Result<(), JsValue>;
+
+    /// Returns a promise resolving to the GeoJSON data of the source
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/GeoJSONSource/#getdata>
+    #[wasm_bindgen(method, js_name=getData)]
+    pub fn get_data(this: &GeoJSONSource) -> Promise;
+
+    /// Returns a promise resolving to the zoom level at which the cluster with the given
+    /// identifier expands into several children
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/GeoJSONSource/#getclusterexpansionzoom>
+    #[wasm_bindgen(method, js_name=getClusterExpansionZoom)]
+    pub fn get_cluster_expansion_zoom(this: &GeoJSONSource, cluster_id: f64) -> Promise;
+
+    /// Returns a promise resolving to the children of the cluster with the given identifier on
+    /// the next zoom level, which may be clusters or points
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/GeoJSONSource/#getclusterchildren>
+    #[wasm_bindgen(method, js_name=getClusterChildren)]
+    pub fn get_cluster_children(this: &GeoJSONSource, cluster_id: f64) -> Promise;
+
+    /// Returns a promise resolving to the points of the cluster with the given identifier,
+    /// paginated with `limit` and `offset`
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/GeoJSONSource/#getclusterleaves>
+    #[wasm_bindgen(method, js_name=getClusterLeaves)]
+    pub fn get_cluster_leaves(
+        this: &GeoJSONSource,
+        cluster_id: f64,
+        limit: u32,
+        offset: u32,
+    ) -> Promise;
+}
@@ -0,0 +1,98 @@
+//! # Bindings to the `ImageSource`, `VideoSource` and `CanvasSource` JS objects from MapLibre
+
+use wasm_bindgen::prelude::*;
+use web_sys::{HtmlCanvasElement, HtmlVideoElement};
+
+use super::Source;
+
+#[wasm_bindgen]
+extern "C" {
+    /// `ImageSource` object representing a source of an image placed on four corners
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/ImageSource/>
+    #[wasm_bindgen(js_namespace = maplibregl, extends = Source)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub type ImageSource;
+
+    /// Get the `url` property of `ImageSource`
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/ImageSource/#url>
+    #[wasm_bindgen(method, getter)]
+    pub fn url(this: &ImageSource) -> Option<String>;
+
+    /// Get the `coordinates` property of `ImageSource`, the longitude and latitude of the top
+    /// left, top right, bottom right and bottom left corners
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/ImageSource/#coordinates>
+    #[wasm_bindgen(method, getter)]
+    pub fn coordinates(this: &ImageSource) -> JsValue;
+
+    /// Updates the image URL and, optionally, the coordinates of the corners
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/ImageSource/#updateimage>
+    #[wasm_bindgen(method, catch, js_name=updateImage)]
+    pub fn update_image(this: &ImageSource, options: &JsValue) -> Result<(), JsValue>;
+
+    /// Sets the coordinates of the corners
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/ImageSource/#setcoordinates>
+    #[wasm_bindgen(method, catch, js_name=setCoordinates)]
+    pub fn set_coordinates(this: &ImageSource, coordinates: &JsValue) -> Result<(), JsValue>;
+
+    /// `VideoSource` object representing a source of a video placed on four corners
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/VideoSource/>
+    #[wasm_bindgen(js_namespace = maplibregl, extends = ImageSource, extends = Source)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub type VideoSource;
+
+    /// Plays the video
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/VideoSource/#play>
+    #[wasm_bindgen(method, catch)]
+    pub fn play(this: &VideoSource) -> Result<(), JsValue>;
+
+    /// Pauses the video
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/VideoSource/#pause>
+    #[wasm_bindgen(method, catch)]
+    pub fn pause(this: &VideoSource) -> Result<(), JsValue>;
+
+    /// Sets the playback position of the video to the given time in seconds
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/VideoSource/#seek>
+    #[wasm_bindgen(method, catch)]
+    pub fn seek(this: &VideoSource, seconds: f64) -> Result<(), JsValue>;
+
+    /// Returns the HTML video element, once loaded
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/VideoSource/#getvideo>
+    #[wasm_bindgen(method, js_name=getVideo)]
+    pub fn get_video(this: &VideoSource) -> Option<HtmlVideoElement>;
+
+    /// `CanvasSource` object representing a source of the content of a canvas placed on four
+    /// corners
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/CanvasSource/>
+    #[wasm_bindgen(js_namespace = maplibregl, extends = ImageSource, extends = Source)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub type CanvasSource;
+
+    /// Enables animation, rendering the canvas on every frame
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/CanvasSource/#play>
+    #[wasm_bindgen(method)]
+    pub fn play(this: &CanvasSource);
+
+    /// Disables animation, rendering a static copy of the canvas
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/CanvasSource/#pause>
+    #[wasm_bindgen(method)]
+    pub fn pause(this: &CanvasSource);
+
+    /// Returns the HTML canvas element, once loaded
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/CanvasSource/#getcanvas>
+    #[wasm_bindgen(method, js_name=getCanvas)]
+    pub fn get_canvas(this: &CanvasSource) -> Option<HtmlCanvasElement>;
+}
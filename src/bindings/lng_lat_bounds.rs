@@ -0,0 +1,232 @@
+//! # Bindings to the `LngLatBounds` JS object from MapLibre
+
+use wasm_bindgen::prelude::*;
+
+use super::{LngLat, LngLatBoundsLike, LngLatLike};
+
+#[wasm_bindgen]
+extern "C" {
+    /// `LngLatBounds` object representing a geographical bounding box,
+    /// defined by its southwest and northeast points in longitude and
+    /// latitude
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/LngLatBounds/>
+    #[wasm_bindgen(js_namespace = maplibregl)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub type LngLatBounds;
+
+    /// Create a `LngLatBounds` object calling its constructor with its
+    /// southwest and northeast corners
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/LngLatBounds/#constructor>
+    #[wasm_bindgen(constructor, js_namespace = maplibregl, catch)]
+    pub fn new(sw: &LngLatLike, ne: &LngLatLike) -> Result<LngLatBounds, JsValue>;
+
+    /// Set the northeast corner of the bounding box
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/LngLatBounds/#setNorthEast>
+    #[wasm_bindgen(method, js_name=setNorthEast, catch)]
+    pub fn set_north_east(this: &LngLatBounds, ne: &LngLatLike) -> Result<LngLatBounds, JsValue>;
+
+    /// Set the southwest corner of the bounding box
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/LngLatBounds/#setSouthWest>
+    #[wasm_bindgen(method, js_name=setSouthWest, catch)]
+    pub fn set_south_west(this: &LngLatBounds, sw: &LngLatLike) -> Result<LngLatBounds, JsValue>;
+
+    /// Extend the bounds to include a given point
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/LngLatBounds/#extend>
+    #[wasm_bindgen(method, js_name=extend, catch)]
+    pub fn extend(this: &LngLatBounds, obj: &LngLatLike) -> Result<LngLatBounds, JsValue>;
+
+    /// Extend the bounds to include a given bounding box
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/LngLatBounds/#extend>
+    #[wasm_bindgen(method, js_name=extend, catch)]
+    pub fn extend_bounds(
+        this: &LngLatBounds,
+        obj: &LngLatBoundsLike,
+    ) -> Result<LngLatBounds, JsValue>;
+
+    /// Returns the geographical coordinate equidistant from the bounding
+    /// box's corners
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/LngLatBounds/#getCenter>
+    #[wasm_bindgen(method, js_name=getCenter)]
+    pub fn get_center(this: &LngLatBounds) -> LngLat;
+
+    /// Returns the southwest corner of the bounding box
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/LngLatBounds/#getSouthWest>
+    #[wasm_bindgen(method, js_name=getSouthWest)]
+    pub fn get_south_west(this: &LngLatBounds) -> LngLat;
+
+    /// Returns the northeast corner of the bounding box
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/LngLatBounds/#getNorthEast>
+    #[wasm_bindgen(method, js_name=getNorthEast)]
+    pub fn get_north_east(this: &LngLatBounds) -> LngLat;
+
+    /// Returns the northwest corner of the bounding box
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/LngLatBounds/#getNorthWest>
+    #[wasm_bindgen(method, js_name=getNorthWest)]
+    pub fn get_north_west(this: &LngLatBounds) -> LngLat;
+
+    /// Returns the southeast corner of the bounding box
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/LngLatBounds/#getSouthEast>
+    #[wasm_bindgen(method, js_name=getSouthEast)]
+    pub fn get_south_east(this: &LngLatBounds) -> LngLat;
+
+    /// Returns the west edge of the bounding box
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/LngLatBounds/#getWest>
+    #[wasm_bindgen(method, js_name=getWest)]
+    pub fn get_west(this: &LngLatBounds) -> f64;
+
+    /// Returns the south edge of the bounding box
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/LngLatBounds/#getSouth>
+    #[wasm_bindgen(method, js_name=getSouth)]
+    pub fn get_south(this: &LngLatBounds) -> f64;
+
+    /// Returns the east edge of the bounding box
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/LngLatBounds/#getEast>
+    #[wasm_bindgen(method, js_name=getEast)]
+    pub fn get_east(this: &LngLatBounds) -> f64;
+
+    /// Returns the north edge of the bounding box
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/LngLatBounds/#getNorth>
+    #[wasm_bindgen(method, js_name=getNorth)]
+    pub fn get_north(this: &LngLatBounds) -> f64;
+
+    /// Returns the bounding box represented as an array of the southwest and
+    /// northeast coordinates
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/LngLatBounds/#toArray>
+    #[wasm_bindgen(method, js_name=toArray)]
+    pub fn to_array(this: &LngLatBounds) -> Vec<JsValue>;
+
+    /// Returns the bounding box represented as a string
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/LngLatBounds/#toString>
+    #[wasm_bindgen(method, js_name=toString)]
+    pub fn to_string(this: &LngLatBounds) -> String;
+
+    /// Check if the bounding box is an empty/null-type box
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/LngLatBounds/#isEmpty>
+    #[wasm_bindgen(method, js_name=isEmpty)]
+    pub fn is_empty(this: &LngLatBounds) -> bool;
+
+    /// Check if the point is within the bounding box
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/LngLatBounds/#contains>
+    #[wasm_bindgen(method, js_name=contains)]
+    pub fn contains(this: &LngLatBounds, lnglat: &LngLatLike) -> bool;
+
+    /// Check if the bounding box intersects with another one
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/LngLatBounds/#intersects>
+    #[wasm_bindgen(method, js_name=intersects)]
+    pub fn intersects(this: &LngLatBounds, other: &LngLatBoundsLike) -> bool;
+
+    /// Convert a `LngLatBoundsLike` into a `LngLatBounds`
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/LngLatBounds/#convert>
+    #[wasm_bindgen(js_namespace = maplibregl, js_name=convert, static_method_of=LngLatBounds, catch)]
+    pub fn convert(input: &LngLatBoundsLike) -> Result<LngLatBounds, JsValue>;
+
+    /// Returns a `LngLatBounds` from the coordinates extended by a given
+    /// radius in meters
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/LngLatBounds/#fromLngLat>
+    #[wasm_bindgen(js_namespace = maplibregl, js_name=fromLngLat, static_method_of=LngLatBounds, catch)]
+    pub fn from_lng_lat(center: &LngLatLike, radius: f64) -> Result<LngLatBounds, JsValue>;
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_utils::load_maplibre_gl;
+
+    use super::*;
+    use wasm_bindgen_test::*;
+    use web_sys::js_sys::Array;
+
+    fn lnglatlike(lng: f64, lat: f64) -> LngLatLike {
+        let array = Array::new();
+        array.push(&JsValue::from_f64(lng));
+        array.push(&JsValue::from_f64(lat));
+        LngLatLike::from(JsValue::from(array))
+    }
+
+    #[wasm_bindgen_test]
+    async fn new() {
+        load_maplibre_gl().await;
+        LngLatBounds::new(&lnglatlike(-10., -20.), &lnglatlike(10., 20.))
+            .expect("Creating LngLatBounds should work");
+    }
+
+    #[wasm_bindgen_test]
+    async fn corners() {
+        load_maplibre_gl().await;
+        let bounds = LngLatBounds::new(&lnglatlike(-10., -20.), &lnglatlike(10., 20.))
+            .expect("Creating LngLatBounds should work");
+
+        assert_eq!(bounds.get_south_west().to_string(), "LngLat(-10, -20)");
+        assert_eq!(bounds.get_north_east().to_string(), "LngLat(10, 20)");
+        assert_eq!(bounds.get_north_west().to_string(), "LngLat(-10, 20)");
+        assert_eq!(bounds.get_south_east().to_string(), "LngLat(10, -20)");
+        assert_eq!(bounds.get_center().to_string(), "LngLat(0, 0)");
+    }
+
+    #[wasm_bindgen_test]
+    async fn edges() {
+        load_maplibre_gl().await;
+        let bounds = LngLatBounds::new(&lnglatlike(-10., -20.), &lnglatlike(10., 20.))
+            .expect("Creating LngLatBounds should work");
+
+        assert!((bounds.get_west() + 10.).abs() < 0.01);
+        assert!((bounds.get_south() + 20.).abs() < 0.01);
+        assert!((bounds.get_east() - 10.).abs() < 0.01);
+        assert!((bounds.get_north() - 20.).abs() < 0.01);
+    }
+
+    #[wasm_bindgen_test]
+    async fn extend() {
+        load_maplibre_gl().await;
+        let bounds = LngLatBounds::new(&lnglatlike(-10., -20.), &lnglatlike(10., 20.))
+            .expect("Creating LngLatBounds should work");
+        bounds
+            .extend(&lnglatlike(30., 40.))
+            .expect("Extending should work");
+
+        assert_eq!(bounds.get_north_east().to_string(), "LngLat(30, 40)");
+    }
+
+    #[wasm_bindgen_test]
+    async fn contains() {
+        load_maplibre_gl().await;
+        let bounds = LngLatBounds::new(&lnglatlike(-10., -20.), &lnglatlike(10., 20.))
+            .expect("Creating LngLatBounds should work");
+
+        assert!(bounds.contains(&lnglatlike(0., 0.)));
+        assert!(!bounds.contains(&lnglatlike(30., 0.)));
+    }
+
+    #[wasm_bindgen_test]
+    async fn to_string() {
+        load_maplibre_gl().await;
+        let bounds = LngLatBounds::new(&lnglatlike(-10., -20.), &lnglatlike(10., 20.))
+            .expect("Creating LngLatBounds should work");
+
+        assert_eq!(
+            bounds.to_string(),
+            "LngLatBounds(LngLat(-10, -20), LngLat(10, 20))"
+        );
+    }
+}
@@ -1,8 +1,12 @@
 //! # Bindings to the `Map` JS object from MapLibre
 
 use wasm_bindgen::prelude::*;
+use web_sys::js_sys::{Function, Promise};
 
-use super::MapOptions;
+use super::{
+    AnimationOptions, CameraOptions, EaseToOptions, FitBoundsOptions, FlyToOptions, IControl,
+    LngLat, LngLatBounds, LngLatBoundsLike, LngLatLike, MapOptions, Source, StyleLayer,
+};
 
 #[wasm_bindgen]
 extern "C" {
@@ -17,16 +21,459 @@ extern "C" {
     #[wasm_bindgen(constructor, js_namespace = maplibregl, catch)]
     pub fn new(options: MapOptions) -> Result<Map, JsValue>;
 
+    /// Returns the geographical centerpoint of the map
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#getCenter>
+    #[wasm_bindgen(method, js_name=getCenter)]
+    pub fn get_center(this: &Map) -> LngLat;
+
+    /// Sets the geographical centerpoint of the map without an animated
+    /// transition
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#setCenter>
+    #[wasm_bindgen(method, js_name=setCenter)]
+    pub fn set_center(this: &Map, center: &LngLatLike) -> Map;
+
+    /// Returns the current zoom level of the map
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#getZoom>
+    #[wasm_bindgen(method, js_name=getZoom)]
+    pub fn get_zoom(this: &Map) -> f64;
+
+    /// Sets the zoom level of the map without an animated transition
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#setZoom>
+    #[wasm_bindgen(method, js_name=setZoom)]
+    pub fn set_zoom(this: &Map, zoom: f64) -> Map;
+
+    /// Returns the current bearing of the map in degrees counter-clockwise
+    /// from north
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#getBearing>
+    #[wasm_bindgen(method, js_name=getBearing)]
+    pub fn get_bearing(this: &Map) -> f64;
+
+    /// Sets the bearing of the map without an animated transition
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#setBearing>
+    #[wasm_bindgen(method, js_name=setBearing)]
+    pub fn set_bearing(this: &Map, bearing: f64) -> Map;
+
+    /// Returns the current pitch of the map in degrees away from the plane of
+    /// the screen
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#getPitch>
+    #[wasm_bindgen(method, js_name=getPitch)]
+    pub fn get_pitch(this: &Map) -> f64;
+
+    /// Sets the pitch of the map without an animated transition
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#setPitch>
+    #[wasm_bindgen(method, js_name=setPitch)]
+    pub fn set_pitch(this: &Map, pitch: f64) -> Map;
+
+    /// Returns the current roll of the map in degrees counter-clockwise about
+    /// the camera boresight
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#getRoll>
+    #[wasm_bindgen(method, js_name=getRoll)]
+    pub fn get_roll(this: &Map) -> f64;
+
+    /// Sets the roll of the map without an animated transition
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#setRoll>
+    #[wasm_bindgen(method, js_name=setRoll)]
+    pub fn set_roll(this: &Map, roll: f64) -> Map;
+
+    /// Returns the elevation of the geographical centerpoint of the map in
+    /// meters above sea level
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#getCenterElevation>
+    #[wasm_bindgen(method, js_name=getCenterElevation)]
+    pub fn get_center_elevation(this: &Map) -> f64;
+
+    /// Sets the elevation of the geographical centerpoint of the map without
+    /// an animated transition
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#setCenterElevation>
+    #[wasm_bindgen(method, js_name=setCenterElevation)]
+    pub fn set_center_elevation(this: &Map, elevation: f64) -> Map;
+
+    /// Changes any combination of center, zoom, bearing, pitch, roll and
+    /// elevation without an animated transition
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#jumpTo>
+    #[wasm_bindgen(method, js_name=jumpTo)]
+    pub fn jump_to(this: &Map, options: &CameraOptions) -> Map;
+
+    /// Changes any combination of center, zoom, bearing, pitch, roll and
+    /// elevation with an animated transition between the old and new values
+    ///
+    /// The properties of `event_data` are added to the fired events
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#easeTo>
+    #[wasm_bindgen(method, js_name=easeTo)]
+    pub fn ease_to(this: &Map, options: &EaseToOptions, event_data: &JsValue) -> Map;
+
+    /// Changes any combination of center, zoom, bearing, pitch, roll and
+    /// elevation with an animated transition that evokes flight
+    ///
+    /// The properties of `event_data` are added to the fired events
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#flyTo>
+    #[wasm_bindgen(method, js_name=flyTo)]
+    pub fn fly_to(this: &Map, options: &FlyToOptions, event_data: &JsValue) -> Map;
+
+    /// Pans the map to the specified location with an animated transition
+    ///
+    /// The properties of `event_data` are added to the fired events
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#panTo>
+    #[wasm_bindgen(method, js_name=panTo)]
+    pub fn pan_to(
+        this: &Map,
+        lnglat: &LngLatLike,
+        options: &EaseToOptions,
+        event_data: &JsValue,
+    ) -> Map;
+
+    /// Zooms the map to the specified zoom level with an animated transition
+    ///
+    /// The properties of `event_data` are added to the fired events
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#zoomTo>
+    #[wasm_bindgen(method, js_name=zoomTo)]
+    pub fn zoom_to(this: &Map, zoom: f64, options: &EaseToOptions, event_data: &JsValue) -> Map;
+
+    /// Rotates the map to the specified bearing with an animated transition
+    ///
+    /// The properties of `event_data` are added to the fired events
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#rotateTo>
+    #[wasm_bindgen(method, js_name=rotateTo)]
+    pub fn rotate_to(
+        this: &Map,
+        bearing: f64,
+        options: &EaseToOptions,
+        event_data: &JsValue,
+    ) -> Map;
+
+    /// Rotates the map so that north is up with an animated transition
+    ///
+    /// The properties of `event_data` are added to the fired events
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#resetNorth>
+    #[wasm_bindgen(method, js_name=resetNorth)]
+    pub fn reset_north(this: &Map, options: &AnimationOptions, event_data: &JsValue) -> Map;
+
+    /// Pans and zooms the map to contain its visible area within the
+    /// specified geographical bounds
+    ///
+    /// The properties of `event_data` are added to the fired events
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#fitBounds>
+    #[wasm_bindgen(method, js_name=fitBounds)]
+    pub fn fit_bounds(
+        this: &Map,
+        bounds: &LngLatBoundsLike,
+        options: &FitBoundsOptions,
+        event_data: &JsValue,
+    ) -> Map;
+
+    /// Returns whether the map is moving due to a camera transition or a
+    /// user interaction
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#isMoving>
+    #[wasm_bindgen(method, js_name=isMoving)]
+    pub fn is_moving(this: &Map) -> bool;
+
+    /// Stops any animated transition underway
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#stop>
+    #[wasm_bindgen(method)]
+    pub fn stop(this: &Map) -> Map;
+
+    /// Returns the camera position which fits the specified geographical
+    /// bounds, if any
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#cameraForBounds>
+    #[wasm_bindgen(method, js_name=cameraForBounds)]
+    pub fn camera_for_bounds(
+        this: &Map,
+        bounds: &LngLatBoundsLike,
+        options: &FitBoundsOptions,
+    ) -> Option<CameraOptions>;
+
+    /// Returns the geographical bounds visible in the current map view
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#getBounds>
+    #[wasm_bindgen(method, js_name=getBounds)]
+    pub fn get_bounds(this: &Map) -> LngLatBounds;
+
+    /// Returns the maximum geographical bounds the map is constrained to, if
+    /// any
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#getMaxBounds>
+    #[wasm_bindgen(method, js_name=getMaxBounds)]
+    pub fn get_max_bounds(this: &Map) -> Option<LngLatBounds>;
+
+    /// Sets or clears the maximum geographical bounds the map is constrained
+    /// to
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#setMaxBounds>
+    #[wasm_bindgen(method, js_name=setMaxBounds)]
+    pub fn set_max_bounds(this: &Map, bounds: Option<LngLatBoundsLike>) -> Map;
+
+    /// Adds a source to the style of the map
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#addSource>
+    #[wasm_bindgen(method, js_name=addSource, catch)]
+    pub fn add_source(this: &Map, id: &str, source: &JsValue) -> Result<Map, JsValue>;
+
+    /// Removes a source from the style of the map
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#removeSource>
+    #[wasm_bindgen(method, js_name=removeSource, catch)]
+    pub fn remove_source(this: &Map, id: &str) -> Result<Map, JsValue>;
+
+    /// Returns the source with the given identifier of the style of the
+    /// map, if any
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#getSource>
+    #[wasm_bindgen(method, js_name=getSource)]
+    pub fn get_source(this: &Map, id: &str) -> Option<Source>;
+
+    /// Returns whether the source with the given identifier is loaded
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#isSourceLoaded>
+    #[wasm_bindgen(method, js_name=isSourceLoaded, catch)]
+    pub fn is_source_loaded(this: &Map, id: &str) -> Result<bool, JsValue>;
+
+    /// Adds a layer to the style of the map, optionally before the layer
+    /// with the given identifier
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#addLayer>
+    #[wasm_bindgen(method, js_name=addLayer, catch)]
+    pub fn add_layer(this: &Map, layer: &JsValue, before_id: Option<&str>) -> Result<Map, JsValue>;
+
+    /// Removes the layer with the given identifier from the style of the map
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#removeLayer>
+    #[wasm_bindgen(method, js_name=removeLayer, catch)]
+    pub fn remove_layer(this: &Map, id: &str) -> Result<Map, JsValue>;
+
+    /// Moves a layer to a different z-position, before the layer with the
+    /// given identifier or to the top if not specified
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#moveLayer>
+    #[wasm_bindgen(method, js_name=moveLayer, catch)]
+    pub fn move_layer(this: &Map, id: &str, before_id: Option<&str>) -> Result<Map, JsValue>;
+
+    /// Returns the layer with the given identifier of the style of the map,
+    /// if any
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#getLayer>
+    #[wasm_bindgen(method, js_name=getLayer)]
+    pub fn get_layer(this: &Map, id: &str) -> Option<StyleLayer>;
+
+    /// Returns the identifiers of the layers of the style of the map in
+    /// their rendering order
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#getLayersOrder>
+    #[wasm_bindgen(method, js_name=getLayersOrder)]
+    pub fn get_layers_order(this: &Map) -> Vec<String>;
+
+    /// Sets the state of a feature, merging it with its current state
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#setFeatureState>
+    #[wasm_bindgen(method, js_name=setFeatureState, catch)]
+    pub fn set_feature_state(
+        this: &Map,
+        feature: &JsValue,
+        state: &JsValue,
+    ) -> Result<Map, JsValue>;
+
+    /// Returns the state of a feature
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#getFeatureState>
+    #[wasm_bindgen(method, js_name=getFeatureState, catch)]
+    pub fn get_feature_state(this: &Map, feature: &JsValue) -> Result<JsValue, JsValue>;
+
+    /// Removes the state of a feature, or only the given key of it if
+    /// specified
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#removeFeatureState>
+    #[wasm_bindgen(method, js_name=removeFeatureState, catch)]
+    pub fn remove_feature_state(
+        this: &Map,
+        target: &JsValue,
+        key: Option<&str>,
+    ) -> Result<Map, JsValue>;
+
+    /// Returns the rendered features in the given point or box of the
+    /// screen, or in the whole viewport if `undefined`
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#queryRenderedFeatures>
+    #[wasm_bindgen(method, js_name=queryRenderedFeatures, catch)]
+    pub fn query_rendered_features(
+        this: &Map,
+        geometry: &JsValue,
+        options: &JsValue,
+    ) -> Result<JsValue, JsValue>;
+
+    /// Returns the features of the source with the given identifier in the
+    /// loaded tiles, whether rendered or not
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#querySourceFeatures>
+    #[wasm_bindgen(method, js_name=querySourceFeatures, catch)]
+    pub fn query_source_features(
+        this: &Map,
+        source_id: &str,
+        options: &JsValue,
+    ) -> Result<JsValue, JsValue>;
+
+    /// Sets the callback run before the map requests a resource
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#setTransformRequest>
+    #[wasm_bindgen(method, js_name=setTransformRequest)]
+    pub fn set_transform_request(this: &Map, transform_request: &Function) -> Map;
+
+    /// Adds an image to the style of the map, to be used by icons and patterns
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#addImage>
+    #[wasm_bindgen(method, js_name=addImage, catch)]
+    pub fn add_image(
+        this: &Map,
+        id: &str,
+        image: &JsValue,
+        options: &JsValue,
+    ) -> Result<Map, JsValue>;
+
+    /// Replaces the pixels of an image of the style of the map with an image
+    /// of the same size
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#updateImage>
+    #[wasm_bindgen(method, js_name=updateImage, catch)]
+    pub fn update_image(this: &Map, id: &str, image: &JsValue) -> Result<Map, JsValue>;
+
+    /// Returns whether an image with the given identifier exists in the style
+    /// of the map
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#hasImage>
+    #[wasm_bindgen(method, js_name=hasImage)]
+    pub fn has_image(this: &Map, id: &str) -> bool;
+
+    /// Removes the image with the given identifier from the style of the map
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#removeImage>
+    #[wasm_bindgen(method, js_name=removeImage)]
+    pub fn remove_image(this: &Map, id: &str);
+
+    /// Returns the identifiers of the images of the style of the map,
+    /// including the ones of the sprite
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#listImages>
+    #[wasm_bindgen(method, js_name=listImages)]
+    pub fn list_images(this: &Map) -> Vec<String>;
+
+    /// Loads an image from the given URL, resolving to a response with the
+    /// image as its `data`
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#loadImage>
+    #[wasm_bindgen(method, js_name=loadImage)]
+    pub fn load_image(this: &Map, url: &str) -> Promise;
+
+    /// Requests a new frame to be rendered, for example to animate a style
+    /// image or a custom layer
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#triggerRepaint>
+    #[wasm_bindgen(method, js_name=triggerRepaint)]
+    pub fn trigger_repaint(this: &Map);
+
+    /// Adds a control to the map at the given position, or at the default
+    /// position of the control when `None` is given
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#addControl>
+    #[wasm_bindgen(method, js_name=addControl)]
+    pub fn add_control(this: &Map, control: &IControl, position: Option<String>) -> Map;
+
+    /// Removes a control from the map
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#removeControl>
+    #[wasm_bindgen(method, js_name=removeControl)]
+    pub fn remove_control(this: &Map, control: &IControl) -> Map;
+
+    /// Returns whether the control has been added to the map
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#hasControl>
+    #[wasm_bindgen(method, js_name=hasControl)]
+    pub fn has_control(this: &Map, control: &IControl) -> bool;
+
+    /// Returns whether the map is fully loaded
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#loaded>
+    #[wasm_bindgen(method)]
+    pub fn loaded(this: &Map) -> bool;
+
+    /// Adds a listener for events of the given type
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#on>
+    #[wasm_bindgen(method, js_name=on)]
+    pub fn on(this: &Map, r#type: &str, listener: &Function) -> Map;
+
+    /// Adds a listener for events of the given type occurring on features of
+    /// the given style layer
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#on>
+    #[wasm_bindgen(method, js_name=on)]
+    pub fn on_layer(this: &Map, r#type: &str, layer_id: &str, listener: &Function) -> Map;
+
+    /// Adds a listener that will be called only once for events of the given
+    /// type
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#once>
+    #[wasm_bindgen(method, js_name=once)]
+    pub fn once(this: &Map, r#type: &str, listener: &Function) -> Map;
+
+    /// Removes a listener previously added with `on` or `once`
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#off>
+    #[wasm_bindgen(method, js_name=off)]
+    pub fn off(this: &Map, r#type: &str, listener: &Function) -> Map;
+
+    /// Removes a listener previously added with `on_layer`
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#off>
+    #[wasm_bindgen(method, js_name=off)]
+    pub fn off_layer(this: &Map, r#type: &str, layer_id: &str, listener: &Function) -> Map;
+
+    /// Returns a `Promise` which resolves with the event data the next time
+    /// the event of the given type is fired
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#once>
+    #[wasm_bindgen(method, js_name=once)]
+    pub fn once_promise(this: &Map, r#type: &str) -> Promise;
+
+    /// Fires an event of the given type with the given properties
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Map/#fire>
+    #[wasm_bindgen(method)]
+    pub fn fire(this: &Map, r#type: &str, properties: &JsValue) -> Map;
+
     // TODO: Add all the methods
 }
 
 #[cfg(test)]
 mod test {
-    use crate::test_utils::{gen_html_element_with_id, load_maplibre_gl};
+    use crate::test_utils::{
+        HtmlElementRAII, gen_html_element, gen_html_element_with_id, load_maplibre_gl,
+    };
 
     use super::*;
+    use std::{cell::Cell, rc::Rc};
+    use wasm_bindgen_futures::JsFuture;
     use wasm_bindgen_test::*;
-    use web_sys::js_sys::{Object, Reflect};
+    use web_sys::js_sys::{Array, Object, Reflect};
 
     #[wasm_bindgen_test]
     async fn new() {
@@ -41,4 +488,117 @@ mod test {
         };
         Map::new(options).expect("Creating Map should work");
     }
+
+    #[wasm_bindgen_test]
+    async fn set_center() {
+        load_maplibre_gl().await;
+        let element = gen_html_element("div");
+        let map = new_map(&element);
+        let center = LngLat::new(12.23, 14.42).expect("Creating LngLat should work");
+        map.set_center(&LngLatLike::from(JsValue::from(center)));
+        let retrieved = map.get_center();
+
+        assert!((retrieved.lng() - 12.23).abs() < 0.01);
+        assert!((retrieved.lat() - 14.42).abs() < 0.01);
+    }
+
+    #[wasm_bindgen_test]
+    async fn set_zoom() {
+        load_maplibre_gl().await;
+        let element = gen_html_element("div");
+        let map = new_map(&element);
+        map.set_zoom(4.5);
+
+        assert!((map.get_zoom() - 4.5).abs() < 0.01);
+    }
+
+    #[wasm_bindgen_test]
+    async fn set_bearing() {
+        load_maplibre_gl().await;
+        let element = gen_html_element("div");
+        let map = new_map(&element);
+        map.set_bearing(30.);
+
+        assert!((map.get_bearing() - 30.).abs() < 0.01);
+    }
+
+    #[wasm_bindgen_test]
+    async fn jump_to() {
+        load_maplibre_gl().await;
+        let element = gen_html_element("div");
+        let map = new_map(&element);
+        let options = Object::new();
+        Reflect::set(&options, &"zoom".into(), &JsValue::from(3.))
+            .expect("Setting zoom value should work");
+        Reflect::set(&options, &"pitch".into(), &JsValue::from(20.))
+            .expect("Setting pitch value should work");
+        map.jump_to(&CameraOptions::from(JsValue::from(options)));
+
+        assert!((map.get_zoom() - 3.).abs() < 0.01);
+        assert!((map.get_pitch() - 20.).abs() < 0.01);
+    }
+
+    #[wasm_bindgen_test]
+    async fn ease_to() {
+        load_maplibre_gl().await;
+        let element = gen_html_element("div");
+        let map = new_map(&element);
+        let options = Object::new();
+        Reflect::set(&options, &"zoom".into(), &JsValue::from(5.))
+            .expect("Setting zoom value should work");
+        Reflect::set(&options, &"duration".into(), &JsValue::from(0.))
+            .expect("Setting duration value should work");
+        let move_end = JsFuture::from(map.once_promise("moveend"));
+        map.ease_to(
+            &EaseToOptions::from(JsValue::from(options)),
+            &JsValue::UNDEFINED,
+        );
+        move_end.await.expect("moveend should be fired");
+
+        assert!((map.get_zoom() - 5.).abs() < 0.01);
+    }
+
+    #[wasm_bindgen_test]
+    async fn set_max_bounds() {
+        load_maplibre_gl().await;
+        let element = gen_html_element("div");
+        let map = new_map(&element);
+        let bounds = Array::new();
+        for value in [-10., -20., 10., 20.] {
+            bounds.push(&JsValue::from_f64(value));
+        }
+        map.set_max_bounds(Some(LngLatBoundsLike::from(JsValue::from(bounds))));
+        let max_bounds = map.get_max_bounds().expect("Max bounds should be set");
+
+        assert!((max_bounds.get_west() + 10.).abs() < 0.01);
+        assert!((max_bounds.get_north() - 20.).abs() < 0.01);
+
+        map.set_max_bounds(None);
+        assert!(map.get_max_bounds().is_none());
+    }
+
+    #[wasm_bindgen_test]
+    async fn on_off() {
+        load_maplibre_gl().await;
+        let element = gen_html_element("div");
+        let map = new_map(&element);
+        let count = Rc::new(Cell::new(0));
+        let listener = {
+            let count = count.clone();
+            Closure::<dyn FnMut(JsValue)>::new(move |_| count.set(count.get() + 1))
+        };
+        map.on("zoom", listener.as_ref().unchecked_ref());
+        map.set_zoom(2.);
+        map.off("zoom", listener.as_ref().unchecked_ref());
+        map.set_zoom(3.);
+
+        assert_eq!(count.get(), 1);
+    }
+
+    fn new_map(element: &HtmlElementRAII) -> Map {
+        let options = Object::new();
+        Reflect::set(&options, &"container".into(), &element.cloned_ref())
+            .expect("Setting container value should work");
+        Map::new(MapOptions::from(JsValue::from(options))).expect("Creating Map should work")
+    }
 }
@@ -0,0 +1,139 @@
+//! # Bindings to the `Marker` JS object from MapLibre
+
+use wasm_bindgen::prelude::*;
+use web_sys::HtmlElement;
+
+use super::{Evented, LngLat, LngLatLike, Map, Point, PointLike, Popup};
+
+#[wasm_bindgen]
+extern "C" {
+    /// `Marker` object representing an HTML element placed on the map at a
+    /// given longitude and latitude
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Marker/>
+    #[wasm_bindgen(js_namespace = maplibregl, extends = Evented)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub type Marker;
+
+    /// Create a `Marker` object calling its constructor with the given
+    /// options
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Marker/#constructor>
+    #[wasm_bindgen(constructor, js_namespace = maplibregl, catch)]
+    pub fn new(options: &JsValue) -> Result<Marker, JsValue>;
+
+    /// Attaches the marker to a map
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Marker/#addto>
+    #[wasm_bindgen(method, js_name=addTo)]
+    pub fn add_to(this: &Marker, map: &Map) -> Marker;
+
+    /// Removes the marker from the map
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Marker/#remove>
+    #[wasm_bindgen(method)]
+    pub fn remove(this: &Marker) -> Marker;
+
+    /// Returns the geographical location of the marker
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Marker/#getlnglat>
+    #[wasm_bindgen(method, js_name=getLngLat)]
+    pub fn get_lng_lat(this: &Marker) -> LngLat;
+
+    /// Sets the geographical location of the marker
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Marker/#setlnglat>
+    #[wasm_bindgen(method, js_name=setLngLat)]
+    pub fn set_lng_lat(this: &Marker, lnglat: &LngLatLike) -> Marker;
+
+    /// Returns the HTML element of the marker
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Marker/#getelement>
+    #[wasm_bindgen(method, js_name=getElement)]
+    pub fn get_element(this: &Marker) -> HtmlElement;
+
+    /// Returns the offset of the marker in pixels
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Marker/#getoffset>
+    #[wasm_bindgen(method, js_name=getOffset)]
+    pub fn get_offset(this: &Marker) -> Point;
+
+    /// Sets the offset of the marker in pixels
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Marker/#setoffset>
+    #[wasm_bindgen(method, js_name=setOffset)]
+    pub fn set_offset(this: &Marker, offset: &PointLike) -> Marker;
+
+    /// Returns whether the marker can be dragged
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Marker/#isdraggable>
+    #[wasm_bindgen(method, js_name=isDraggable)]
+    pub fn is_draggable(this: &Marker) -> bool;
+
+    /// Sets whether the marker can be dragged
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Marker/#setdraggable>
+    #[wasm_bindgen(method, js_name=setDraggable)]
+    pub fn set_draggable(this: &Marker, should_be_draggable: bool) -> Marker;
+
+    /// Returns the rotation of the marker in degrees
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Marker/#getrotation>
+    #[wasm_bindgen(method, js_name=getRotation)]
+    pub fn get_rotation(this: &Marker) -> f64;
+
+    /// Sets the rotation of the marker in degrees
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Marker/#setrotation>
+    #[wasm_bindgen(method, js_name=setRotation)]
+    pub fn set_rotation(this: &Marker, rotation: f64) -> Marker;
+
+    /// Sets the opacity of the marker when visible and when covered by the
+    /// terrain
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Marker/#setopacity>
+    #[wasm_bindgen(method, js_name=setOpacity)]
+    pub fn set_opacity(
+        this: &Marker,
+        opacity: Option<f64>,
+        opacity_when_covered: Option<f64>,
+    ) -> Marker;
+
+    /// Adds a CSS class to the element of the marker
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Marker/#addclassname>
+    #[wasm_bindgen(method, js_name=addClassName)]
+    pub fn add_class_name(this: &Marker, class_name: &str);
+
+    /// Removes a CSS class from the element of the marker
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Marker/#removeclassname>
+    #[wasm_bindgen(method, js_name=removeClassName)]
+    pub fn remove_class_name(this: &Marker, class_name: &str);
+
+    /// Toggles a CSS class of the element of the marker, returning whether
+    /// the class is now present
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Marker/#toggleclassname>
+    #[wasm_bindgen(method, js_name=toggleClassName)]
+    pub fn toggle_class_name(this: &Marker, class_name: &str) -> bool;
+
+    /// Binds a popup to the marker, or unbinds the current one when
+    /// `undefined` is given
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Marker/#setpopup>
+    #[wasm_bindgen(method, js_name=setPopup)]
+    pub fn set_popup(this: &Marker, popup: Option<Popup>) -> Marker;
+
+    /// Returns the popup bound to the marker, if any
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Marker/#getpopup>
+    #[wasm_bindgen(method, js_name=getPopup)]
+    pub fn get_popup(this: &Marker) -> Option<Popup>;
+
+    /// Opens or closes the popup bound to the marker
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Marker/#togglepopup>
+    #[wasm_bindgen(method, js_name=togglePopup)]
+    pub fn toggle_popup(this: &Marker) -> Marker;
+}
@@ -1,11 +1,47 @@
 //! # Bindings to the distributed JS from MapLibre
 
+mod camera_options;
+mod controls;
+mod evented;
+mod events;
+mod geojson_source;
+mod image_source;
 mod lng_lat;
+mod lng_lat_bounds;
+mod lng_lat_bounds_like;
 mod lng_lat_like;
 mod map;
 mod map_options;
+mod marker;
+mod point;
+mod popup;
+mod protocol;
+mod source;
+mod style_layer;
 
+pub use camera_options::{
+    AnimationOptions, CameraOptions, EaseToOptions, FitBoundsOptions, FlyToOptions,
+};
+pub use controls::{
+    AttributionControl, FullscreenControl, GeolocateControl, GlobeControl, IControl, LogoControl,
+    NavigationControl, ScaleControl, TerrainControl,
+};
+pub use evented::Evented;
+pub use events::{
+    ErrorEvent, MapDataEvent, MapLibreEvent, MapLibreZoomEvent, MapMouseEvent, MapTouchEvent,
+    MapWheelEvent,
+};
+pub use geojson_source::GeoJSONSource;
+pub use image_source::{CanvasSource, ImageSource, VideoSource};
 pub use lng_lat::LngLat;
+pub use lng_lat_bounds::LngLatBounds;
+pub use lng_lat_bounds_like::LngLatBoundsLike;
 pub use lng_lat_like::LngLatLike;
 pub use map::Map;
 pub use map_options::MapOptions;
+pub use marker::Marker;
+pub use point::{Point, PointLike};
+pub use popup::Popup;
+pub use protocol::{add_protocol, remove_protocol};
+pub use source::Source;
+pub use style_layer::StyleLayer;
@@ -0,0 +1,57 @@
+//! # Bindings to the `Point` and `PointLike` JS objects used by MapLibre
+
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    /// `Point` object representing a position in screen pixels
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Point/>
+    #[wasm_bindgen(js_namespace = maplibregl)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub type Point;
+
+    /// Create a `Point` object calling its constructor with coordinates
+    /// measured in pixels
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Point/#constructor>
+    #[wasm_bindgen(constructor, js_namespace = maplibregl, catch)]
+    pub fn new(x: f64, y: f64) -> Result<Point, JsValue>;
+
+    /// Get the `x` property of `Point`
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Point/#x>
+    #[wasm_bindgen(method, getter)]
+    pub fn x(this: &Point) -> f64;
+
+    /// Get the `y` property of `Point`
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Point/#y>
+    #[wasm_bindgen(method, getter)]
+    pub fn y(this: &Point) -> f64;
+
+    /// `PointLike` object representing something that can be converted into
+    /// an actual `Point`
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/type-aliases/PointLike/>
+    #[wasm_bindgen(js_namespace = maplibregl)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub type PointLike;
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_utils::load_maplibre_gl;
+
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    async fn new() {
+        load_maplibre_gl().await;
+        let point = Point::new(2.5, 3.5).expect("Creating Point should work");
+
+        assert!((point.x() - 2.5).abs() < 0.01);
+        assert!((point.y() - 3.5).abs() < 0.01);
+    }
+}
@@ -0,0 +1,122 @@
+//! # Bindings to the `Popup` JS object from MapLibre
+
+use wasm_bindgen::prelude::*;
+use web_sys::{HtmlElement, Node};
+
+use super::{Evented, LngLat, LngLatLike, Map};
+
+#[wasm_bindgen]
+extern "C" {
+    /// `Popup` object representing a popup component placed on the map
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Popup/>
+    #[wasm_bindgen(js_namespace = maplibregl, extends = Evented)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub type Popup;
+
+    /// Create a `Popup` object calling its constructor with the given
+    /// options
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Popup/#constructor>
+    #[wasm_bindgen(constructor, js_namespace = maplibregl, catch)]
+    pub fn new(options: &JsValue) -> Result<Popup, JsValue>;
+
+    /// Adds the popup to a map
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Popup/#addto>
+    #[wasm_bindgen(method, js_name=addTo)]
+    pub fn add_to(this: &Popup, map: &Map) -> Popup;
+
+    /// Returns whether the popup is open
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Popup/#isopen>
+    #[wasm_bindgen(method, js_name=isOpen)]
+    pub fn is_open(this: &Popup) -> bool;
+
+    /// Removes the popup from the map it has been added to
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Popup/#remove>
+    #[wasm_bindgen(method)]
+    pub fn remove(this: &Popup) -> Popup;
+
+    /// Returns the geographical location of the popup's anchor, if set
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Popup/#getlnglat>
+    #[wasm_bindgen(method, js_name=getLngLat)]
+    pub fn get_lng_lat(this: &Popup) -> Option<LngLat>;
+
+    /// Sets the geographical location of the popup's anchor
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Popup/#setlnglat>
+    #[wasm_bindgen(method, js_name=setLngLat)]
+    pub fn set_lng_lat(this: &Popup, lnglat: &LngLatLike) -> Popup;
+
+    /// Tracks the popup anchor to the cursor position on screens with a
+    /// pointer device
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Popup/#trackpointer>
+    #[wasm_bindgen(method, js_name=trackPointer)]
+    pub fn track_pointer(this: &Popup) -> Popup;
+
+    /// Returns the HTML element of the popup, if it has been added to a map
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Popup/#getelement>
+    #[wasm_bindgen(method, js_name=getElement)]
+    pub fn get_element(this: &Popup) -> Option<HtmlElement>;
+
+    /// Sets the content of the popup to a string of text
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Popup/#settext>
+    #[wasm_bindgen(method, js_name=setText)]
+    pub fn set_text(this: &Popup, text: &str) -> Popup;
+
+    /// Sets the content of the popup to the given HTML string
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Popup/#sethtml>
+    #[wasm_bindgen(method, js_name=setHTML)]
+    pub fn set_html(this: &Popup, html: &str) -> Popup;
+
+    /// Sets the content of the popup to the given DOM node
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Popup/#setdomcontent>
+    #[wasm_bindgen(method, js_name=setDOMContent)]
+    pub fn set_dom_content(this: &Popup, html_node: &Node) -> Popup;
+
+    /// Returns the CSS max width of the popup, if it has been added to a
+    /// map
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Popup/#getmaxwidth>
+    #[wasm_bindgen(method, js_name=getMaxWidth)]
+    pub fn get_max_width(this: &Popup) -> Option<String>;
+
+    /// Sets the CSS max width of the popup
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Popup/#setmaxwidth>
+    #[wasm_bindgen(method, js_name=setMaxWidth)]
+    pub fn set_max_width(this: &Popup, max_width: &str) -> Popup;
+
+    /// Sets the offset of the popup
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Popup/#setoffset>
+    #[wasm_bindgen(method, js_name=setOffset)]
+    pub fn set_offset(this: &Popup, offset: &JsValue) -> Popup;
+
+    /// Adds a CSS class to the container element of the popup
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Popup/#addclassname>
+    #[wasm_bindgen(method, js_name=addClassName)]
+    pub fn add_class_name(this: &Popup, class_name: &str) -> Popup;
+
+    /// Removes a CSS class from the container element of the popup
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Popup/#removeclassname>
+    #[wasm_bindgen(method, js_name=removeClassName)]
+    pub fn remove_class_name(this: &Popup, class_name: &str) -> Popup;
+
+    /// Toggles a CSS class of the container element of the popup, returning
+    /// whether the class is now present
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Popup/#toggleclassname>
+    #[wasm_bindgen(method, js_name=toggleClassName)]
+    pub fn toggle_class_name(this: &Popup, class_name: &str) -> Option<bool>;
+}
@@ -0,0 +1,20 @@
+//! # Bindings to the functions registering custom protocols in MapLibre
+
+use wasm_bindgen::prelude::*;
+use web_sys::js_sys::Function;
+
+#[wasm_bindgen]
+extern "C" {
+    /// Adds a custom load resource function called when requesting URLs
+    /// starting with the given protocol, such as `custom://`
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/functions/addProtocol/>
+    #[wasm_bindgen(js_namespace = maplibregl, js_name=addProtocol)]
+    pub fn add_protocol(custom_protocol: &str, load_fn: &Function);
+
+    /// Removes a previously added protocol
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/functions/removeProtocol/>
+    #[wasm_bindgen(js_namespace = maplibregl, js_name=removeProtocol)]
+    pub fn remove_protocol(custom_protocol: &str);
+}
@@ -0,0 +1,31 @@
+//! # Bindings to the `Source` JS objects from MapLibre
+
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    /// `Source` object representing a data source of the style of the map
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/interfaces/Source/>
+    #[wasm_bindgen(js_namespace = maplibregl)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub type Source;
+
+    /// Get the `id` property of `Source`
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/interfaces/Source/#id>
+    #[wasm_bindgen(method, getter)]
+    pub fn id(this: &Source) -> String;
+
+    /// Get the `type` property of `Source`
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/interfaces/Source/#type>
+    #[wasm_bindgen(method, getter, js_name=type)]
+    pub fn source_type(this: &Source) -> String;
+
+    /// Returns the specification of the source
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/interfaces/Source/#serialize>
+    #[wasm_bindgen(method)]
+    pub fn serialize(this: &Source) -> JsValue;
+}
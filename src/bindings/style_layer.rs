@@ -0,0 +1,55 @@
+//! # Bindings to the `StyleLayer` JS object from MapLibre
+
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    /// `StyleLayer` object representing a layer of the style of the map
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/StyleLayer/>
+    #[wasm_bindgen(js_namespace = maplibregl)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub type StyleLayer;
+
+    /// Get the `id` property of `StyleLayer`
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/StyleLayer/#id>
+    #[wasm_bindgen(method, getter)]
+    pub fn id(this: &StyleLayer) -> String;
+
+    /// Get the `type` property of `StyleLayer`
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/StyleLayer/#type>
+    #[wasm_bindgen(method, getter, js_name=type)]
+    pub fn layer_type(this: &StyleLayer) -> String;
+
+    /// Get the `source` property of `StyleLayer`
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/StyleLayer/#source>
+    #[wasm_bindgen(method, getter)]
+    pub fn source(this: &StyleLayer) -> Option<String>;
+
+    /// Get the `sourceLayer` property of `StyleLayer`
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/StyleLayer/#sourcelayer>
+    #[wasm_bindgen(method, getter, js_name=sourceLayer)]
+    pub fn source_layer(this: &StyleLayer) -> Option<String>;
+
+    /// Get the `minzoom` property of `StyleLayer`, if set
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/StyleLayer/#minzoom>
+    #[wasm_bindgen(method, getter)]
+    pub fn minzoom(this: &StyleLayer) -> Option<f64>;
+
+    /// Get the `maxzoom` property of `StyleLayer`, if set
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/StyleLayer/#maxzoom>
+    #[wasm_bindgen(method, getter)]
+    pub fn maxzoom(this: &StyleLayer) -> Option<f64>;
+
+    /// Returns the specification of the layer
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/StyleLayer/#serialize>
+    #[wasm_bindgen(method)]
+    pub fn serialize(this: &StyleLayer) -> JsValue;
+}
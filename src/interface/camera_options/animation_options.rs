@@ -0,0 +1,227 @@
+//! Submodule for `AnimationOptions` and associated tests
+
+use serde::{Serialize, Serializer};
+use wasm_bindgen::prelude::*;
+use web_sys::js_sys::Function;
+
+use super::Error;
+use crate::{bindings, interface::PointLike};
+
+/// Easing function of an animation. It takes a time progress ratio in the range `[0, 1]` and
+/// returns the animation progress ratio, where `0` is the initial state and `1` the final one
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Easing {
+    /// JS function that the struct wraps
+    js_value: Function,
+}
+
+impl Easing {
+    /// Create an `Easing` from a Rust function. The memory of the function is managed by the JS
+    /// garbage collector
+    pub fn new(easing: impl Fn(f64) -> f64 + 'static) -> Self {
+        let closure = Closure::<dyn Fn(f64) -> f64>::new(easing);
+        Self {
+            js_value: closure.into_js_value().unchecked_into(),
+        }
+    }
+
+    /// Get the underlying JS function
+    #[must_use]
+    pub fn into_inner(self) -> Function {
+        self.js_value
+    }
+}
+
+impl Serialize for Easing {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde_wasm_bindgen::preserve::serialize(&self.js_value, serializer)
+    }
+}
+
+impl From<Function> for Easing {
+    fn from(value: Function) -> Self {
+        Self { js_value: value }
+    }
+}
+
+/// Options common to all the animated camera transitions
+#[derive(Debug, PartialEq, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnimationOptions {
+    /// The duration of the animation in milliseconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duration: Option<f64>,
+    /// The easing function of the animation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    easing: Option<Easing>,
+    /// Offset in pixels of the target center relative to the real map container center
+    #[serde(skip_serializing_if = "Option::is_none")]
+    offset: Option<PointLike>,
+    /// Whether the transition is animated or not. Defaults to `true`
+    #[serde(skip_serializing_if = "Option::is_none", rename = "animate")]
+    animation: Option<bool>,
+    /// Whether the animation is essential and should not be affected by the user's
+    /// `prefers-reduced-motion` setting. Defaults to `false`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    essential: Option<bool>,
+    /// Whether to keep the elevation of the center point during the transition or not. Defaults
+    /// to `false`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    freeze_elevation: Option<bool>,
+}
+
+impl AnimationOptions {
+    /// Create new empty `AnimationOptions`
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Specify the duration of the animation in milliseconds
+    #[must_use]
+    pub fn with_duration(self, duration: impl Into<f64>) -> Self {
+        Self {
+            duration: Some(duration.into()),
+            ..self
+        }
+    }
+
+    /// Specify the easing function of the animation
+    #[must_use]
+    pub fn with_easing(self, easing: impl Into<Easing>) -> Self {
+        Self {
+            easing: Some(easing.into()),
+            ..self
+        }
+    }
+
+    /// Specify the offset in pixels of the target center relative to the real map container
+    /// center
+    #[must_use]
+    pub fn with_offset(self, offset: impl Into<PointLike>) -> Self {
+        Self {
+            offset: Some(offset.into()),
+            ..self
+        }
+    }
+
+    /// Disable the animation, so the transition happens immediately
+    #[must_use]
+    pub fn without_animation(self) -> Self {
+        Self {
+            animation: Some(false),
+            ..self
+        }
+    }
+
+    /// Mark the animation as essential, so it is not affected by the user's
+    /// `prefers-reduced-motion` setting
+    #[must_use]
+    pub fn with_essential(self) -> Self {
+        Self {
+            essential: Some(true),
+            ..self
+        }
+    }
+
+    /// Keep the elevation of the center point during the transition
+    #[must_use]
+    pub fn with_freeze_elevation(self) -> Self {
+        Self {
+            freeze_elevation: Some(true),
+            ..self
+        }
+    }
+
+    /// Try converting `AnimationOptions` into the equivalent bindings type
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn as_js_value(&self) -> Result<bindings::AnimationOptions, Error> {
+        Ok(bindings::AnimationOptions::from(self.serialize(
+            &serde_wasm_bindgen::Serializer::json_compatible(),
+        )?))
+    }
+}
+
+impl TryFrom<AnimationOptions> for bindings::AnimationOptions {
+    type Error = Error;
+
+    fn try_from(value: AnimationOptions) -> Result<Self, Self::Error> {
+        value.as_js_value()
+    }
+}
+
+impl TryFrom<&AnimationOptions> for bindings::AnimationOptions {
+    type Error = Error;
+
+    fn try_from(value: &AnimationOptions) -> Result<Self, Self::Error> {
+        value.as_js_value()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_utils::{get_key_list_from_object, get_value_from_object};
+
+    use super::*;
+    use wasm_bindgen_test::*;
+    use web_sys::js_sys::Boolean;
+
+    #[wasm_bindgen_test]
+    fn animation_options_with_duration() {
+        let options_js = AnimationOptions::new()
+            .with_duration(500)
+            .as_js_value()
+            .expect("Conversion from AnimationOptions to JS should work");
+        let retrieved: f64 = get_value_from_object(&options_js, "duration")
+            .try_into()
+            .expect("Back conversion should work");
+        let keys = get_key_list_from_object(&options_js);
+
+        assert_eq!(keys.len(), 1);
+        assert!((retrieved - 500.).abs() < 0.001);
+    }
+
+    #[wasm_bindgen_test]
+    fn animation_options_with_easing() {
+        let options_js = AnimationOptions::new()
+            .with_easing(Easing::new(|t| t * t))
+            .as_js_value()
+            .expect("Conversion from AnimationOptions to JS should work");
+        let easing: Function = get_value_from_object(&options_js, "easing").unchecked_into();
+        let result = easing
+            .call1(&JsValue::NULL, &JsValue::from(0.5))
+            .expect("Calling the easing function should work")
+            .as_f64()
+            .expect("Result should be a f64");
+
+        assert!((result - 0.25).abs() < 0.001);
+    }
+
+    #[wasm_bindgen_test]
+    fn animation_options_with_flags() {
+        let options_js = AnimationOptions::new()
+            .without_animation()
+            .with_essential()
+            .with_freeze_elevation()
+            .as_js_value()
+            .expect("Conversion from AnimationOptions to JS should work");
+        let keys = get_key_list_from_object(&options_js);
+
+        assert_eq!(keys.len(), 3);
+        assert_eq!(
+            Boolean::from(get_value_from_object(&options_js, "animate")),
+            false
+        );
+        assert_eq!(
+            Boolean::from(get_value_from_object(&options_js, "essential")),
+            true
+        );
+        assert_eq!(
+            Boolean::from(get_value_from_object(&options_js, "freezeElevation")),
+            true
+        );
+    }
+}
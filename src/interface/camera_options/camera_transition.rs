@@ -0,0 +1,96 @@
+//! Submodule for the `CameraTransition` future returned by the animated camera methods of `Map`
+
+use std::{
+    pin::Pin,
+    sync::atomic::{AtomicU32, Ordering},
+    task::{Context, Poll},
+};
+
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::js_sys::{Object, Promise, Reflect};
+
+use super::Error;
+use crate::{
+    bindings,
+    interface::events::{EventType, MapEventListener, MoveEnd},
+};
+
+/// Property added to the events fired by a transition to identify it
+const TRANSITION_ID_KEY: &str = "cameraTransitionId";
+
+/// Identifier of the next transition started
+static NEXT_TRANSITION_ID: AtomicU32 = AtomicU32::new(0);
+
+/// Future which resolves when an animated camera transition of a `Map` ends.
+///
+/// It resolves on the `moveend` event fired by the transition, either because it finished or
+/// because it was interrupted by another transition. The transition itself starts as soon as it
+/// is requested, so it is not necessary to poll this future for the camera to move
+#[derive(Debug)]
+#[must_use = "the transition has already started, await it to wait until the camera stops"]
+pub struct CameraTransition {
+    /// Pending `moveend` event with the listener which resolves it, or the error raised when
+    /// starting the transition. The error is taken out once it is returned
+    move_end: Result<(JsFuture, MapEventListener), Option<Error>>,
+}
+
+impl CameraTransition {
+    /// Create a `CameraTransition` listening to the `moveend` event of the given map which carries
+    /// the returned event data. The event data has to be given to the MapLibre method starting
+    /// the transition
+    pub(crate) fn listen(map: &bindings::Map) -> (Self, JsValue) {
+        let id = f64::from(NEXT_TRANSITION_ID.fetch_add(1, Ordering::Relaxed));
+        let event_data = Object::new();
+        Reflect::set(&event_data, &TRANSITION_ID_KEY.into(), &id.into())
+            .expect("Setting a property of a new object should work");
+
+        let mut listener = None;
+        let move_end = Promise::new(&mut |resolve, _reject| {
+            let closure = Closure::<dyn FnMut(JsValue)>::new(move |event: JsValue| {
+                let event_id = Reflect::get(&event, &TRANSITION_ID_KEY.into()).ok();
+                if event_id.and_then(|event_id| event_id.as_f64()) == Some(id) {
+                    // Resolving an already resolved promise has no effect
+                    let _ = resolve.call0(&JsValue::UNDEFINED);
+                }
+            });
+            map.on(MoveEnd::NAME, closure.as_ref().unchecked_ref());
+            listener = Some(MapEventListener::new(
+                map.clone(),
+                MoveEnd::NAME,
+                None,
+                closure,
+            ));
+        });
+        let listener = listener.expect("The promise executor should run synchronously");
+
+        (
+            Self {
+                move_end: Ok((JsFuture::from(move_end), listener)),
+            },
+            event_data.into(),
+        )
+    }
+
+    /// Create a `CameraTransition` which resolves immediately with the given error
+    pub(crate) const fn failed(error: Error) -> Self {
+        Self {
+            move_end: Err(Some(error)),
+        }
+    }
+}
+
+impl Future for CameraTransition {
+    type Output = Result<(), Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match &mut self.move_end {
+            Ok((move_end, _listener)) => Pin::new(move_end)
+                .poll(cx)
+                .map(|result| result.map(|_| ()).map_err(Into::into)),
+            Err(error) => Poll::Ready(Err(error
+                .take()
+                .expect("CameraTransition should not be polled after completion"))),
+        }
+    }
+}
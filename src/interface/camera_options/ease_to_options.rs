@@ -0,0 +1,123 @@
+//! Submodule for `EaseToOptions` and associated tests
+
+use serde::Serialize;
+
+use super::{AnimationOptions, CameraOptions, Error, Padding};
+use crate::bindings;
+
+/// Options for an animated transition with easing of the camera
+#[derive(Debug, PartialEq, Clone, Default, Serialize)]
+pub struct EaseToOptions {
+    /// Target of the camera
+    #[serde(flatten)]
+    camera: CameraOptions,
+    /// Configuration of the animation
+    #[serde(flatten)]
+    animation: AnimationOptions,
+    /// Padding in pixels to add to the sides of the map at the end of the transition
+    #[serde(skip_serializing_if = "Option::is_none")]
+    padding: Option<Padding>,
+}
+
+impl EaseToOptions {
+    /// Create new empty `EaseToOptions`
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Specify the target of the camera
+    #[must_use]
+    pub fn with_camera(self, camera: CameraOptions) -> Self {
+        Self { camera, ..self }
+    }
+
+    /// Specify the configuration of the animation
+    #[must_use]
+    pub fn with_animation(self, animation: AnimationOptions) -> Self {
+        Self { animation, ..self }
+    }
+
+    /// Specify the padding in pixels to add to the sides of the map at the end of the transition
+    #[must_use]
+    pub fn with_padding(self, padding: impl Into<Padding>) -> Self {
+        Self {
+            padding: Some(padding.into()),
+            ..self
+        }
+    }
+
+    /// Try converting `EaseToOptions` into the equivalent bindings type
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn as_js_value(&self) -> Result<bindings::EaseToOptions, Error> {
+        Ok(bindings::EaseToOptions::from(self.serialize(
+            &serde_wasm_bindgen::Serializer::json_compatible(),
+        )?))
+    }
+}
+
+impl From<CameraOptions> for EaseToOptions {
+    fn from(value: CameraOptions) -> Self {
+        Self::new().with_camera(value)
+    }
+}
+
+impl From<AnimationOptions> for EaseToOptions {
+    fn from(value: AnimationOptions) -> Self {
+        Self::new().with_animation(value)
+    }
+}
+
+impl TryFrom<EaseToOptions> for bindings::EaseToOptions {
+    type Error = Error;
+
+    fn try_from(value: EaseToOptions) -> Result<Self, Self::Error> {
+        value.as_js_value()
+    }
+}
+
+impl TryFrom<&EaseToOptions> for bindings::EaseToOptions {
+    type Error = Error;
+
+    fn try_from(value: &EaseToOptions) -> Result<Self, Self::Error> {
+        value.as_js_value()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_utils::{get_key_list_from_object, get_value_from_object};
+
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn ease_to_options_empty() {
+        let options_js = EaseToOptions::new()
+            .as_js_value()
+            .expect("Conversion from EaseToOptions to JS should work");
+
+        assert_eq!(get_key_list_from_object(&options_js).len(), 0);
+    }
+
+    #[wasm_bindgen_test]
+    fn ease_to_options_flattened() {
+        let options_js = EaseToOptions::new()
+            .with_camera(CameraOptions::new().with_zoom(3.).with_bearing(10))
+            .with_animation(AnimationOptions::new().with_duration(200))
+            .with_padding(15.)
+            .as_js_value()
+            .expect("Conversion from EaseToOptions to JS should work");
+        let mut keys = get_key_list_from_object(&options_js);
+        keys.sort();
+        let duration: f64 = get_value_from_object(&options_js, "duration")
+            .try_into()
+            .expect("Back conversion should work");
+
+        assert_eq!(keys, vec!["bearing", "duration", "padding", "zoom"]);
+        assert!((duration - 200.).abs() < 0.001);
+    }
+}
@@ -0,0 +1,118 @@
+//! Submodule for `FitBoundsOptions` and associated tests
+
+use serde::Serialize;
+
+use super::{AnimationOptions, Error, FlyToOptions};
+use crate::{bindings, interface::map_options::MapZoom};
+
+/// Options for an animated transition of the camera that fits a set of bounds in the viewport
+#[derive(Debug, PartialEq, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FitBoundsOptions {
+    /// Configuration of the flight
+    #[serde(flatten)]
+    fly_to: FlyToOptions,
+    /// Whether to use `ease_to` instead of `fly_to` for the transition. Defaults to `false`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    linear: Option<bool>,
+    /// The maximum zoom level to allow when the camera fits the bounds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_zoom: Option<MapZoom>,
+}
+
+impl FitBoundsOptions {
+    /// Create new empty `FitBoundsOptions`
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Specify the configuration of the flight
+    #[must_use]
+    pub fn with_fly_to(self, fly_to: FlyToOptions) -> Self {
+        Self { fly_to, ..self }
+    }
+
+    /// Use `ease_to` instead of `fly_to` for the transition
+    #[must_use]
+    pub fn with_linear(self) -> Self {
+        Self {
+            linear: Some(true),
+            ..self
+        }
+    }
+
+    /// Specify the maximum zoom level to allow when the camera fits the bounds
+    #[must_use]
+    pub fn with_max_zoom(self, max_zoom: impl Into<MapZoom>) -> Self {
+        Self {
+            max_zoom: Some(max_zoom.into()),
+            ..self
+        }
+    }
+
+    /// Try converting `FitBoundsOptions` into the equivalent bindings type
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn as_js_value(&self) -> Result<bindings::FitBoundsOptions, Error> {
+        Ok(bindings::FitBoundsOptions::from(self.serialize(
+            &serde_wasm_bindgen::Serializer::json_compatible(),
+        )?))
+    }
+}
+
+impl From<FlyToOptions> for FitBoundsOptions {
+    fn from(value: FlyToOptions) -> Self {
+        Self::new().with_fly_to(value)
+    }
+}
+
+impl From<AnimationOptions> for FitBoundsOptions {
+    fn from(value: AnimationOptions) -> Self {
+        Self::new().with_fly_to(FlyToOptions::new().with_animation(value))
+    }
+}
+
+impl TryFrom<FitBoundsOptions> for bindings::FitBoundsOptions {
+    type Error = Error;
+
+    fn try_from(value: FitBoundsOptions) -> Result<Self, Self::Error> {
+        value.as_js_value()
+    }
+}
+
+impl TryFrom<&FitBoundsOptions> for bindings::FitBoundsOptions {
+    type Error = Error;
+
+    fn try_from(value: &FitBoundsOptions) -> Result<Self, Self::Error> {
+        value.as_js_value()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_utils::get_key_list_from_object;
+
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn fit_bounds_options_flattened() {
+        let options_js = FitBoundsOptions::new()
+            .with_fly_to(
+                FlyToOptions::new()
+                    .with_animation(AnimationOptions::new().with_duration(100))
+                    .with_padding(10.),
+            )
+            .with_linear()
+            .with_max_zoom(8.)
+            .as_js_value()
+            .expect("Conversion from FitBoundsOptions to JS should work");
+        let mut keys = get_key_list_from_object(&options_js);
+        keys.sort();
+
+        assert_eq!(keys, vec!["duration", "linear", "maxZoom", "padding"]);
+    }
+}
@@ -0,0 +1,185 @@
+//! Submodule for `FlyToOptions` and associated tests
+
+use serde::Serialize;
+
+use super::{AnimationOptions, CameraOptions, Error, Padding};
+use crate::{bindings, interface::map_options::MapZoom};
+
+/// Options for a flying animated transition of the camera, zooming out and back in while panning
+#[derive(Debug, PartialEq, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FlyToOptions {
+    /// Target of the camera
+    #[serde(flatten)]
+    camera: CameraOptions,
+    /// Configuration of the animation
+    #[serde(flatten)]
+    animation: AnimationOptions,
+    /// The zooming "curve" that will occur along the flight path. Defaults to `1.42`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    curve: Option<f64>,
+    /// The zero-based zoom level at the peak of the flight path
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_zoom: Option<MapZoom>,
+    /// The average speed of the animation relative to `curve`. Defaults to `1.2`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    speed: Option<f64>,
+    /// The average speed of the animation measured in screenfuls per second. Overrides `speed`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    screen_speed: Option<f64>,
+    /// The maximum duration of the animation in milliseconds. If exceeded, the duration is set to
+    /// `0`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_duration: Option<f64>,
+    /// Padding in pixels to add to the sides of the map at the end of the transition
+    #[serde(skip_serializing_if = "Option::is_none")]
+    padding: Option<Padding>,
+}
+
+impl FlyToOptions {
+    /// Create new empty `FlyToOptions`
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Specify the target of the camera
+    #[must_use]
+    pub fn with_camera(self, camera: CameraOptions) -> Self {
+        Self { camera, ..self }
+    }
+
+    /// Specify the configuration of the animation
+    #[must_use]
+    pub fn with_animation(self, animation: AnimationOptions) -> Self {
+        Self { animation, ..self }
+    }
+
+    /// Specify the zooming "curve" that will occur along the flight path
+    #[must_use]
+    pub fn with_curve(self, curve: impl Into<f64>) -> Self {
+        Self {
+            curve: Some(curve.into()),
+            ..self
+        }
+    }
+
+    /// Specify the zero-based zoom level at the peak of the flight path
+    #[must_use]
+    pub fn with_min_zoom(self, min_zoom: impl Into<MapZoom>) -> Self {
+        Self {
+            min_zoom: Some(min_zoom.into()),
+            ..self
+        }
+    }
+
+    /// Specify the average speed of the animation relative to `curve`
+    #[must_use]
+    pub fn with_speed(self, speed: impl Into<f64>) -> Self {
+        Self {
+            speed: Some(speed.into()),
+            ..self
+        }
+    }
+
+    /// Specify the average speed of the animation measured in screenfuls per second
+    #[must_use]
+    pub fn with_screen_speed(self, screen_speed: impl Into<f64>) -> Self {
+        Self {
+            screen_speed: Some(screen_speed.into()),
+            ..self
+        }
+    }
+
+    /// Specify the maximum duration of the animation in milliseconds
+    #[must_use]
+    pub fn with_max_duration(self, max_duration: impl Into<f64>) -> Self {
+        Self {
+            max_duration: Some(max_duration.into()),
+            ..self
+        }
+    }
+
+    /// Specify the padding in pixels to add to the sides of the map at the end of the transition
+    #[must_use]
+    pub fn with_padding(self, padding: impl Into<Padding>) -> Self {
+        Self {
+            padding: Some(padding.into()),
+            ..self
+        }
+    }
+
+    /// Try converting `FlyToOptions` into the equivalent bindings type
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn as_js_value(&self) -> Result<bindings::FlyToOptions, Error> {
+        Ok(bindings::FlyToOptions::from(self.serialize(
+            &serde_wasm_bindgen::Serializer::json_compatible(),
+        )?))
+    }
+}
+
+impl From<CameraOptions> for FlyToOptions {
+    fn from(value: CameraOptions) -> Self {
+        Self::new().with_camera(value)
+    }
+}
+
+impl TryFrom<FlyToOptions> for bindings::FlyToOptions {
+    type Error = Error;
+
+    fn try_from(value: FlyToOptions) -> Result<Self, Self::Error> {
+        value.as_js_value()
+    }
+}
+
+impl TryFrom<&FlyToOptions> for bindings::FlyToOptions {
+    type Error = Error;
+
+    fn try_from(value: &FlyToOptions) -> Result<Self, Self::Error> {
+        value.as_js_value()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_utils::{get_key_list_from_object, get_value_from_object};
+
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn fly_to_options_flattened() {
+        let options_js = FlyToOptions::new()
+            .with_camera(CameraOptions::new().with_center([1., 2.]))
+            .with_animation(AnimationOptions::new().with_essential())
+            .with_curve(1.2)
+            .with_min_zoom(2.)
+            .with_speed(0.8)
+            .with_screen_speed(1.)
+            .with_max_duration(1000)
+            .as_js_value()
+            .expect("Conversion from FlyToOptions to JS should work");
+        let mut keys = get_key_list_from_object(&options_js);
+        keys.sort();
+        let max_duration: f64 = get_value_from_object(&options_js, "maxDuration")
+            .try_into()
+            .expect("Back conversion should work");
+
+        assert_eq!(
+            keys,
+            vec![
+                "center",
+                "curve",
+                "essential",
+                "maxDuration",
+                "minZoom",
+                "screenSpeed",
+                "speed"
+            ]
+        );
+        assert!((max_duration - 1000.).abs() < 0.001);
+    }
+}
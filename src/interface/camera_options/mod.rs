@@ -0,0 +1,272 @@
+//! Rusty interface to the bindings of `CameraOptions` and the related camera movement options of
+//! MapLibre GL JS
+
+mod animation_options;
+mod camera_transition;
+mod ease_to_options;
+mod fit_bounds_options;
+mod fly_to_options;
+mod padding;
+
+pub use animation_options::{AnimationOptions, Easing};
+pub use camera_transition::CameraTransition;
+pub use ease_to_options::EaseToOptions;
+pub use fit_bounds_options::FitBoundsOptions;
+pub use fly_to_options::FlyToOptions;
+pub use padding::{Padding, PaddingOptions};
+
+use serde::{Deserialize, Serialize};
+
+use super::Error;
+use crate::{
+    bindings,
+    interface::{LngLatLike, map_options::MapZoom},
+};
+
+/// Options to position the camera of a `Map`. Any unspecified field is left untouched
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CameraOptions {
+    /// The desired center
+    #[serde(skip_serializing_if = "Option::is_none")]
+    center: Option<LngLatLike>,
+    /// The elevation of the desired center, in meters above sea level
+    #[serde(skip_serializing_if = "Option::is_none")]
+    elevation: Option<f64>,
+    /// The desired zoom level
+    #[serde(skip_serializing_if = "Option::is_none")]
+    zoom: Option<MapZoom>,
+    /// The desired bearing in degrees counter-clockwise from north
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bearing: Option<f64>,
+    /// The desired pitch in degrees away from the plane of the screen
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pitch: Option<f64>,
+    /// The desired roll in degrees counter-clockwise about the camera boresight
+    #[serde(skip_serializing_if = "Option::is_none")]
+    roll: Option<f64>,
+    /// The point which remains fixed when `zoom` is specified
+    #[serde(skip_serializing_if = "Option::is_none")]
+    around: Option<LngLatLike>,
+}
+
+impl CameraOptions {
+    /// Create new empty `CameraOptions`
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Specify the desired center
+    #[must_use]
+    pub fn with_center(self, center: impl Into<LngLatLike>) -> Self {
+        Self {
+            center: Some(center.into()),
+            ..self
+        }
+    }
+
+    /// Specify the elevation of the desired center, in meters above sea level
+    #[must_use]
+    pub fn with_elevation(self, elevation: impl Into<f64>) -> Self {
+        Self {
+            elevation: Some(elevation.into()),
+            ..self
+        }
+    }
+
+    /// Specify the desired zoom level
+    #[must_use]
+    pub fn with_zoom(self, zoom: impl Into<MapZoom>) -> Self {
+        Self {
+            zoom: Some(zoom.into()),
+            ..self
+        }
+    }
+
+    /// Specify the desired bearing in degrees counter-clockwise from north
+    #[must_use]
+    pub fn with_bearing(self, bearing: impl Into<f64>) -> Self {
+        Self {
+            bearing: Some(bearing.into()),
+            ..self
+        }
+    }
+
+    /// Specify the desired pitch in degrees away from the plane of the screen
+    #[must_use]
+    pub fn with_pitch(self, pitch: impl Into<f64>) -> Self {
+        Self {
+            pitch: Some(pitch.into()),
+            ..self
+        }
+    }
+
+    /// Specify the desired roll in degrees counter-clockwise about the camera boresight
+    #[must_use]
+    pub fn with_roll(self, roll: impl Into<f64>) -> Self {
+        Self {
+            roll: Some(roll.into()),
+            ..self
+        }
+    }
+
+    /// Specify the point which remains fixed when `zoom` is specified
+    #[must_use]
+    pub fn with_around(self, around: impl Into<LngLatLike>) -> Self {
+        Self {
+            around: Some(around.into()),
+            ..self
+        }
+    }
+
+    /// Get the desired center, if specified
+    #[must_use]
+    pub const fn center(&self) -> Option<&LngLatLike> {
+        self.center.as_ref()
+    }
+
+    /// Get the elevation of the desired center, if specified
+    #[must_use]
+    pub const fn elevation(&self) -> Option<f64> {
+        self.elevation
+    }
+
+    /// Get the desired zoom level, if specified
+    #[must_use]
+    pub const fn zoom(&self) -> Option<MapZoom> {
+        self.zoom
+    }
+
+    /// Get the desired bearing, if specified
+    #[must_use]
+    pub const fn bearing(&self) -> Option<f64> {
+        self.bearing
+    }
+
+    /// Get the desired pitch, if specified
+    #[must_use]
+    pub const fn pitch(&self) -> Option<f64> {
+        self.pitch
+    }
+
+    /// Get the desired roll, if specified
+    #[must_use]
+    pub const fn roll(&self) -> Option<f64> {
+        self.roll
+    }
+
+    /// Try converting `CameraOptions` into the equivalent bindings type
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn as_js_value(&self) -> Result<bindings::CameraOptions, Error> {
+        Ok(bindings::CameraOptions::from(self.serialize(
+            &serde_wasm_bindgen::Serializer::json_compatible(),
+        )?))
+    }
+}
+
+impl TryFrom<bindings::CameraOptions> for CameraOptions {
+    type Error = Error;
+
+    fn try_from(value: bindings::CameraOptions) -> Result<Self, Self::Error> {
+        serde_wasm_bindgen::from_value(value.into())
+    }
+}
+
+impl TryFrom<CameraOptions> for bindings::CameraOptions {
+    type Error = Error;
+
+    fn try_from(value: CameraOptions) -> Result<Self, Self::Error> {
+        value.as_js_value()
+    }
+}
+
+impl TryFrom<&CameraOptions> for bindings::CameraOptions {
+    type Error = Error;
+
+    fn try_from(value: &CameraOptions) -> Result<Self, Self::Error> {
+        value.as_js_value()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_utils::{get_key_list_from_object, get_value_from_object};
+
+    use super::*;
+    use wasm_bindgen::JsValue;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn camera_options_empty() {
+        let options_js = CameraOptions::new()
+            .as_js_value()
+            .expect("Conversion from CameraOptions to JS should work");
+
+        assert_eq!(get_key_list_from_object(&options_js).len(), 0);
+    }
+
+    #[wasm_bindgen_test]
+    fn camera_options_with_center_and_zoom() {
+        let options = CameraOptions::new().with_center([1.2, 4.2]).with_zoom(3.2);
+        let options_js = options
+            .as_js_value()
+            .expect("Conversion from CameraOptions to JS should work");
+        let retrieved_center: LngLatLike =
+            bindings::LngLatLike::from(get_value_from_object(&options_js, "center"))
+                .try_into()
+                .expect("Back conversion of the center should work");
+        let retrieved_zoom: MapZoom = get_value_from_object(&options_js, "zoom")
+            .try_into()
+            .expect("Back conversion of the zoom should work");
+        let keys = get_key_list_from_object(&options_js);
+
+        assert_eq!(keys.len(), 2);
+        assert_eq!(options.center(), Some(&retrieved_center));
+        assert_eq!(options.zoom(), Some(retrieved_zoom));
+    }
+
+    #[wasm_bindgen_test]
+    fn camera_options_with_angles() {
+        let options = CameraOptions::new()
+            .with_bearing(20)
+            .with_pitch(30)
+            .with_roll(40)
+            .with_elevation(50);
+        let options_js = options
+            .as_js_value()
+            .expect("Conversion from CameraOptions to JS should work");
+        let keys = get_key_list_from_object(&options_js);
+
+        assert_eq!(keys.len(), 4);
+        for (key, expected) in [
+            ("bearing", 20.),
+            ("pitch", 30.),
+            ("roll", 40.),
+            ("elevation", 50.),
+        ] {
+            let retrieved: f64 = get_value_from_object(&options_js, key)
+                .try_into()
+                .expect("Back conversion should work");
+            assert!((retrieved - expected).abs() < 0.001);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn camera_options_back_conversion() {
+        let options = CameraOptions::new()
+            .with_around([1.2, 4.2])
+            .with_bearing(20);
+        let options_js = options
+            .as_js_value()
+            .expect("Conversion from CameraOptions to JS should work");
+        let retrieved: CameraOptions = bindings::CameraOptions::from(JsValue::from(options_js))
+            .try_into()
+            .expect("Back conversion should work");
+
+        assert_eq!(options, retrieved);
+    }
+}
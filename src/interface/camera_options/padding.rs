@@ -0,0 +1,118 @@
+//! Submodule for the `Padding` field of the camera transition options and associated tests
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsValue;
+
+/// Padding in pixels to add to the sides of the map when positioning the camera
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Padding {
+    /// Same padding for all the sides
+    Uniform(f64),
+    /// Different padding for each side. Unspecified sides are left untouched
+    Sides(PaddingOptions),
+}
+
+/// Padding in pixels for each side of the map
+#[derive(Debug, PartialEq, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct PaddingOptions {
+    /// Padding from the top of the map
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top: Option<f64>,
+    /// Padding from the bottom of the map
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bottom: Option<f64>,
+    /// Padding from the left of the map
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub left: Option<f64>,
+    /// Padding from the right of the map
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub right: Option<f64>,
+}
+
+impl Padding {
+    /// Try converting `Padding` into the equivalent `JsValue`
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn as_js_value(&self) -> Result<JsValue, super::Error> {
+        self.serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+    }
+}
+
+impl From<f64> for Padding {
+    fn from(value: f64) -> Self {
+        Self::Uniform(value)
+    }
+}
+
+impl From<PaddingOptions> for Padding {
+    fn from(value: PaddingOptions) -> Self {
+        Self::Sides(value)
+    }
+}
+
+impl TryFrom<JsValue> for Padding {
+    type Error = super::Error;
+
+    fn try_from(value: JsValue) -> Result<Self, Self::Error> {
+        serde_wasm_bindgen::from_value(value)
+    }
+}
+
+impl TryFrom<Padding> for JsValue {
+    type Error = super::Error;
+
+    fn try_from(value: Padding) -> Result<Self, Self::Error> {
+        value.as_js_value()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_utils::{get_key_list_from_object, get_value_from_object};
+
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn padding_uniform_conversion() {
+        let padding: Padding = 20.0.into();
+        let padding_js = padding
+            .as_js_value()
+            .expect("Should be able to convert to JsValue");
+        let padding_retrieved =
+            Padding::try_from(padding_js.clone()).expect("Should be able to back convert padding");
+
+        assert!((padding_js.as_f64().expect("Converted should be a f64") - 20.0).abs() < 0.01);
+        assert_eq!(padding, padding_retrieved);
+    }
+
+    #[wasm_bindgen_test]
+    fn padding_sides_conversion() {
+        let padding: Padding = PaddingOptions {
+            top: Some(10.),
+            left: Some(5.),
+            ..Default::default()
+        }
+        .into();
+        let padding_js = padding
+            .as_js_value()
+            .expect("Should be able to convert to JsValue");
+        let padding_retrieved =
+            Padding::try_from(padding_js.clone()).expect("Should be able to back convert padding");
+        let keys = get_key_list_from_object(&padding_js);
+
+        assert_eq!(keys.len(), 2);
+        assert!(
+            (get_value_from_object(&padding_js, "top")
+                .as_f64()
+                .expect("top should be a f64")
+                - 10.)
+                .abs()
+                < 0.01
+        );
+        assert_eq!(padding, padding_retrieved);
+    }
+}
@@ -0,0 +1,151 @@
+//! Submodule for `AttributionControl` and its options
+
+use serde::{Deserialize, Serialize};
+
+use super::{Error, impl_control};
+use crate::bindings;
+
+/// Attribution added to the ones of the sources of the map
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum CustomAttribution {
+    /// Single attribution, which may contain HTML
+    Single(String),
+    /// List of attributions, which may contain HTML
+    Multiple(Vec<String>),
+}
+
+impl From<&str> for CustomAttribution {
+    fn from(value: &str) -> Self {
+        Self::Single(value.to_string())
+    }
+}
+
+impl From<String> for CustomAttribution {
+    fn from(value: String) -> Self {
+        Self::Single(value)
+    }
+}
+
+impl From<Vec<String>> for CustomAttribution {
+    fn from(value: Vec<String>) -> Self {
+        Self::Multiple(value)
+    }
+}
+
+impl From<Vec<&str>> for CustomAttribution {
+    fn from(value: Vec<&str>) -> Self {
+        Self::Multiple(value.into_iter().map(ToString::to_string).collect())
+    }
+}
+
+/// Options of an `AttributionControl`
+#[derive(Debug, PartialEq, Eq, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AttributionControlOptions {
+    /// Whether the attribution is shown in its compact form, collapsed behind an info button.
+    /// Defaults to the width of the map being smaller than `640` pixels
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    compact: Option<bool>,
+    /// Attributions to show in addition to the ones of the sources of the map
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    custom_attribution: Option<CustomAttribution>,
+}
+
+impl AttributionControlOptions {
+    /// Create new empty `AttributionControlOptions`
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Always show the attribution in its compact form
+    #[must_use]
+    pub fn with_compact(self) -> Self {
+        Self {
+            compact: Some(true),
+            ..self
+        }
+    }
+
+    /// Never show the attribution in its compact form
+    #[must_use]
+    pub fn without_compact(self) -> Self {
+        Self {
+            compact: Some(false),
+            ..self
+        }
+    }
+
+    /// Specify attributions to show in addition to the ones of the sources of the map
+    #[must_use]
+    pub fn with_custom_attribution(self, custom_attribution: impl Into<CustomAttribution>) -> Self {
+        Self {
+            custom_attribution: Some(custom_attribution.into()),
+            ..self
+        }
+    }
+
+    /// Try converting `AttributionControlOptions` into the equivalent `JsValue`
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn as_js_value(&self) -> Result<wasm_bindgen::JsValue, Error> {
+        self.serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+    }
+}
+
+/// Control presenting the attribution of the map
+///
+/// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/AttributionControl/>
+#[derive(Debug, PartialEq, Clone)]
+pub struct AttributionControl {
+    /// JS object that the struct wraps
+    js_value: bindings::AttributionControl,
+}
+
+impl AttributionControl {
+    /// Create an `AttributionControl` with the specified options
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors generated on converting the given options into JS and the ones thrown by
+    /// MapLibre on creating the control
+    pub fn new(options: &AttributionControlOptions) -> Result<Self, Error> {
+        let js_value = bindings::AttributionControl::new(&options.as_js_value()?)?;
+        Ok(Self { js_value })
+    }
+}
+
+impl_control!(AttributionControl);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn attribution_control_options_serialization() {
+        let single = AttributionControlOptions::new()
+            .with_compact()
+            .with_custom_attribution("© Contributors");
+        let multiple = AttributionControlOptions::new().with_custom_attribution(vec!["A", "B"]);
+
+        assert_eq!(
+            serde_json::to_value(&single).expect("Serializing should work"),
+            json!({"compact": true, "customAttribution": "© Contributors"})
+        );
+        assert_eq!(
+            serde_json::to_value(&multiple).expect("Serializing should work"),
+            json!({"customAttribution": ["A", "B"]})
+        );
+        assert_eq!(
+            serde_json::from_value::<AttributionControlOptions>(
+                json!({"customAttribution": ["A", "B"]})
+            )
+            .expect("Deserializing should work"),
+            multiple
+        );
+    }
+}
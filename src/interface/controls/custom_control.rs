@@ -0,0 +1,197 @@
+//! Submodule for the `IControl` trait, the `CustomControl` handle and associated tests
+
+use std::{cell::RefCell, rc::Rc};
+
+use wasm_bindgen::prelude::*;
+use web_sys::{
+    HtmlElement,
+    js_sys::{Object, Reflect},
+};
+
+use super::{Control, ControlPosition, Error};
+use crate::{bindings, interface::Map};
+
+/// Control of a `Map` implemented in Rust, such as a layer switcher or a legend. Wrap it with
+/// `CustomControl::new` to add it to a map.
+///
+/// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/interfaces/IControl/>
+pub trait IControl: 'static {
+    /// Called when the control is added to the given map. Returns the element of the control,
+    /// which MapLibre places in the corner of the map given by its position
+    fn on_add(&mut self, map: &Map) -> HtmlElement;
+
+    /// Called when the control is removed from the given map. The element returned by `on_add`
+    /// should be removed from the DOM here
+    fn on_remove(&mut self, map: &Map);
+
+    /// Position used when the control is added without an explicit one. `None` lets MapLibre
+    /// choose, which defaults to the top right corner
+    fn default_position(&self) -> Option<ControlPosition> {
+        None
+    }
+}
+
+/// Handle to an `IControl` bridged to the JS object given to MapLibre.
+///
+/// The control is removed from the map it was added to and its closures are freed when the
+/// handle is dropped
+#[derive(Debug)]
+#[must_use = "the control is removed as soon as the handle is dropped"]
+pub struct CustomControl {
+    /// JS object implementing `IControl` which is given to MapLibre
+    js_value: bindings::IControl,
+    /// Map where the control is currently added, if any
+    map: Rc<RefCell<Option<bindings::Map>>>,
+    /// Closure called by MapLibre when the control is added
+    _on_add: Closure<dyn FnMut(bindings::Map) -> HtmlElement>,
+    /// Closure called by MapLibre when the control is removed
+    _on_remove: Closure<dyn FnMut(bindings::Map)>,
+    /// Closure called by MapLibre to get the default position of the control, if it has one
+    _get_default_position: Option<Closure<dyn Fn() -> String>>,
+}
+
+impl CustomControl {
+    /// Bridge the given `IControl` into a control which can be added with `Map::add_control`
+    ///
+    /// # Errors
+    ///
+    /// Propagates the errors generated on building the JS object of the control
+    pub fn new(control: impl IControl) -> Result<Self, Error> {
+        let default_position = control.default_position();
+        let control = Rc::new(RefCell::new(control));
+        let map = Rc::new(RefCell::new(None));
+
+        let on_add = Closure::<dyn FnMut(bindings::Map) -> HtmlElement>::new({
+            let control = control.clone();
+            let map = map.clone();
+            move |js_map: bindings::Map| {
+                *map.borrow_mut() = Some(js_map.clone());
+                control.borrow_mut().on_add(&js_map.into())
+            }
+        });
+        let on_remove = Closure::<dyn FnMut(bindings::Map)>::new({
+            let map = map.clone();
+            move |js_map: bindings::Map| {
+                map.borrow_mut().take();
+                control.borrow_mut().on_remove(&js_map.into());
+            }
+        });
+
+        let object = Object::new();
+        Reflect::set(&object, &"onAdd".into(), on_add.as_ref())?;
+        Reflect::set(&object, &"onRemove".into(), on_remove.as_ref())?;
+        let get_default_position = default_position.map(|position| {
+            Closure::<dyn Fn() -> String>::new(move || position.as_str().to_string())
+        });
+        if let Some(get_default_position) = &get_default_position {
+            Reflect::set(
+                &object,
+                &"getDefaultPosition".into(),
+                get_default_position.as_ref(),
+            )?;
+        }
+
+        Ok(Self {
+            js_value: object.unchecked_into(),
+            map,
+            _on_add: on_add,
+            _on_remove: on_remove,
+            _get_default_position: get_default_position,
+        })
+    }
+
+    /// Returns whether the control is currently added to a map
+    #[must_use]
+    pub fn is_added(&self) -> bool {
+        self.map.borrow().is_some()
+    }
+}
+
+impl Control for CustomControl {
+    fn as_control(&self) -> &bindings::IControl {
+        &self.js_value
+    }
+}
+
+impl Drop for CustomControl {
+    fn drop(&mut self) {
+        let map = self.map.borrow_mut().take();
+        if let Some(map) = map {
+            map.remove_control(&self.js_value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        interface::MapOptions,
+        test_utils::{gen_html_element, load_maplibre_gl},
+    };
+    use std::cell::Cell;
+    use wasm_bindgen_test::*;
+
+    struct Legend {
+        element: Option<HtmlElement>,
+        removed: Rc<Cell<bool>>,
+    }
+
+    impl IControl for Legend {
+        fn on_add(&mut self, _map: &Map) -> HtmlElement {
+            let element = gen_html_element("div").cloned_ref();
+            element.set_class_name("maplibregl-ctrl legend");
+            self.element = Some(element.clone());
+            element
+        }
+
+        fn on_remove(&mut self, _map: &Map) {
+            if let Some(element) = self.element.take() {
+                element.remove();
+            }
+            self.removed.set(true);
+        }
+
+        fn default_position(&self) -> Option<ControlPosition> {
+            Some(ControlPosition::BottomLeft)
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn custom_control_add_and_drop() {
+        load_maplibre_gl().await;
+        let html_element = gen_html_element("div").cloned_ref();
+        let map = MapOptions::new(html_element.clone())
+            .build()
+            .expect("Creating a map should work");
+        let removed = Rc::new(Cell::new(false));
+        let control = CustomControl::new(Legend {
+            element: None,
+            removed: removed.clone(),
+        })
+        .expect("Creating a custom control should work");
+
+        map.add_control(&control, None);
+
+        assert!(control.is_added());
+        assert!(map.has_control(&control));
+        assert!(
+            html_element
+                .query_selector(".maplibregl-ctrl-bottom-left .legend")
+                .expect("Querying should work")
+                .is_some()
+        );
+
+        let js_control = control.as_control().clone();
+        drop(control);
+
+        assert!(removed.get());
+        assert!(!map.into_inner().has_control(&js_control));
+        assert!(
+            html_element
+                .query_selector(".legend")
+                .expect("Querying should work")
+                .is_none()
+        );
+    }
+}
@@ -0,0 +1,80 @@
+//! Submodule for `FullscreenControl` and its options
+
+use serde::{Serialize, Serializer};
+use web_sys::HtmlElement;
+
+use super::{Error, impl_control};
+use crate::bindings;
+
+/// Options of a `FullscreenControl`
+#[derive(Debug, PartialEq, Eq, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FullscreenControlOptions {
+    /// Element made fullscreen. Defaults to the container of the map
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_container"
+    )]
+    container: Option<HtmlElement>,
+}
+
+/// Serialize the fullscreen container passing the JS object through unchanged
+#[allow(clippy::ref_option)]
+fn serialize_container<S: Serializer>(
+    container: &Option<HtmlElement>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match container {
+        Some(container) => serde_wasm_bindgen::preserve::serialize(container, serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+impl FullscreenControlOptions {
+    /// Create new empty `FullscreenControlOptions`
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Specify the element made fullscreen instead of the container of the map
+    #[must_use]
+    pub fn with_container(self, container: impl Into<HtmlElement>) -> Self {
+        Self {
+            container: Some(container.into()),
+        }
+    }
+
+    /// Try converting `FullscreenControlOptions` into the equivalent `JsValue`
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn as_js_value(&self) -> Result<wasm_bindgen::JsValue, Error> {
+        self.serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+    }
+}
+
+/// Control containing a button to toggle the map in and out of fullscreen mode
+///
+/// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/FullscreenControl/>
+#[derive(Debug, PartialEq, Clone)]
+pub struct FullscreenControl {
+    /// JS object that the struct wraps
+    js_value: bindings::FullscreenControl,
+}
+
+impl FullscreenControl {
+    /// Create a `FullscreenControl` with the specified options
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors generated on converting the given options into JS and the ones thrown by
+    /// MapLibre on creating the control
+    pub fn new(options: &FullscreenControlOptions) -> Result<Self, Error> {
+        let js_value = bindings::FullscreenControl::new(&options.as_js_value()?)?;
+        Ok(Self { js_value })
+    }
+}
+
+impl_control!(FullscreenControl);
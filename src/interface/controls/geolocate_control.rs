@@ -0,0 +1,172 @@
+//! Submodule for `GeolocateControl` and its options
+
+use serde::Serialize;
+
+use super::{Error, impl_control};
+use crate::{bindings, interface::camera_options::FitBoundsOptions};
+
+/// Options of the geolocation request made by a `GeolocateControl`
+///
+/// MDN docs: <https://developer.mozilla.org/en-US/docs/Web/API/Geolocation/getCurrentPosition#options>
+#[derive(Debug, PartialEq, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionOptions {
+    /// Whether the most accurate position is requested, at a higher cost. Defaults to `false`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enable_high_accuracy: Option<bool>,
+    /// Maximum age in milliseconds of a cached position to be accepted. Defaults to `0`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    maximum_age: Option<f64>,
+    /// Maximum time in milliseconds allowed to get the position. Defaults to `6000`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timeout: Option<f64>,
+}
+
+impl PositionOptions {
+    /// Create new empty `PositionOptions`
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request the most accurate position
+    #[must_use]
+    pub const fn with_high_accuracy(self) -> Self {
+        Self {
+            enable_high_accuracy: Some(true),
+            ..self
+        }
+    }
+
+    /// Specify the maximum age in milliseconds of a cached position to be accepted
+    #[must_use]
+    pub fn with_maximum_age(self, maximum_age: impl Into<f64>) -> Self {
+        Self {
+            maximum_age: Some(maximum_age.into()),
+            ..self
+        }
+    }
+
+    /// Specify the maximum time in milliseconds allowed to get the position
+    #[must_use]
+    pub fn with_timeout(self, timeout: impl Into<f64>) -> Self {
+        Self {
+            timeout: Some(timeout.into()),
+            ..self
+        }
+    }
+}
+
+/// Options of a `GeolocateControl`
+#[derive(Debug, PartialEq, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GeolocateControlOptions {
+    /// Options of the geolocation request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    position_options: Option<PositionOptions>,
+    /// Options of the camera transition to the location of the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fit_bounds_options: Option<FitBoundsOptions>,
+    /// Whether the control keeps tracking the location of the user. Defaults to `false`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    track_user_location: Option<bool>,
+    /// Whether a circle showing the accuracy of the location is drawn. Defaults to `true`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    show_accuracy_circle: Option<bool>,
+    /// Whether a dot is drawn at the location of the user. Defaults to `true`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    show_user_location: Option<bool>,
+}
+
+impl GeolocateControlOptions {
+    /// Create new empty `GeolocateControlOptions`
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Specify the options of the geolocation request
+    #[must_use]
+    pub fn with_position_options(self, position_options: PositionOptions) -> Self {
+        Self {
+            position_options: Some(position_options),
+            ..self
+        }
+    }
+
+    /// Specify the options of the camera transition to the location of the user
+    #[must_use]
+    pub fn with_fit_bounds_options(self, fit_bounds_options: FitBoundsOptions) -> Self {
+        Self {
+            fit_bounds_options: Some(fit_bounds_options),
+            ..self
+        }
+    }
+
+    /// Keep tracking the location of the user
+    #[must_use]
+    pub fn with_track_user_location(self) -> Self {
+        Self {
+            track_user_location: Some(true),
+            ..self
+        }
+    }
+
+    /// Don't draw a circle showing the accuracy of the location
+    #[must_use]
+    pub fn without_accuracy_circle(self) -> Self {
+        Self {
+            show_accuracy_circle: Some(false),
+            ..self
+        }
+    }
+
+    /// Don't draw a dot at the location of the user
+    #[must_use]
+    pub fn without_user_location(self) -> Self {
+        Self {
+            show_user_location: Some(false),
+            ..self
+        }
+    }
+
+    /// Try converting `GeolocateControlOptions` into the equivalent `JsValue`
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn as_js_value(&self) -> Result<wasm_bindgen::JsValue, Error> {
+        self.serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+    }
+}
+
+/// Control containing a button that locates the user on the map
+///
+/// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/GeolocateControl/>
+#[derive(Debug, PartialEq, Clone)]
+pub struct GeolocateControl {
+    /// JS object that the struct wraps
+    js_value: bindings::GeolocateControl,
+}
+
+impl GeolocateControl {
+    /// Create a `GeolocateControl` with the specified options
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors generated on converting the given options into JS and the ones thrown by
+    /// MapLibre on creating the control
+    pub fn new(options: &GeolocateControlOptions) -> Result<Self, Error> {
+        let js_value = bindings::GeolocateControl::new(&options.as_js_value()?)?;
+        Ok(Self { js_value })
+    }
+
+    /// Requests the location of the user and moves the map to it. Returns `false` if the control
+    /// hasn't been added to a map yet
+    #[must_use]
+    pub fn trigger(&self) -> bool {
+        self.js_value.trigger()
+    }
+}
+
+impl_control!(GeolocateControl);
@@ -0,0 +1,27 @@
+//! Submodule for `GlobeControl`
+
+use super::{Error, impl_control};
+use crate::bindings;
+
+/// Control containing a button to toggle the map projection between mercator and globe
+///
+/// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/GlobeControl/>
+#[derive(Debug, PartialEq, Clone)]
+pub struct GlobeControl {
+    /// JS object that the struct wraps
+    js_value: bindings::GlobeControl,
+}
+
+impl GlobeControl {
+    /// Create a `GlobeControl`
+    ///
+    /// # Errors
+    ///
+    /// Propagates the errors thrown by MapLibre on creating the control
+    pub fn new() -> Result<Self, Error> {
+        let js_value = bindings::GlobeControl::new()?;
+        Ok(Self { js_value })
+    }
+}
+
+impl_control!(GlobeControl);
@@ -0,0 +1,73 @@
+//! Submodule for `LogoControl` and its options
+
+use serde::Serialize;
+
+use super::{Error, impl_control};
+use crate::bindings;
+
+/// Options of a `LogoControl`
+#[derive(Debug, PartialEq, Eq, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogoControlOptions {
+    /// Whether the logo is shown in its compact form. Defaults to the width of the map being
+    /// smaller than `640` pixels
+    #[serde(skip_serializing_if = "Option::is_none")]
+    compact: Option<bool>,
+}
+
+impl LogoControlOptions {
+    /// Create new empty `LogoControlOptions`
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Always show the logo in its compact form
+    #[must_use]
+    pub const fn with_compact(self) -> Self {
+        Self {
+            compact: Some(true),
+        }
+    }
+
+    /// Never show the logo in its compact form
+    #[must_use]
+    pub const fn without_compact(self) -> Self {
+        Self {
+            compact: Some(false),
+        }
+    }
+
+    /// Try converting `LogoControlOptions` into the equivalent `JsValue`
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn as_js_value(&self) -> Result<wasm_bindgen::JsValue, Error> {
+        self.serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+    }
+}
+
+/// Control displaying the MapLibre logo
+///
+/// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/LogoControl/>
+#[derive(Debug, PartialEq, Clone)]
+pub struct LogoControl {
+    /// JS object that the struct wraps
+    js_value: bindings::LogoControl,
+}
+
+impl LogoControl {
+    /// Create a `LogoControl` with the specified options
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors generated on converting the given options into JS and the ones thrown by
+    /// MapLibre on creating the control
+    pub fn new(options: &LogoControlOptions) -> Result<Self, Error> {
+        let js_value = bindings::LogoControl::new(&options.as_js_value()?)?;
+        Ok(Self { js_value })
+    }
+}
+
+impl_control!(LogoControl);
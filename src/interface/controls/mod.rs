@@ -0,0 +1,109 @@
+//! Rusty interface to the built-in controls of MapLibre GL JS and the types used to add them to
+//! a `Map`
+
+mod attribution_control;
+mod custom_control;
+mod fullscreen_control;
+mod geolocate_control;
+mod globe_control;
+mod logo_control;
+mod navigation_control;
+mod scale_control;
+mod terrain_control;
+
+pub use attribution_control::{AttributionControl, AttributionControlOptions, CustomAttribution};
+pub use custom_control::{CustomControl, IControl};
+pub use fullscreen_control::{FullscreenControl, FullscreenControlOptions};
+pub use geolocate_control::{GeolocateControl, GeolocateControlOptions, PositionOptions};
+pub use globe_control::GlobeControl;
+pub use logo_control::{LogoControl, LogoControlOptions};
+pub use navigation_control::{NavigationControl, NavigationControlOptions};
+pub use scale_control::{ScaleControl, ScaleControlOptions, ScaleUnit};
+pub use terrain_control::{TerrainControl, TerrainControlOptions};
+
+use serde::{Deserialize, Serialize};
+
+use super::Error;
+use crate::bindings;
+
+/// Control which can be added to a `Map` with `Map::add_control`, either a built-in one or a
+/// `CustomControl` implemented in Rust
+pub trait Control {
+    /// Get the JS object implementing `IControl` which is given to MapLibre
+    fn as_control(&self) -> &bindings::IControl;
+}
+
+/// Corner of the map where a control is placed
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ControlPosition {
+    /// Top left corner of the map
+    TopLeft,
+    /// Top right corner of the map
+    TopRight,
+    /// Bottom left corner of the map
+    BottomLeft,
+    /// Bottom right corner of the map
+    BottomRight,
+}
+
+impl ControlPosition {
+    /// Get the name of the position in MapLibre
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::TopLeft => "top-left",
+            Self::TopRight => "top-right",
+            Self::BottomLeft => "bottom-left",
+            Self::BottomRight => "bottom-right",
+        }
+    }
+}
+
+/// Implement the conversions and the `Control` trait shared by the wrappers of the built-in
+/// controls
+macro_rules! impl_control {
+    ($name:ident) => {
+        impl $name {
+            /// Get the underlying JS object
+            #[must_use]
+            pub fn into_inner(self) -> bindings::$name {
+                self.js_value
+            }
+        }
+
+        impl From<bindings::$name> for $name {
+            fn from(value: bindings::$name) -> Self {
+                Self { js_value: value }
+            }
+        }
+
+        impl super::Control for $name {
+            fn as_control(&self) -> &bindings::IControl {
+                self.js_value.as_ref()
+            }
+        }
+    };
+}
+
+use impl_control;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn control_position_names() {
+        for position in [
+            ControlPosition::TopLeft,
+            ControlPosition::TopRight,
+            ControlPosition::BottomLeft,
+            ControlPosition::BottomRight,
+        ] {
+            assert_eq!(
+                serde_json::to_value(position).expect("Serializing should work"),
+                position.as_str()
+            );
+        }
+    }
+}
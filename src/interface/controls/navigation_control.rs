@@ -0,0 +1,101 @@
+//! Submodule for `NavigationControl` and its options
+
+use serde::Serialize;
+
+use super::{Error, impl_control};
+use crate::bindings;
+
+/// Options of a `NavigationControl`
+#[derive(Debug, PartialEq, Eq, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NavigationControlOptions {
+    /// Whether the compass button is shown. Defaults to `true`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    show_compass: Option<bool>,
+    /// Whether the zoom in and zoom out buttons are shown. Defaults to `true`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    show_zoom: Option<bool>,
+    /// Whether the pitch is visualized by rotating the compass on its X axis. Defaults to `false`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visualize_pitch: Option<bool>,
+    /// Whether the roll is visualized by rotating the compass on its Z axis. Defaults to `true`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visualize_roll: Option<bool>,
+}
+
+impl NavigationControlOptions {
+    /// Create new empty `NavigationControlOptions`
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Hide the compass button
+    #[must_use]
+    pub const fn without_compass(self) -> Self {
+        Self {
+            show_compass: Some(false),
+            ..self
+        }
+    }
+
+    /// Hide the zoom in and zoom out buttons
+    #[must_use]
+    pub const fn without_zoom(self) -> Self {
+        Self {
+            show_zoom: Some(false),
+            ..self
+        }
+    }
+
+    /// Visualize the pitch by rotating the compass on its X axis
+    #[must_use]
+    pub const fn with_visualize_pitch(self) -> Self {
+        Self {
+            visualize_pitch: Some(true),
+            ..self
+        }
+    }
+
+    /// Don't visualize the roll by rotating the compass on its Z axis
+    #[must_use]
+    pub const fn without_visualize_roll(self) -> Self {
+        Self {
+            visualize_roll: Some(false),
+            ..self
+        }
+    }
+
+    /// Try converting `NavigationControlOptions` into the equivalent `JsValue`
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn as_js_value(&self) -> Result<wasm_bindgen::JsValue, Error> {
+        self.serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+    }
+}
+
+/// Control containing zoom buttons and a compass
+///
+/// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/NavigationControl/>
+#[derive(Debug, PartialEq, Clone)]
+pub struct NavigationControl {
+    /// JS object that the struct wraps
+    js_value: bindings::NavigationControl,
+}
+
+impl NavigationControl {
+    /// Create a `NavigationControl` with the specified options
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors generated on converting the given options into JS and the ones thrown by
+    /// MapLibre on creating the control
+    pub fn new(options: &NavigationControlOptions) -> Result<Self, Error> {
+        let js_value = bindings::NavigationControl::new(&options.as_js_value()?)?;
+        Ok(Self { js_value })
+    }
+}
+
+impl_control!(NavigationControl);
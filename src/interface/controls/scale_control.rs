@@ -0,0 +1,107 @@
+//! Submodule for `ScaleControl` and its options
+
+use serde::{Deserialize, Serialize};
+
+use super::{Error, impl_control};
+use crate::bindings;
+
+/// Unit of the distance displayed by a `ScaleControl`
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScaleUnit {
+    /// Miles and feet
+    Imperial,
+    /// Kilometers and meters
+    Metric,
+    /// Nautical miles
+    Nautical,
+}
+
+impl ScaleUnit {
+    /// Get the name of the unit in MapLibre
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Imperial => "imperial",
+            Self::Metric => "metric",
+            Self::Nautical => "nautical",
+        }
+    }
+}
+
+/// Options of a `ScaleControl`
+#[derive(Debug, PartialEq, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScaleControlOptions {
+    /// Maximum length of the scale in pixels. Defaults to `100`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_width: Option<f64>,
+    /// Unit of the displayed distance. Defaults to `metric`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unit: Option<ScaleUnit>,
+}
+
+impl ScaleControlOptions {
+    /// Create new empty `ScaleControlOptions`
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Specify the maximum length of the scale in pixels
+    #[must_use]
+    pub fn with_max_width(self, max_width: impl Into<f64>) -> Self {
+        Self {
+            max_width: Some(max_width.into()),
+            ..self
+        }
+    }
+
+    /// Specify the unit of the displayed distance
+    #[must_use]
+    pub const fn with_unit(self, unit: ScaleUnit) -> Self {
+        Self {
+            unit: Some(unit),
+            ..self
+        }
+    }
+
+    /// Try converting `ScaleControlOptions` into the equivalent `JsValue`
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn as_js_value(&self) -> Result<wasm_bindgen::JsValue, Error> {
+        self.serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+    }
+}
+
+/// Control displaying the ratio of a distance on the map to the corresponding distance on the
+/// ground
+///
+/// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/ScaleControl/>
+#[derive(Debug, PartialEq, Clone)]
+pub struct ScaleControl {
+    /// JS object that the struct wraps
+    js_value: bindings::ScaleControl,
+}
+
+impl ScaleControl {
+    /// Create a `ScaleControl` with the specified options
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors generated on converting the given options into JS and the ones thrown by
+    /// MapLibre on creating the control
+    pub fn new(options: &ScaleControlOptions) -> Result<Self, Error> {
+        let js_value = bindings::ScaleControl::new(&options.as_js_value()?)?;
+        Ok(Self { js_value })
+    }
+
+    /// Sets the unit of the displayed distance
+    pub fn set_unit(&self, unit: ScaleUnit) {
+        self.js_value.set_unit(unit.as_str());
+    }
+}
+
+impl_control!(ScaleControl);
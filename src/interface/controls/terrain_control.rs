@@ -0,0 +1,72 @@
+//! Submodule for `TerrainControl` and its options
+
+use serde::Serialize;
+
+use super::{Error, impl_control};
+use crate::bindings;
+
+/// Options of a `TerrainControl`, being the terrain enabled by its button
+///
+/// MapLibre docs: <https://maplibre.org/maplibre-style-spec/terrain/>
+#[derive(Debug, PartialEq, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TerrainControlOptions {
+    /// Identifier of the raster DEM source used for the terrain
+    source: String,
+    /// Exaggeration of the elevation of the terrain. Defaults to `1`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exaggeration: Option<f64>,
+}
+
+impl TerrainControlOptions {
+    /// Create new `TerrainControlOptions` using the raster DEM source with the given identifier
+    #[must_use]
+    pub fn new(source: impl Into<String>) -> Self {
+        Self {
+            source: source.into(),
+            exaggeration: None,
+        }
+    }
+
+    /// Specify the exaggeration of the elevation of the terrain
+    #[must_use]
+    pub fn with_exaggeration(self, exaggeration: impl Into<f64>) -> Self {
+        Self {
+            exaggeration: Some(exaggeration.into()),
+            ..self
+        }
+    }
+
+    /// Try converting `TerrainControlOptions` into the equivalent `JsValue`
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn as_js_value(&self) -> Result<wasm_bindgen::JsValue, Error> {
+        self.serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+    }
+}
+
+/// Control containing a button to turn the terrain on and off
+///
+/// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/TerrainControl/>
+#[derive(Debug, PartialEq, Clone)]
+pub struct TerrainControl {
+    /// JS object that the struct wraps
+    js_value: bindings::TerrainControl,
+}
+
+impl TerrainControl {
+    /// Create a `TerrainControl` with the specified options
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors generated on converting the given options into JS and the ones thrown by
+    /// MapLibre on creating the control
+    pub fn new(options: &TerrainControlOptions) -> Result<Self, Error> {
+        let js_value = bindings::TerrainControl::new(&options.as_js_value()?)?;
+        Ok(Self { js_value })
+    }
+}
+
+impl_control!(TerrainControl);
@@ -0,0 +1,90 @@
+//! Submodule for `ErrorEvent`
+
+use wasm_bindgen::prelude::*;
+use web_sys::js_sys;
+
+use crate::bindings;
+
+/// Event fired by the map when an error occurs, such as a style or a resource failing to load
+#[derive(Debug, Clone, PartialEq)]
+pub struct ErrorEvent {
+    /// JS object that the struct wraps
+    js_value: bindings::ErrorEvent,
+}
+
+impl ErrorEvent {
+    /// Get the name of the event
+    #[must_use]
+    pub fn event_type(&self) -> String {
+        self.js_value.event_type()
+    }
+
+    /// Get the JS error which caused the event
+    #[must_use]
+    pub fn error(&self) -> js_sys::Error {
+        self.js_value.error()
+    }
+
+    /// Get the message of the JS error which caused the event
+    #[must_use]
+    pub fn message(&self) -> String {
+        self.error().message().into()
+    }
+
+    /// Get the identifier of the source which raised the error, if any, for example when one of
+    /// its tiles failed to load
+    #[must_use]
+    pub fn source_id(&self) -> Option<String> {
+        self.js_value.source_id()
+    }
+
+    /// Get the underlying JS object
+    #[must_use]
+    pub fn into_inner(self) -> bindings::ErrorEvent {
+        self.js_value
+    }
+}
+
+impl std::fmt::Display for ErrorEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message())
+    }
+}
+
+impl From<bindings::ErrorEvent> for ErrorEvent {
+    fn from(value: bindings::ErrorEvent) -> Self {
+        Self { js_value: value }
+    }
+}
+
+impl From<JsValue> for ErrorEvent {
+    fn from(value: JsValue) -> Self {
+        bindings::ErrorEvent::from(value).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use wasm_bindgen_test::*;
+    use web_sys::js_sys::{Object, Reflect};
+
+    #[wasm_bindgen_test]
+    fn getters() {
+        let event = Object::new();
+        Reflect::set(&event, &"type".into(), &"error".into())
+            .expect("Setting type value should work");
+        Reflect::set(
+            &event,
+            &"error".into(),
+            &js_sys::Error::new("Style could not be loaded"),
+        )
+        .expect("Setting error value should work");
+        let event = ErrorEvent::from(JsValue::from(event));
+
+        assert_eq!(event.event_type(), "error");
+        assert_eq!(event.message(), "Style could not be loaded");
+        assert_eq!(event.to_string(), "Style could not be loaded");
+        assert_eq!(event.source_id(), None);
+    }
+}
@@ -0,0 +1,112 @@
+//! Submodule for `MapEventListener` and `EventListener`
+
+use wasm_bindgen::prelude::*;
+
+use crate::bindings;
+
+/// Handle to a listener registered on a `Map`.
+///
+/// The listener is removed from the map and its closure is freed when the handle is dropped
+#[derive(Debug)]
+#[must_use = "the listener is removed as soon as the handle is dropped"]
+pub struct MapEventListener {
+    /// Map where the listener is registered
+    map: bindings::Map,
+    /// Name of the event which is listened to
+    event_type: &'static str,
+    /// Style layer the listener is scoped to, if any
+    layer_id: Option<String>,
+    /// Closure called by MapLibre when the event is fired
+    closure: Closure<dyn FnMut(JsValue)>,
+}
+
+impl MapEventListener {
+    /// Create a new handle for a listener that has already been registered on the map
+    pub(crate) const fn new(
+        map: bindings::Map,
+        event_type: &'static str,
+        layer_id: Option<String>,
+        closure: Closure<dyn FnMut(JsValue)>,
+    ) -> Self {
+        Self {
+            map,
+            event_type,
+            layer_id,
+            closure,
+        }
+    }
+
+    /// Get the name of the event which is listened to
+    #[must_use]
+    pub const fn event_type(&self) -> &'static str {
+        self.event_type
+    }
+
+    /// Get the style layer the listener is scoped to, if any
+    #[must_use]
+    pub fn layer_id(&self) -> Option<&str> {
+        self.layer_id.as_deref()
+    }
+
+    /// Keep the listener registered for the rest of the life of the page, leaking its closure
+    pub const fn forget(self) {
+        std::mem::forget(self);
+    }
+}
+
+impl Drop for MapEventListener {
+    fn drop(&mut self) {
+        let listener = self.closure.as_ref().unchecked_ref();
+        match &self.layer_id {
+            Some(layer_id) => self.map.off_layer(self.event_type, layer_id, listener),
+            None => self.map.off(self.event_type, listener),
+        };
+    }
+}
+
+/// Handle to a listener registered on an evented object other than the `Map`, such as a `Marker`.
+///
+/// The listener is removed from its target and its closure is freed when the handle is dropped
+#[derive(Debug)]
+#[must_use = "the listener is removed as soon as the handle is dropped"]
+pub struct EventListener {
+    /// Object where the listener is registered
+    target: bindings::Evented,
+    /// Name of the event which is listened to
+    event_type: &'static str,
+    /// Closure called by MapLibre when the event is fired
+    closure: Closure<dyn FnMut(JsValue)>,
+}
+
+impl EventListener {
+    /// Create a new handle for a listener that has already been registered on the target
+    pub(crate) const fn new(
+        target: bindings::Evented,
+        event_type: &'static str,
+        closure: Closure<dyn FnMut(JsValue)>,
+    ) -> Self {
+        Self {
+            target,
+            event_type,
+            closure,
+        }
+    }
+
+    /// Get the name of the event which is listened to
+    #[must_use]
+    pub const fn event_type(&self) -> &'static str {
+        self.event_type
+    }
+
+    /// Keep the listener registered for the rest of the life of the page, leaking its closure
+    pub const fn forget(self) {
+        std::mem::forget(self);
+    }
+}
+
+impl Drop for EventListener {
+    fn drop(&mut self) {
+        self.target
+            .off(self.event_type, self.closure.as_ref().unchecked_ref());
+    }
+}
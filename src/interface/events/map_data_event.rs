@@ -0,0 +1,90 @@
+//! Submodule for `MapDataEvent`
+
+use wasm_bindgen::prelude::*;
+
+use crate::bindings;
+
+/// Event fired by the map when the map, its style or one of its sources loads or changes
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapDataEvent {
+    /// JS object that the struct wraps
+    js_value: bindings::MapDataEvent,
+}
+
+impl MapDataEvent {
+    /// Get the name of the event
+    #[must_use]
+    pub fn event_type(&self) -> String {
+        self.js_value.event_type()
+    }
+
+    /// Get the type of data that has changed, either `source` or `style`
+    #[must_use]
+    pub fn data_type(&self) -> String {
+        self.js_value.data_type()
+    }
+
+    /// Check if the source that has changed has finished loading. Only present on source events
+    #[must_use]
+    pub fn is_source_loaded(&self) -> Option<bool> {
+        self.js_value.is_source_loaded()
+    }
+
+    /// Get the identifier of the source that has changed. Only present on source events
+    #[must_use]
+    pub fn source_id(&self) -> Option<String> {
+        self.js_value.source_id()
+    }
+
+    /// Get the kind of change of the source, such as `metadata` or `content`. Only present on
+    /// source events
+    #[must_use]
+    pub fn source_data_type(&self) -> Option<String> {
+        self.js_value.source_data_type()
+    }
+
+    /// Get the underlying JS object
+    #[must_use]
+    pub fn into_inner(self) -> bindings::MapDataEvent {
+        self.js_value
+    }
+}
+
+impl From<bindings::MapDataEvent> for MapDataEvent {
+    fn from(value: bindings::MapDataEvent) -> Self {
+        Self { js_value: value }
+    }
+}
+
+impl From<JsValue> for MapDataEvent {
+    fn from(value: JsValue) -> Self {
+        bindings::MapDataEvent::from(value).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use wasm_bindgen_test::*;
+    use web_sys::js_sys::{Object, Reflect};
+
+    #[wasm_bindgen_test]
+    fn getters() {
+        let event = Object::new();
+        Reflect::set(&event, &"type".into(), &"sourcedata".into())
+            .expect("Setting type value should work");
+        Reflect::set(&event, &"dataType".into(), &"source".into())
+            .expect("Setting dataType value should work");
+        Reflect::set(&event, &"isSourceLoaded".into(), &true.into())
+            .expect("Setting isSourceLoaded value should work");
+        Reflect::set(&event, &"sourceId".into(), &"points".into())
+            .expect("Setting sourceId value should work");
+        let event = MapDataEvent::from(JsValue::from(event));
+
+        assert_eq!(event.event_type(), "sourcedata");
+        assert_eq!(event.data_type(), "source");
+        assert_eq!(event.is_source_loaded(), Some(true));
+        assert_eq!(event.source_id().as_deref(), Some("points"));
+        assert_eq!(event.source_data_type(), None);
+    }
+}
@@ -0,0 +1,45 @@
+//! Submodule for `MapLibreEvent`
+
+use wasm_bindgen::prelude::*;
+use web_sys::Event;
+
+use crate::bindings;
+
+/// Generic event fired by the map, such as the ones related to camera movements
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapLibreEvent {
+    /// JS object that the struct wraps
+    js_value: bindings::MapLibreEvent,
+}
+
+impl MapLibreEvent {
+    /// Get the name of the event
+    #[must_use]
+    pub fn event_type(&self) -> String {
+        self.js_value.event_type()
+    }
+
+    /// Get the DOM event which caused the map event, if any
+    #[must_use]
+    pub fn original_event(&self) -> Option<Event> {
+        self.js_value.original_event()
+    }
+
+    /// Get the underlying JS object
+    #[must_use]
+    pub fn into_inner(self) -> bindings::MapLibreEvent {
+        self.js_value
+    }
+}
+
+impl From<bindings::MapLibreEvent> for MapLibreEvent {
+    fn from(value: bindings::MapLibreEvent) -> Self {
+        Self { js_value: value }
+    }
+}
+
+impl From<JsValue> for MapLibreEvent {
+    fn from(value: JsValue) -> Self {
+        bindings::MapLibreEvent::from(value).into()
+    }
+}
@@ -0,0 +1,45 @@
+//! Submodule for `MapLibreZoomEvent`
+
+use wasm_bindgen::prelude::*;
+use web_sys::MouseEvent;
+
+use crate::bindings;
+
+/// Event fired by the map during a box zoom interaction
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapLibreZoomEvent {
+    /// JS object that the struct wraps
+    js_value: bindings::MapLibreZoomEvent,
+}
+
+impl MapLibreZoomEvent {
+    /// Get the name of the event
+    #[must_use]
+    pub fn event_type(&self) -> String {
+        self.js_value.event_type()
+    }
+
+    /// Get the DOM event which caused the map event
+    #[must_use]
+    pub fn original_event(&self) -> MouseEvent {
+        self.js_value.original_event()
+    }
+
+    /// Get the underlying JS object
+    #[must_use]
+    pub fn into_inner(self) -> bindings::MapLibreZoomEvent {
+        self.js_value
+    }
+}
+
+impl From<bindings::MapLibreZoomEvent> for MapLibreZoomEvent {
+    fn from(value: bindings::MapLibreZoomEvent) -> Self {
+        Self { js_value: value }
+    }
+}
+
+impl From<JsValue> for MapLibreZoomEvent {
+    fn from(value: JsValue) -> Self {
+        bindings::MapLibreZoomEvent::from(value).into()
+    }
+}
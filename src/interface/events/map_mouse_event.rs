@@ -0,0 +1,105 @@
+//! Submodule for `MapMouseEvent`
+
+use wasm_bindgen::prelude::*;
+use web_sys::MouseEvent;
+
+use crate::{
+    bindings,
+    interface::{LngLat, Point},
+};
+
+/// Event fired by the map when a pointing device interacts with it
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapMouseEvent {
+    /// JS object that the struct wraps
+    js_value: bindings::MapMouseEvent,
+}
+
+impl MapMouseEvent {
+    /// Get the name of the event
+    #[must_use]
+    pub fn event_type(&self) -> String {
+        self.js_value.event_type()
+    }
+
+    /// Get the DOM event which caused the map event
+    #[must_use]
+    pub fn original_event(&self) -> MouseEvent {
+        self.js_value.original_event()
+    }
+
+    /// Get the pixel coordinates of the pointer, relative to the map container
+    #[must_use]
+    pub fn point(&self) -> Point {
+        self.js_value.point().into()
+    }
+
+    /// Get the geographical location of the pointer
+    #[must_use]
+    pub fn lng_lat(&self) -> LngLat {
+        self.js_value.lng_lat().into()
+    }
+
+    /// Prevent the map from applying its default handling of the event
+    pub fn prevent_default(&self) {
+        self.js_value.prevent_default();
+    }
+
+    /// Check if `prevent_default` has been called
+    #[must_use]
+    pub fn default_prevented(&self) -> bool {
+        self.js_value.default_prevented()
+    }
+
+    /// Get the underlying JS object
+    #[must_use]
+    pub fn into_inner(self) -> bindings::MapMouseEvent {
+        self.js_value
+    }
+}
+
+impl From<bindings::MapMouseEvent> for MapMouseEvent {
+    fn from(value: bindings::MapMouseEvent) -> Self {
+        Self { js_value: value }
+    }
+}
+
+impl From<JsValue> for MapMouseEvent {
+    fn from(value: JsValue) -> Self {
+        bindings::MapMouseEvent::from(value).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_utils::load_maplibre_gl;
+
+    use super::*;
+    use wasm_bindgen_test::*;
+    use web_sys::js_sys::{Object, Reflect};
+
+    #[wasm_bindgen_test]
+    async fn getters() {
+        load_maplibre_gl().await;
+        let event = Object::new();
+        Reflect::set(&event, &"type".into(), &"click".into())
+            .expect("Setting type value should work");
+        Reflect::set(
+            &event,
+            &"point".into(),
+            &bindings::Point::new(10., 20.).expect("Creating Point should work"),
+        )
+        .expect("Setting point value should work");
+        Reflect::set(
+            &event,
+            &"lngLat".into(),
+            &bindings::LngLat::new(1., 2.).expect("Creating LngLat should work"),
+        )
+        .expect("Setting lngLat value should work");
+        let event = MapMouseEvent::from(JsValue::from(event));
+
+        assert_eq!(event.event_type(), "click");
+        assert_eq!(event.point(), Point::new(10., 20.));
+        assert_eq!(event.lng_lat().to_array(), [1., 2.]);
+    }
+}
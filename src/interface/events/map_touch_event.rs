@@ -0,0 +1,87 @@
+//! Submodule for `MapTouchEvent`
+
+use wasm_bindgen::prelude::*;
+use web_sys::TouchEvent;
+
+use crate::{
+    bindings,
+    interface::{LngLat, Point},
+};
+
+/// Event fired by the map when a touch point interacts with it
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapTouchEvent {
+    /// JS object that the struct wraps
+    js_value: bindings::MapTouchEvent,
+}
+
+impl MapTouchEvent {
+    /// Get the name of the event
+    #[must_use]
+    pub fn event_type(&self) -> String {
+        self.js_value.event_type()
+    }
+
+    /// Get the DOM event which caused the map event
+    #[must_use]
+    pub fn original_event(&self) -> TouchEvent {
+        self.js_value.original_event()
+    }
+
+    /// Get the pixel coordinates of the center of the touch points, relative to the map container
+    #[must_use]
+    pub fn point(&self) -> Point {
+        self.js_value.point().into()
+    }
+
+    /// Get the geographical location of the center of the touch points
+    #[must_use]
+    pub fn lng_lat(&self) -> LngLat {
+        self.js_value.lng_lat().into()
+    }
+
+    /// Get the pixel coordinates of each touch point, relative to the map container
+    #[must_use]
+    pub fn points(&self) -> Vec<Point> {
+        self.js_value.points().into_iter().map(Into::into).collect()
+    }
+
+    /// Get the geographical location of each touch point
+    #[must_use]
+    pub fn lng_lats(&self) -> Vec<LngLat> {
+        self.js_value
+            .lng_lats()
+            .into_iter()
+            .map(Into::into)
+            .collect()
+    }
+
+    /// Prevent the map from applying its default handling of the event
+    pub fn prevent_default(&self) {
+        self.js_value.prevent_default();
+    }
+
+    /// Check if `prevent_default` has been called
+    #[must_use]
+    pub fn default_prevented(&self) -> bool {
+        self.js_value.default_prevented()
+    }
+
+    /// Get the underlying JS object
+    #[must_use]
+    pub fn into_inner(self) -> bindings::MapTouchEvent {
+        self.js_value
+    }
+}
+
+impl From<bindings::MapTouchEvent> for MapTouchEvent {
+    fn from(value: bindings::MapTouchEvent) -> Self {
+        Self { js_value: value }
+    }
+}
+
+impl From<JsValue> for MapTouchEvent {
+    fn from(value: JsValue) -> Self {
+        bindings::MapTouchEvent::from(value).into()
+    }
+}
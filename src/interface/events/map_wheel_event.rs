@@ -0,0 +1,56 @@
+//! Submodule for `MapWheelEvent`
+
+use wasm_bindgen::prelude::*;
+use web_sys::WheelEvent;
+
+use crate::bindings;
+
+/// Event fired by the map when a wheel event occurs within it
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapWheelEvent {
+    /// JS object that the struct wraps
+    js_value: bindings::MapWheelEvent,
+}
+
+impl MapWheelEvent {
+    /// Get the name of the event
+    #[must_use]
+    pub fn event_type(&self) -> String {
+        self.js_value.event_type()
+    }
+
+    /// Get the DOM event which caused the map event
+    #[must_use]
+    pub fn original_event(&self) -> WheelEvent {
+        self.js_value.original_event()
+    }
+
+    /// Prevent the map from zooming as the default handling of the event
+    pub fn prevent_default(&self) {
+        self.js_value.prevent_default();
+    }
+
+    /// Check if `prevent_default` has been called
+    #[must_use]
+    pub fn default_prevented(&self) -> bool {
+        self.js_value.default_prevented()
+    }
+
+    /// Get the underlying JS object
+    #[must_use]
+    pub fn into_inner(self) -> bindings::MapWheelEvent {
+        self.js_value
+    }
+}
+
+impl From<bindings::MapWheelEvent> for MapWheelEvent {
+    fn from(value: bindings::MapWheelEvent) -> Self {
+        Self { js_value: value }
+    }
+}
+
+impl From<JsValue> for MapWheelEvent {
+    fn from(value: JsValue) -> Self {
+        bindings::MapWheelEvent::from(value).into()
+    }
+}
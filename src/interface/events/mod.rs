@@ -0,0 +1,422 @@
+//! Rusty interface to the events fired by the `Map` of MapLibre GL JS and the types used to
+//! listen to them
+
+mod error_event;
+mod listener;
+mod map_data_event;
+mod map_libre_event;
+mod map_libre_zoom_event;
+mod map_mouse_event;
+mod map_touch_event;
+mod map_wheel_event;
+
+pub use error_event::ErrorEvent;
+pub use listener::{EventListener, MapEventListener};
+pub use map_data_event::MapDataEvent;
+pub use map_libre_event::MapLibreEvent;
+pub use map_libre_zoom_event::MapLibreZoomEvent;
+pub use map_mouse_event::MapMouseEvent;
+pub use map_touch_event::MapTouchEvent;
+pub use map_wheel_event::MapWheelEvent;
+
+use wasm_bindgen::JsValue;
+
+/// Type of an event fired by MapLibre, linking its name with the payload received by its
+/// listeners
+pub trait EventType {
+    /// Name of the event in MapLibre
+    const NAME: &'static str;
+    /// Payload received by the listeners of the event
+    type Event: From<JsValue>;
+}
+
+/// Type of an event which can be listened to on the whole `Map`
+pub trait MapEventType: EventType {}
+
+/// Type of an event which can be listened to on the features of a single style layer
+pub trait MapLayerEventType: EventType {}
+
+/// Type of an event which can be listened to on a `Marker`
+pub trait MarkerEventType: EventType {}
+
+/// Type of an event which can be listened to on a `Popup`
+pub trait PopupEventType: EventType {}
+
+/// Generate the unit structs representing each event type
+macro_rules! declare_event_types {
+    (
+        $(
+            {
+                name: $name:ident,
+                event: $event:expr,
+                payload: $payload:ty,
+                targets: [$($target:ident),*],
+                docs: $docs:expr,
+            }
+        ),* $(,)?
+    ) => {
+        $(
+            #[doc = $docs]
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+            pub struct $name;
+
+            impl EventType for $name {
+                const NAME: &'static str = $event;
+                type Event = $payload;
+            }
+
+            $(
+                impl $target for $name {}
+            )*
+        )*
+    };
+}
+
+declare_event_types!(
+    {
+        name: MouseDown,
+        event: "mousedown",
+        payload: MapMouseEvent,
+        targets: [MapEventType, MapLayerEventType],
+        docs: "Fired when a pointing device is pressed within the map",
+    },
+    {
+        name: MouseUp,
+        event: "mouseup",
+        payload: MapMouseEvent,
+        targets: [MapEventType, MapLayerEventType],
+        docs: "Fired when a pointing device is released within the map",
+    },
+    {
+        name: MouseOver,
+        event: "mouseover",
+        payload: MapMouseEvent,
+        targets: [MapEventType, MapLayerEventType],
+        docs: "Fired when a pointing device is moved within the map",
+    },
+    {
+        name: MouseOut,
+        event: "mouseout",
+        payload: MapMouseEvent,
+        targets: [MapEventType, MapLayerEventType],
+        docs: "Fired when a pointing device leaves the map's canvas",
+    },
+    {
+        name: MouseMove,
+        event: "mousemove",
+        payload: MapMouseEvent,
+        targets: [MapEventType, MapLayerEventType],
+        docs: "Fired when a pointing device is moved while inside the map",
+    },
+    {
+        name: MouseEnter,
+        event: "mouseenter",
+        payload: MapMouseEvent,
+        targets: [MapLayerEventType],
+        docs: "Fired when a pointing device enters a visible portion of a style layer",
+    },
+    {
+        name: MouseLeave,
+        event: "mouseleave",
+        payload: MapMouseEvent,
+        targets: [MapLayerEventType],
+        docs: "Fired when a pointing device leaves a style layer or the map's canvas",
+    },
+    {
+        name: Click,
+        event: "click",
+        payload: MapMouseEvent,
+        targets: [MapEventType, MapLayerEventType],
+        docs: "Fired when a pointing device is pressed and released at the same point",
+    },
+    {
+        name: DblClick,
+        event: "dblclick",
+        payload: MapMouseEvent,
+        targets: [MapEventType, MapLayerEventType],
+        docs: "Fired when a pointing device is clicked twice at the same point",
+    },
+    {
+        name: ContextMenu,
+        event: "contextmenu",
+        payload: MapMouseEvent,
+        targets: [MapEventType, MapLayerEventType],
+        docs: "Fired when the right button of the mouse is clicked or the context menu key is \
+               pressed",
+    },
+    {
+        name: TouchStart,
+        event: "touchstart",
+        payload: MapTouchEvent,
+        targets: [MapEventType, MapLayerEventType],
+        docs: "Fired when a touch point is placed on the map",
+    },
+    {
+        name: TouchEnd,
+        event: "touchend",
+        payload: MapTouchEvent,
+        targets: [MapEventType, MapLayerEventType],
+        docs: "Fired when a touch point is removed from the map",
+    },
+    {
+        name: TouchMove,
+        event: "touchmove",
+        payload: MapTouchEvent,
+        targets: [MapEventType, MapLayerEventType],
+        docs: "Fired when a touch point is moved within the map",
+    },
+    {
+        name: TouchCancel,
+        event: "touchcancel",
+        payload: MapTouchEvent,
+        targets: [MapEventType, MapLayerEventType],
+        docs: "Fired when a touch point has been disrupted",
+    },
+    {
+        name: Wheel,
+        event: "wheel",
+        payload: MapWheelEvent,
+        targets: [MapEventType],
+        docs: "Fired when a wheel event occurs within the map",
+    },
+    {
+        name: BoxZoomStart,
+        event: "boxzoomstart",
+        payload: MapLibreZoomEvent,
+        targets: [MapEventType],
+        docs: "Fired when a box zoom interaction starts",
+    },
+    {
+        name: BoxZoomEnd,
+        event: "boxzoomend",
+        payload: MapLibreZoomEvent,
+        targets: [MapEventType],
+        docs: "Fired when a box zoom interaction ends",
+    },
+    {
+        name: BoxZoomCancel,
+        event: "boxzoomcancel",
+        payload: MapLibreZoomEvent,
+        targets: [MapEventType],
+        docs: "Fired when the user cancels a box zoom interaction or when the bounding box does \
+               not meet the minimum size threshold",
+    },
+    {
+        name: Load,
+        event: "load",
+        payload: MapLibreEvent,
+        targets: [MapEventType],
+        docs: "Fired immediately after all necessary resources have been downloaded and the first \
+               visually complete rendering of the map has occurred",
+    },
+    {
+        name: Error,
+        event: "error",
+        payload: ErrorEvent,
+        targets: [MapEventType],
+        docs: "Fired when an error occurs, such as a style or a resource failing to load",
+    },
+    {
+        name: Idle,
+        event: "idle",
+        payload: MapLibreEvent,
+        targets: [MapEventType],
+        docs: "Fired after the last frame rendered before the map enters an idle state",
+    },
+    {
+        name: Remove,
+        event: "remove",
+        payload: MapLibreEvent,
+        targets: [MapEventType],
+        docs: "Fired immediately after the map has been removed",
+    },
+    {
+        name: Render,
+        event: "render",
+        payload: MapLibreEvent,
+        targets: [MapEventType],
+        docs: "Fired whenever the map is drawn to the screen",
+    },
+    {
+        name: Resize,
+        event: "resize",
+        payload: MapLibreEvent,
+        targets: [MapEventType],
+        docs: "Fired immediately after the map has been resized",
+    },
+    {
+        name: MoveStart,
+        event: "movestart",
+        payload: MapLibreEvent,
+        targets: [MapEventType],
+        docs: "Fired just before the map begins a transition from one view to another",
+    },
+    {
+        name: Move,
+        event: "move",
+        payload: MapLibreEvent,
+        targets: [MapEventType],
+        docs: "Fired repeatedly during an animated transition from one view to another",
+    },
+    {
+        name: MoveEnd,
+        event: "moveend",
+        payload: MapLibreEvent,
+        targets: [MapEventType],
+        docs: "Fired just after the map completes a transition from one view to another",
+    },
+    {
+        name: DragStart,
+        event: "dragstart",
+        payload: MapLibreEvent,
+        targets: [MapEventType, MarkerEventType],
+        docs: "Fired when a drag to pan interaction or the drag of a marker starts",
+    },
+    {
+        name: Drag,
+        event: "drag",
+        payload: MapLibreEvent,
+        targets: [MapEventType, MarkerEventType],
+        docs: "Fired repeatedly during a drag to pan interaction or the drag of a marker",
+    },
+    {
+        name: DragEnd,
+        event: "dragend",
+        payload: MapLibreEvent,
+        targets: [MapEventType, MarkerEventType],
+        docs: "Fired when a drag to pan interaction or the drag of a marker ends",
+    },
+    {
+        name: ZoomStart,
+        event: "zoomstart",
+        payload: MapLibreEvent,
+        targets: [MapEventType],
+        docs: "Fired just before the map begins a transition from one zoom level to another",
+    },
+    {
+        name: Zoom,
+        event: "zoom",
+        payload: MapLibreEvent,
+        targets: [MapEventType],
+        docs: "Fired repeatedly during an animated transition from one zoom level to another",
+    },
+    {
+        name: ZoomEnd,
+        event: "zoomend",
+        payload: MapLibreEvent,
+        targets: [MapEventType],
+        docs: "Fired just after the map completes a transition from one zoom level to another",
+    },
+    {
+        name: RotateStart,
+        event: "rotatestart",
+        payload: MapLibreEvent,
+        targets: [MapEventType],
+        docs: "Fired when a rotate interaction starts",
+    },
+    {
+        name: Rotate,
+        event: "rotate",
+        payload: MapLibreEvent,
+        targets: [MapEventType],
+        docs: "Fired repeatedly during a rotate interaction",
+    },
+    {
+        name: RotateEnd,
+        event: "rotateend",
+        payload: MapLibreEvent,
+        targets: [MapEventType],
+        docs: "Fired when a rotate interaction ends",
+    },
+    {
+        name: PitchStart,
+        event: "pitchstart",
+        payload: MapLibreEvent,
+        targets: [MapEventType],
+        docs: "Fired whenever the map's pitch begins a change",
+    },
+    {
+        name: Pitch,
+        event: "pitch",
+        payload: MapLibreEvent,
+        targets: [MapEventType],
+        docs: "Fired repeatedly during the map's pitch change",
+    },
+    {
+        name: PitchEnd,
+        event: "pitchend",
+        payload: MapLibreEvent,
+        targets: [MapEventType],
+        docs: "Fired immediately after the map's pitch change finished",
+    },
+    {
+        name: Data,
+        event: "data",
+        payload: MapDataEvent,
+        targets: [MapEventType],
+        docs: "Fired when any map data loads or changes",
+    },
+    {
+        name: StyleData,
+        event: "styledata",
+        payload: MapDataEvent,
+        targets: [MapEventType],
+        docs: "Fired when the map's style loads or changes",
+    },
+    {
+        name: SourceData,
+        event: "sourcedata",
+        payload: MapDataEvent,
+        targets: [MapEventType],
+        docs: "Fired when one of the map's sources loads or changes",
+    },
+    {
+        name: DataLoading,
+        event: "dataloading",
+        payload: MapDataEvent,
+        targets: [MapEventType],
+        docs: "Fired when any map data begins loading or changing asynchronously",
+    },
+    {
+        name: StyleDataLoading,
+        event: "styledataloading",
+        payload: MapDataEvent,
+        targets: [MapEventType],
+        docs: "Fired when the map's style begins loading or changing asynchronously",
+    },
+    {
+        name: SourceDataLoading,
+        event: "sourcedataloading",
+        payload: MapDataEvent,
+        targets: [MapEventType],
+        docs: "Fired when one of the map's sources begins loading or changing asynchronously",
+    },
+    {
+        name: DataAbort,
+        event: "dataabort",
+        payload: MapDataEvent,
+        targets: [MapEventType],
+        docs: "Fired when a request for one of the map's sources' data is aborted",
+    },
+    {
+        name: SourceDataAbort,
+        event: "sourcedataabort",
+        payload: MapDataEvent,
+        targets: [MapEventType],
+        docs: "Fired when a request for one of the map's sources' data is aborted",
+    },
+    {
+        name: Open,
+        event: "open",
+        payload: MapLibreEvent,
+        targets: [PopupEventType],
+        docs: "Fired when a popup is opened manually or programmatically",
+    },
+    {
+        name: Close,
+        event: "close",
+        payload: MapLibreEvent,
+        targets: [PopupEventType],
+        docs: "Fired when a popup is closed manually or programmatically",
+    },
+);
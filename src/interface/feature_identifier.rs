@@ -0,0 +1,75 @@
+//! Rusty interface to the `FeatureIdentifier` used by the feature state methods of MapLibre GL JS
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsValue;
+
+use crate::interface::sources::GeoJSONFeatureId;
+
+/// Identification of a feature of a source of the style of a `Map`, used to set and get its
+/// state.
+///
+/// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/type-aliases/FeatureIdentifier/>
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeatureIdentifier {
+    /// Identifier of the source of the feature
+    pub source: String,
+    /// Layer of the vector source containing the feature. Required for vector sources
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_layer: Option<String>,
+    /// Identifier of the feature, either its own or the one promoted or generated by the source
+    pub id: GeoJSONFeatureId,
+}
+
+impl FeatureIdentifier {
+    /// Create a `FeatureIdentifier` of the feature with the given identifier in the given source
+    #[must_use]
+    pub fn new(source: impl Into<String>, id: impl Into<GeoJSONFeatureId>) -> Self {
+        Self {
+            source: source.into(),
+            source_layer: None,
+            id: id.into(),
+        }
+    }
+
+    /// Specify the layer of the vector source containing the feature
+    #[must_use]
+    pub fn with_source_layer(self, source_layer: impl Into<String>) -> Self {
+        Self {
+            source_layer: Some(source_layer.into()),
+            ..self
+        }
+    }
+
+    /// Try converting `FeatureIdentifier` into the equivalent `JsValue`
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn as_js_value(&self) -> Result<JsValue, super::Error> {
+        self.serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn feature_identifier_serialization() {
+        assert_eq!(
+            serde_json::to_value(FeatureIdentifier::new("vehicles", 3))
+                .expect("Serializing should work"),
+            json!({"source": "vehicles", "id": 3})
+        );
+        assert_eq!(
+            serde_json::to_value(
+                FeatureIdentifier::new("openmaptiles", "way-12").with_source_layer("roads")
+            )
+            .expect("Serializing should work"),
+            json!({"source": "openmaptiles", "sourceLayer": "roads", "id": "way-12"})
+        );
+    }
+}
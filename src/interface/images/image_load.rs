@@ -0,0 +1,41 @@
+//! Submodule for the `ImageLoad` future returned by `Map::load_image`
+
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use wasm_bindgen_futures::JsFuture;
+use web_sys::js_sys::{Promise, Reflect};
+
+use super::{Error, MapImage};
+
+/// Future which resolves to the image fetched and decoded by `Map::load_image`
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ImageLoad {
+    /// Promise returned by MapLibre, resolving to the response of the request
+    response: JsFuture,
+}
+
+impl ImageLoad {
+    /// Create an `ImageLoad` waiting for the given promise
+    pub(crate) fn new(promise: Promise) -> Self {
+        Self {
+            response: JsFuture::from(promise),
+        }
+    }
+}
+
+impl Future for ImageLoad {
+    type Output = Result<MapImage, Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let response = std::task::ready!(Pin::new(&mut self.response).poll(cx))?;
+        Poll::Ready(
+            Reflect::get(&response, &"data".into())
+                .map_err(Error::from)
+                .and_then(MapImage::try_from),
+        )
+    }
+}
@@ -0,0 +1,154 @@
+//! Submodule for `MapImage` and associated tests
+
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{
+    HtmlImageElement, ImageBitmap, ImageData,
+    js_sys::{Object, Reflect, Uint8Array},
+};
+
+use super::{CustomStyleImage, Error, StyleImage};
+
+/// Image that can be added to the style of a `Map` with `Map::add_image`
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum MapImage {
+    /// Image element, such as the ones returned by `Map::load_image`
+    Element(HtmlImageElement),
+    /// Decoded image bitmap
+    Bitmap(ImageBitmap),
+    /// Pixels of a canvas
+    Data(ImageData),
+    /// Raw pixels in RGBA order, row by row from the top left corner
+    Rgba {
+        /// Width of the image in pixels
+        width: u32,
+        /// Height of the image in pixels
+        height: u32,
+        /// Bytes of the pixels, four per pixel
+        data: Vec<u8>,
+    },
+    /// Image whose pixels are generated by a `StyleImage`, created with
+    /// `MapImage::from_style_image`
+    Custom(CustomStyleImage),
+}
+
+impl MapImage {
+    /// Create a `MapImage` from raw pixels in RGBA order
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `data` doesn't hold exactly four bytes per pixel
+    pub fn from_rgba(width: u32, height: u32, data: impl Into<Vec<u8>>) -> Result<Self, Error> {
+        let data = data.into();
+        let expected = u64::from(width) * u64::from(height) * 4;
+        if data.len() as u64 != expected {
+            return Err(Error::new(format!(
+                "Expected {expected} bytes for a {width}x{height} RGBA image, got {}",
+                data.len()
+            )));
+        }
+        Ok(Self::Rgba {
+            width,
+            height,
+            data,
+        })
+    }
+
+    /// Create a `MapImage` whose pixels are generated by the given `StyleImage`, which can
+    /// change them on every frame
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors generated from JS
+    pub fn from_style_image(image: impl StyleImage) -> Result<Self, Error> {
+        CustomStyleImage::new(image).map(Self::Custom)
+    }
+
+    /// Try converting `MapImage` into the equivalent `JsValue`
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors generated from JS
+    pub fn as_js_value(&self) -> Result<JsValue, Error> {
+        Ok(match self {
+            Self::Element(image) => image.into(),
+            Self::Bitmap(image) => image.into(),
+            Self::Data(image) => image.into(),
+            Self::Custom(image) => image.as_object().into(),
+            Self::Rgba {
+                width,
+                height,
+                data,
+            } => {
+                let image = Object::new();
+                Reflect::set(&image, &"width".into(), &(*width).into())?;
+                Reflect::set(&image, &"height".into(), &(*height).into())?;
+                Reflect::set(&image, &"data".into(), &Uint8Array::from(data.as_slice()))?;
+                image.into()
+            }
+        })
+    }
+}
+
+impl From<HtmlImageElement> for MapImage {
+    fn from(value: HtmlImageElement) -> Self {
+        Self::Element(value)
+    }
+}
+
+impl From<ImageBitmap> for MapImage {
+    fn from(value: ImageBitmap) -> Self {
+        Self::Bitmap(value)
+    }
+}
+
+impl From<ImageData> for MapImage {
+    fn from(value: ImageData) -> Self {
+        Self::Data(value)
+    }
+}
+
+impl TryFrom<JsValue> for MapImage {
+    type Error = Error;
+
+    fn try_from(value: JsValue) -> Result<Self, Self::Error> {
+        if value.is_instance_of::<HtmlImageElement>() {
+            Ok(Self::Element(value.unchecked_into()))
+        } else if value.is_instance_of::<ImageBitmap>() {
+            Ok(Self::Bitmap(value.unchecked_into()))
+        } else if value.is_instance_of::<ImageData>() {
+            Ok(Self::Data(value.unchecked_into()))
+        } else {
+            Err(Error::new("The value is not an image"))
+        }
+    }
+}
+
+impl TryFrom<&MapImage> for JsValue {
+    type Error = Error;
+
+    fn try_from(value: &MapImage) -> Result<Self, Self::Error> {
+        value.as_js_value()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utils::get_value_from_object;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn map_image_from_rgba() {
+        let image = MapImage::from_rgba(2, 1, [255, 0, 0, 255, 0, 0, 255, 255])
+            .expect("The size should match");
+        let js_value = image.as_js_value().expect("Conversion should work");
+
+        assert_eq!(get_value_from_object(&js_value, "width"), 2);
+        assert_eq!(get_value_from_object(&js_value, "height"), 1);
+        assert_eq!(
+            Uint8Array::new(&get_value_from_object(&js_value, "data")).to_vec(),
+            vec![255, 0, 0, 255, 0, 0, 255, 255]
+        );
+        assert!(MapImage::from_rgba(2, 2, [0; 4]).is_err());
+    }
+}
@@ -0,0 +1,14 @@
+//! Rusty interface to the images of the style of a `Map` of MapLibre GL JS, used by the symbol
+//! icons and the pattern properties of the layers
+
+mod image_load;
+mod map_image;
+mod style_image;
+mod style_image_options;
+
+pub use image_load::ImageLoad;
+pub use map_image::MapImage;
+pub use style_image::{CustomStyleImage, StyleImage};
+pub use style_image_options::StyleImageOptions;
+
+use super::Error;
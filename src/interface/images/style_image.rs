@@ -0,0 +1,285 @@
+//! Submodule for the `StyleImage` trait and associated tests
+
+use std::{cell::RefCell, rc::Rc};
+
+use wasm_bindgen::prelude::*;
+use web_sys::js_sys::{Object, Reflect, Uint8Array};
+
+use super::Error;
+use crate::{bindings, interface::Map};
+
+/// Image of the style of a `Map` whose pixels are generated in Rust and can change on every
+/// frame, for example to animate icons. Add it with `MapImage::from_style_image`.
+///
+/// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/interfaces/StyleImageInterface/>
+pub trait StyleImage: 'static {
+    /// Width of the image in pixels, which must not change
+    fn width(&self) -> u32;
+
+    /// Height of the image in pixels, which must not change
+    fn height(&self) -> u32;
+
+    /// Pixels of the image in RGBA order, row by row from the top left corner. Frames whose
+    /// pixels don't have the size of the first ones are skipped
+    fn data(&self) -> &[u8];
+
+    /// Called before every frame in which the image is used. Returns whether the pixels have
+    /// changed and must be uploaded again. Call `Map::trigger_repaint` to keep rendering frames
+    /// while animating
+    fn render(&mut self) -> bool {
+        false
+    }
+
+    /// Called when the image is added to the given map with the given identifier
+    fn on_add(&mut self, _map: Map, _id: String) {}
+
+    /// Called when the image is removed from the map
+    fn on_remove(&mut self) {}
+}
+
+/// Closures of the JS object implementing `StyleImageInterface`
+#[derive(Debug)]
+struct StyleImageClosures {
+    /// Closure called by MapLibre before every frame in which the image is used
+    _render: Closure<dyn Fn() -> bool>,
+    /// Closure called by MapLibre when the image is added to a map
+    _on_add: Closure<dyn Fn(bindings::Map, String)>,
+    /// Closure called by MapLibre when the image is removed from the map
+    _on_remove: Closure<dyn Fn()>,
+}
+
+/// Shared slot holding the closures of a `CustomStyleImage` until they are freed
+type ClosuresSlot = Rc<RefCell<Option<StyleImageClosures>>>;
+
+/// Handle to a `StyleImage` bridged to the JS object given to MapLibre, created with
+/// `MapImage::from_style_image`.
+///
+/// Its closures are kept alive while the image is added to a map and freed once MapLibre removes
+/// it, for example with `Map::remove_image`. If the image is never added, they are freed with the
+/// last clone of the handle
+#[derive(Debug, Clone)]
+pub struct CustomStyleImage {
+    /// JS object implementing `StyleImageInterface` which is given to MapLibre
+    object: Object,
+    /// Closures of the JS object
+    _closures: ClosuresSlot,
+}
+
+impl CustomStyleImage {
+    /// Bridge the given `StyleImage` into the JS object given to MapLibre
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors generated from JS
+    pub(super) fn new(image: impl StyleImage) -> Result<Self, Error> {
+        let object = Object::new();
+        Reflect::set(&object, &"width".into(), &image.width().into())?;
+        Reflect::set(&object, &"height".into(), &image.height().into())?;
+        let data = Uint8Array::from(image.data());
+        Reflect::set(&object, &"data".into(), &data)?;
+        let image = Rc::new(RefCell::new(image));
+        let closures: ClosuresSlot = Rc::new(RefCell::new(None));
+        // Keeps the closures alive from the moment the image is added until it is removed
+        let keep_alive = Rc::new(RefCell::new(None::<ClosuresSlot>));
+
+        // The pixels are copied into the same array so that the closures don't hold the object,
+        // which would keep it alive forever
+        let render = Closure::<dyn Fn() -> bool>::new({
+            let image = Rc::clone(&image);
+            move || {
+                let image = &mut *image.borrow_mut();
+                if !image.render() {
+                    return false;
+                }
+                if image.data().len() != data.length() as usize {
+                    return false;
+                }
+                data.copy_from(image.data());
+                true
+            }
+        });
+        let on_add = Closure::<dyn Fn(bindings::Map, String)>::new({
+            let image = Rc::clone(&image);
+            let keep_alive = Rc::clone(&keep_alive);
+            let closures = Rc::downgrade(&closures);
+            move |map: bindings::Map, id: String| {
+                *keep_alive.borrow_mut() = closures.upgrade();
+                image.borrow_mut().on_add(map.into(), id);
+            }
+        });
+        let on_remove = Closure::<dyn Fn()>::new(move || {
+            image.borrow_mut().on_remove();
+            // Freeing the closure being run is deferred by wasm-bindgen until it returns
+            let closures = keep_alive.borrow_mut().take();
+            drop(closures);
+        });
+
+        Reflect::set(&object, &"render".into(), render.as_ref())?;
+        Reflect::set(&object, &"onAdd".into(), on_add.as_ref())?;
+        Reflect::set(&object, &"onRemove".into(), on_remove.as_ref())?;
+        *closures.borrow_mut() = Some(StyleImageClosures {
+            _render: render,
+            _on_add: on_add,
+            _on_remove: on_remove,
+        });
+        Ok(Self {
+            object,
+            _closures: closures,
+        })
+    }
+
+    /// Get the JS object implementing `StyleImageInterface`
+    #[must_use]
+    pub const fn as_object(&self) -> &Object {
+        &self.object
+    }
+}
+
+impl PartialEq for CustomStyleImage {
+    fn eq(&self, other: &Self) -> bool {
+        self.object == other.object
+    }
+}
+
+impl Eq for CustomStyleImage {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        interface::{
+            MapOptions,
+            images::{MapImage, StyleImageOptions},
+        },
+        test_utils::{gen_sized_container, load_maplibre_gl},
+    };
+    use serde_json::json;
+    use wasm_bindgen_futures::JsFuture;
+    use wasm_bindgen_test::*;
+
+    /// Calls received by a `BlinkingDot`
+    #[derive(Debug, Default)]
+    struct Calls {
+        /// Identifiers given to `on_add`
+        added: Vec<String>,
+        /// Number of calls to `render`
+        renders: u32,
+        /// Number of calls to `on_remove`
+        removed: u32,
+    }
+
+    /// Image which switches between red and blue on every frame
+    struct BlinkingDot {
+        /// Current pixels
+        pixels: Vec<u8>,
+        /// Map the image has been added to
+        map: Option<Map>,
+        /// Calls received by the image, shared with the test
+        calls: Rc<RefCell<Calls>>,
+    }
+
+    impl StyleImage for BlinkingDot {
+        fn width(&self) -> u32 {
+            1
+        }
+
+        fn height(&self) -> u32 {
+            1
+        }
+
+        fn data(&self) -> &[u8] {
+            &self.pixels
+        }
+
+        fn render(&mut self) -> bool {
+            self.calls.borrow_mut().renders += 1;
+            self.pixels.swap(0, 2);
+            if let Some(map) = &self.map {
+                map.trigger_repaint();
+            }
+            true
+        }
+
+        fn on_add(&mut self, map: Map, id: String) {
+            self.calls.borrow_mut().added.push(id);
+            self.map = Some(map);
+        }
+
+        fn on_remove(&mut self) {
+            self.calls.borrow_mut().removed += 1;
+            self.map = None;
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn style_image_add_remove() {
+        load_maplibre_gl().await;
+        let container = gen_sized_container(400, 300);
+        let map = MapOptions::new(container.cloned_ref())
+            .with_style(json!({"version": 8, "sources": {}, "layers": []}))
+            .build_async()
+            .await
+            .expect("Creating a map should work");
+        let calls = Rc::new(RefCell::new(Calls::default()));
+        let image = MapImage::from_style_image(BlinkingDot {
+            pixels: vec![255, 0, 0, 255],
+            map: None,
+            calls: Rc::clone(&calls),
+        })
+        .expect("Conversion should work");
+
+        map.add_image("blinking-dot", image, &StyleImageOptions::new())
+            .expect("Adding a style image should work");
+        assert!(map.has_image("blinking-dot"));
+        assert_eq!(calls.borrow().added, vec!["blinking-dot"]);
+
+        map.add_source(
+            "dots",
+            &json!({"type": "geojson", "data": {
+                "type": "Point",
+                "coordinates": [0, 0],
+            }}),
+        )
+        .expect("Adding a source should work");
+        map.add_layer(
+            &json!({
+                "id": "dots",
+                "type": "symbol",
+                "source": "dots",
+                "layout": {"icon-image": "blinking-dot"},
+            }),
+            None,
+        )
+        .expect("Adding a layer should work");
+        for _ in 0..100 {
+            if calls.borrow().renders >= 2 {
+                break;
+            }
+            JsFuture::from(map.clone().into_inner().once_promise("render"))
+                .await
+                .expect("The map should render");
+        }
+        assert!(calls.borrow().renders >= 2);
+
+        map.remove_image("blinking-dot");
+        assert!(!map.has_image("blinking-dot"));
+        assert_eq!(calls.borrow().removed, 1);
+        // The closures have been freed along with the image
+        assert_eq!(Rc::strong_count(&calls), 1);
+    }
+
+    #[wasm_bindgen_test]
+    fn style_image_dropped_without_map() {
+        let calls = Rc::new(RefCell::new(Calls::default()));
+        let image = MapImage::from_style_image(BlinkingDot {
+            pixels: vec![255, 0, 0, 255],
+            map: None,
+            calls: Rc::clone(&calls),
+        })
+        .expect("Conversion should work");
+        assert_eq!(Rc::strong_count(&calls), 2);
+
+        drop(image);
+        assert_eq!(Rc::strong_count(&calls), 1);
+    }
+}
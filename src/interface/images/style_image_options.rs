@@ -0,0 +1,130 @@
+//! Submodule for `StyleImageOptions` and associated tests
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsValue;
+
+use super::Error;
+
+/// Options of an image added to the style of a `Map` with `Map::add_image`.
+///
+/// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/type-aliases/StyleImageMetadata/>
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StyleImageOptions {
+    /// Ratio of pixels in the image to physical pixels on the screen. Defaults to `1`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pixel_ratio: Option<f64>,
+    /// Whether the image is a signed distance field, recolorable with `icon-color`. Defaults to
+    /// `false`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sdf: Option<bool>,
+    /// Horizontal ranges of pixels that can be stretched to fit `icon-text-fit`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stretch_x: Option<Vec<[f64; 2]>>,
+    /// Vertical ranges of pixels that can be stretched to fit `icon-text-fit`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stretch_y: Option<Vec<[f64; 2]>>,
+    /// Part of the image that can be covered by the text of `icon-text-fit`, as
+    /// `[left, top, right, bottom]` in pixels
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content: Option<[f64; 4]>,
+}
+
+impl StyleImageOptions {
+    /// Create new empty `StyleImageOptions`
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Specify the ratio of pixels in the image to physical pixels on the screen
+    #[must_use]
+    pub fn with_pixel_ratio(self, pixel_ratio: impl Into<f64>) -> Self {
+        Self {
+            pixel_ratio: Some(pixel_ratio.into()),
+            ..self
+        }
+    }
+
+    /// Mark the image as a signed distance field
+    #[must_use]
+    pub fn with_sdf(self) -> Self {
+        Self {
+            sdf: Some(true),
+            ..self
+        }
+    }
+
+    /// Mark the image as a regular image
+    #[must_use]
+    pub fn without_sdf(self) -> Self {
+        Self {
+            sdf: Some(false),
+            ..self
+        }
+    }
+
+    /// Specify the horizontal ranges of pixels that can be stretched
+    #[must_use]
+    pub fn with_stretch_x(self, stretch_x: impl IntoIterator<Item = [f64; 2]>) -> Self {
+        Self {
+            stretch_x: Some(stretch_x.into_iter().collect()),
+            ..self
+        }
+    }
+
+    /// Specify the vertical ranges of pixels that can be stretched
+    #[must_use]
+    pub fn with_stretch_y(self, stretch_y: impl IntoIterator<Item = [f64; 2]>) -> Self {
+        Self {
+            stretch_y: Some(stretch_y.into_iter().collect()),
+            ..self
+        }
+    }
+
+    /// Specify the part of the image that can be covered by text, as
+    /// `[left, top, right, bottom]` in pixels
+    #[must_use]
+    pub fn with_content(self, content: [f64; 4]) -> Self {
+        Self {
+            content: Some(content),
+            ..self
+        }
+    }
+
+    /// Try converting `StyleImageOptions` into the equivalent `JsValue`
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn as_js_value(&self) -> Result<JsValue, Error> {
+        self.serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn style_image_options_serialization() {
+        let options = StyleImageOptions::new()
+            .with_pixel_ratio(2)
+            .with_sdf()
+            .with_stretch_x([[4., 12.]])
+            .with_stretch_y([[4., 12.]])
+            .with_content([4., 4., 12., 12.]);
+
+        assert_eq!(
+            serde_json::to_value(&options).expect("Serializing should work"),
+            json!({
+                "pixelRatio": 2.0,
+                "sdf": true,
+                "stretchX": [[4.0, 12.0]],
+                "stretchY": [[4.0, 12.0]],
+                "content": [4.0, 4.0, 12.0, 12.0],
+            })
+        );
+    }
+}
@@ -0,0 +1,35 @@
+//! Submodule for the specification of `background` layers
+
+use super::{Visibility, declare_layer_properties, declare_layer_specification};
+use crate::interface::style::Color;
+
+declare_layer_properties!(
+    /// Layout properties of a `background` layer
+    BackgroundLayout {
+        /// Whether the layer is displayed. Defaults to `visible`
+        visibility: Visibility,
+    }
+);
+
+declare_layer_properties!(
+    /// Paint properties of a `background` layer
+    BackgroundPaint {
+        /// Color of the background. Defaults to `#000000`
+        background_color: Color,
+        /// Name of the image used as the pattern of the background
+        background_pattern: String,
+        /// Opacity of the background, between `0` and `1`. Defaults to `1`
+        background_opacity: f64,
+    }
+);
+
+declare_layer_specification!(
+    /// Layer filling the whole map with a color or a pattern.
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-style-spec/layers/#background>
+    BackgroundLayerSpecification {
+        kind: background,
+        layout: BackgroundLayout,
+        paint: BackgroundPaint,
+    }
+);
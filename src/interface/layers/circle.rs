@@ -0,0 +1,55 @@
+//! Submodule for the specification of `circle` layers
+
+use super::{TranslateAnchor, Visibility, declare_layer_properties, declare_layer_specification};
+use crate::interface::style::Color;
+
+declare_layer_properties!(
+    /// Layout properties of a `circle` layer
+    CircleLayout {
+        /// Sorts the features in ascending order, features with a higher key are drawn on top
+        circle_sort_key: f64,
+        /// Whether the layer is displayed. Defaults to `visible`
+        visibility: Visibility,
+    }
+);
+
+declare_layer_properties!(
+    /// Paint properties of a `circle` layer
+    CirclePaint {
+        /// Radius of the circles in pixels. Defaults to `5`
+        circle_radius: f64,
+        /// Fill color of the circles. Defaults to `#000000`
+        circle_color: Color,
+        /// Blur applied to the circles, `1` blurs them so only the center is fully opaque.
+        /// Defaults to `0`
+        circle_blur: f64,
+        /// Opacity of the circles, between `0` and `1`. Defaults to `1`
+        circle_opacity: f64,
+        /// Offset of the geometry in pixels, positive values go right and down. Defaults to
+        /// `[0, 0]`
+        circle_translate: [f64; 2],
+        /// Frame of reference of `circle-translate`. Defaults to `map`
+        circle_translate_anchor: TranslateAnchor,
+        /// Scaling of the circles when the map is pitched. Defaults to `map`
+        circle_pitch_scale: TranslateAnchor,
+        /// Orientation of the circles when the map is pitched. Defaults to `viewport`
+        circle_pitch_alignment: TranslateAnchor,
+        /// Width of the stroke of the circles in pixels. Defaults to `0`
+        circle_stroke_width: f64,
+        /// Color of the stroke of the circles. Defaults to `#000000`
+        circle_stroke_color: Color,
+        /// Opacity of the stroke of the circles, between `0` and `1`. Defaults to `1`
+        circle_stroke_opacity: f64,
+    }
+);
+
+declare_layer_specification!(
+    /// Layer drawing circles at points.
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-style-spec/layers/#circle>
+    CircleLayerSpecification {
+        kind: vector,
+        layout: CircleLayout,
+        paint: CirclePaint,
+    }
+);
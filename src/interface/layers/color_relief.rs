@@ -0,0 +1,33 @@
+//! Submodule for the specification of `color-relief` layers
+
+use super::{Visibility, declare_layer_properties, declare_layer_specification};
+use crate::interface::style::Color;
+
+declare_layer_properties!(
+    /// Layout properties of a `color-relief` layer
+    ColorReliefLayout {
+        /// Whether the layer is displayed. Defaults to `visible`
+        visibility: Visibility,
+    }
+);
+
+declare_layer_properties!(
+    /// Paint properties of a `color-relief` layer
+    ColorReliefPaint {
+        /// Opacity of the layer, between `0` and `1`. Defaults to `1`
+        color_relief_opacity: f64,
+        /// Color of each pixel, defined with an `elevation` expression
+        color_relief_color: Color,
+    }
+);
+
+declare_layer_specification!(
+    /// Layer coloring the elevation of a `raster-dem` source.
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-style-spec/layers/#color-relief>
+    ColorReliefLayerSpecification {
+        kind: raster,
+        layout: ColorReliefLayout,
+        paint: ColorReliefPaint,
+    }
+);
@@ -0,0 +1,46 @@
+//! Submodule for the specification of `fill` layers
+
+use super::{TranslateAnchor, Visibility, declare_layer_properties, declare_layer_specification};
+use crate::interface::style::Color;
+
+declare_layer_properties!(
+    /// Layout properties of a `fill` layer
+    FillLayout {
+        /// Sorts the features in ascending order, features with a higher key are drawn on top
+        fill_sort_key: f64,
+        /// Whether the layer is displayed. Defaults to `visible`
+        visibility: Visibility,
+    }
+);
+
+declare_layer_properties!(
+    /// Paint properties of a `fill` layer
+    FillPaint {
+        /// Whether the fill is antialiased. Defaults to `true`
+        fill_antialias: bool,
+        /// Opacity of the fill, between `0` and `1`. Defaults to `1`
+        fill_opacity: f64,
+        /// Color of the fill. Defaults to `#000000`
+        fill_color: Color,
+        /// Color of the outline of the fill. Defaults to `fill-color`
+        fill_outline_color: Color,
+        /// Offset of the geometry in pixels, positive values go right and down. Defaults to
+        /// `[0, 0]`
+        fill_translate: [f64; 2],
+        /// Frame of reference of `fill-translate`. Defaults to `map`
+        fill_translate_anchor: TranslateAnchor,
+        /// Name of the image used as the pattern of the fill
+        fill_pattern: String,
+    }
+);
+
+declare_layer_specification!(
+    /// Layer filling polygons.
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-style-spec/layers/#fill>
+    FillLayerSpecification {
+        kind: vector,
+        layout: FillLayout,
+        paint: FillPaint,
+    }
+);
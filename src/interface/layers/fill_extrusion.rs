@@ -0,0 +1,47 @@
+//! Submodule for the specification of `fill-extrusion` layers
+
+use super::{TranslateAnchor, Visibility, declare_layer_properties, declare_layer_specification};
+use crate::interface::style::Color;
+
+declare_layer_properties!(
+    /// Layout properties of a `fill-extrusion` layer
+    FillExtrusionLayout {
+        /// Whether the layer is displayed. Defaults to `visible`
+        visibility: Visibility,
+    }
+);
+
+declare_layer_properties!(
+    /// Paint properties of a `fill-extrusion` layer
+    FillExtrusionPaint {
+        /// Opacity of the whole layer, between `0` and `1`. Defaults to `1`
+        fill_extrusion_opacity: f64,
+        /// Base color of the extrusions. Defaults to `#000000`
+        fill_extrusion_color: Color,
+        /// Offset of the geometry in pixels, positive values go right and down. Defaults to
+        /// `[0, 0]`
+        fill_extrusion_translate: [f64; 2],
+        /// Frame of reference of `fill-extrusion-translate`. Defaults to `map`
+        fill_extrusion_translate_anchor: TranslateAnchor,
+        /// Name of the image used as the pattern of the extrusions
+        fill_extrusion_pattern: String,
+        /// Height of the extrusions in meters. Defaults to `0`
+        fill_extrusion_height: f64,
+        /// Height of the base of the extrusions in meters. Defaults to `0`
+        fill_extrusion_base: f64,
+        /// Whether to apply a vertical gradient to the sides of the extrusions. Defaults to
+        /// `true`
+        fill_extrusion_vertical_gradient: bool,
+    }
+);
+
+declare_layer_specification!(
+    /// Layer extruding polygons in 3D.
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-style-spec/layers/#fill-extrusion>
+    FillExtrusionLayerSpecification {
+        kind: vector,
+        layout: FillExtrusionLayout,
+        paint: FillExtrusionPaint,
+    }
+);
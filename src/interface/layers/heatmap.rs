@@ -0,0 +1,40 @@
+//! Submodule for the specification of `heatmap` layers
+
+use super::{Visibility, declare_layer_properties, declare_layer_specification};
+use crate::interface::style::Color;
+
+declare_layer_properties!(
+    /// Layout properties of a `heatmap` layer
+    HeatmapLayout {
+        /// Whether the layer is displayed. Defaults to `visible`
+        visibility: Visibility,
+    }
+);
+
+declare_layer_properties!(
+    /// Paint properties of a `heatmap` layer
+    HeatmapPaint {
+        /// Radius of influence of each point in pixels. Defaults to `30`
+        heatmap_radius: f64,
+        /// Contribution of each point to the heatmap. Defaults to `1`
+        heatmap_weight: f64,
+        /// Global multiplier of the weights, usually increased with the zoom level. Defaults to
+        /// `1`
+        heatmap_intensity: f64,
+        /// Color of each pixel, defined with a `heatmap-density` expression
+        heatmap_color: Color,
+        /// Opacity of the heatmap, between `0` and `1`. Defaults to `1`
+        heatmap_opacity: f64,
+    }
+);
+
+declare_layer_specification!(
+    /// Layer drawing a heatmap of the density of points.
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-style-spec/layers/#heatmap>
+    HeatmapLayerSpecification {
+        kind: vector,
+        layout: HeatmapLayout,
+        paint: HeatmapPaint,
+    }
+);
@@ -0,0 +1,46 @@
+//! Submodule for the specification of `hillshade` layers
+
+use super::{TranslateAnchor, Visibility, declare_layer_properties, declare_layer_specification};
+use crate::interface::style::Color;
+
+declare_layer_properties!(
+    /// Layout properties of a `hillshade` layer
+    HillshadeLayout {
+        /// Whether the layer is displayed. Defaults to `visible`
+        visibility: Visibility,
+    }
+);
+
+declare_layer_properties!(
+    /// Paint properties of a `hillshade` layer
+    HillshadePaint {
+        /// Direction of the light source in degrees, from `0` to `359`. Defaults to `335`
+        hillshade_illumination_direction: f64,
+        /// Altitude of the light source in degrees, from `0` to `90`. Defaults to `45`
+        hillshade_illumination_altitude: f64,
+        /// Frame of reference of `hillshade-illumination-direction`. Defaults to `viewport`
+        hillshade_illumination_anchor: TranslateAnchor,
+        /// Intensity of the shading, between `0` and `1`. Defaults to `0.5`
+        hillshade_exaggeration: f64,
+        /// Color of the areas facing away from the light source. Defaults to `#000000`
+        hillshade_shadow_color: Color,
+        /// Color of the areas facing towards the light source. Defaults to `#FFFFFF`
+        hillshade_highlight_color: Color,
+        /// Color to accentuate rugged terrain. Defaults to `#000000`
+        hillshade_accent_color: Color,
+        /// Algorithm used to compute the shading, such as `standard` or `multidirectional`.
+        /// Defaults to `standard`
+        hillshade_method: String,
+    }
+);
+
+declare_layer_specification!(
+    /// Layer shading the relief of a `raster-dem` source.
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-style-spec/layers/#hillshade>
+    HillshadeLayerSpecification {
+        kind: raster,
+        layout: HillshadeLayout,
+        paint: HillshadePaint,
+    }
+);
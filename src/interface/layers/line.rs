@@ -0,0 +1,67 @@
+//! Submodule for the specification of `line` layers
+
+use super::{
+    LineCap, LineJoin, TranslateAnchor, Visibility, declare_layer_properties,
+    declare_layer_specification,
+};
+use crate::interface::style::Color;
+
+declare_layer_properties!(
+    /// Layout properties of a `line` layer
+    LineLayout {
+        /// Display of the ends of the lines. Defaults to `butt`
+        line_cap: LineCap,
+        /// Display of the joins between the segments of the lines. Defaults to `miter`
+        line_join: LineJoin,
+        /// Limit to turn `miter` joins into `bevel` joins for sharp angles. Defaults to `2`
+        line_miter_limit: f64,
+        /// Limit to turn `round` joins into `miter` joins for shallow angles. Defaults to `1.05`
+        line_round_limit: f64,
+        /// Sorts the features in ascending order, features with a higher key are drawn on top
+        line_sort_key: f64,
+        /// Whether the layer is displayed. Defaults to `visible`
+        visibility: Visibility,
+    }
+);
+
+declare_layer_properties!(
+    /// Paint properties of a `line` layer
+    LinePaint {
+        /// Opacity of the lines, between `0` and `1`. Defaults to `1`
+        line_opacity: f64,
+        /// Color of the lines. Defaults to `#000000`
+        line_color: Color,
+        /// Offset of the geometry in pixels, positive values go right and down. Defaults to
+        /// `[0, 0]`
+        line_translate: [f64; 2],
+        /// Frame of reference of `line-translate`. Defaults to `map`
+        line_translate_anchor: TranslateAnchor,
+        /// Width of the lines in pixels. Defaults to `1`
+        line_width: f64,
+        /// Draws a casing outside of the lines with a gap of the given width in pixels. Defaults
+        /// to `0`
+        line_gap_width: f64,
+        /// Offset in pixels perpendicular to the direction of the lines. Defaults to `0`
+        line_offset: f64,
+        /// Blur applied to the lines in pixels. Defaults to `0`
+        line_blur: f64,
+        /// Lengths of alternating dashes and gaps, measured in line widths
+        line_dasharray: Vec<f64>,
+        /// Name of the image used as the pattern of the lines
+        line_pattern: String,
+        /// Gradient used to color the lines, defined with a `line-progress` expression. Requires
+        /// a `geojson` source with `lineMetrics` enabled
+        line_gradient: Color,
+    }
+);
+
+declare_layer_specification!(
+    /// Layer drawing lines.
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-style-spec/layers/#line>
+    LineLayerSpecification {
+        kind: vector,
+        layout: LineLayout,
+        paint: LinePaint,
+    }
+);
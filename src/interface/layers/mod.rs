@@ -0,0 +1,615 @@
+//! Rusty interface to the layers of the style of a `Map` of MapLibre GL JS, both their typed
+//! specifications and the bindings of the layers of a live map
+
+mod background;
+mod circle;
+mod color_relief;
+mod fill;
+mod fill_extrusion;
+mod heatmap;
+mod hillshade;
+mod line;
+mod property_types;
+mod raster;
+mod symbol;
+
+pub use background::{BackgroundLayerSpecification, BackgroundLayout, BackgroundPaint};
+pub use circle::{CircleLayerSpecification, CircleLayout, CirclePaint};
+pub use color_relief::{ColorReliefLayerSpecification, ColorReliefLayout, ColorReliefPaint};
+pub use fill::{FillLayerSpecification, FillLayout, FillPaint};
+pub use fill_extrusion::{
+    FillExtrusionLayerSpecification, FillExtrusionLayout, FillExtrusionPaint,
+};
+pub use heatmap::{HeatmapLayerSpecification, HeatmapLayout, HeatmapPaint};
+pub use hillshade::{HillshadeLayerSpecification, HillshadeLayout, HillshadePaint};
+pub use line::{LineLayerSpecification, LineLayout, LinePaint};
+pub use property_types::{
+    Alignment, Anchor, IconTextFit, LineCap, LineJoin, Overlap, RasterResampling, SymbolPlacement,
+    SymbolZOrder, TextJustify, TextTransform, TranslateAnchor, Visibility,
+};
+pub use raster::{RasterLayerSpecification, RasterLayout, RasterPaint};
+pub use symbol::{SymbolLayerSpecification, SymbolLayout, SymbolPaint};
+
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use wasm_bindgen::JsValue;
+
+use super::Error;
+use crate::bindings;
+
+/// Generate a struct with the layout or paint properties of a type of layer. Every property is
+/// optional and can be either a literal or an expression
+macro_rules! declare_layer_properties {
+    (
+        $(#[doc = $docs:expr])*
+        $name:ident {
+            $(
+                $(#[doc = $field_docs:expr])*
+                $field:ident: $field_type:ty
+            ),* $(,)?
+        }
+    ) => {
+        $(#[doc = $docs])*
+        // Layouts with only `visibility` could derive `Eq`, but not the rest of the structs
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Debug, PartialEq, Clone, Default, ::serde::Serialize, ::serde::Deserialize)]
+        #[serde(rename_all = "kebab-case", deny_unknown_fields)]
+        pub struct $name {
+            $(
+                $(#[doc = $field_docs])*
+                #[serde(skip_serializing_if = "Option::is_none")]
+                pub $field: Option<$crate::interface::style::PropertyValue<$field_type>>,
+            )*
+        }
+    };
+}
+
+/// Generate the specification struct of a type of layer. `background` layers don't have a
+/// source, `raster` like layers have a source and `vector` like layers additionally can pick
+/// a layer of the source and filter its features
+macro_rules! declare_layer_specification {
+    (
+        $(#[doc = $docs:expr])*
+        $name:ident {
+            kind: background,
+            layout: $layout:ty,
+            paint: $paint:ty,
+        }
+    ) => {
+        $(#[doc = $docs])*
+        #[derive(Debug, PartialEq, Clone, ::serde::Serialize, ::serde::Deserialize)]
+        #[serde(deny_unknown_fields)]
+        pub struct $name {
+            /// Unique identifier of the layer
+            pub id: String,
+            /// Arbitrary properties useful to track with the layer
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub metadata: Option<serde_json::Value>,
+            /// Minimum zoom level at which the layer is visible
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub minzoom: Option<f64>,
+            /// Maximum zoom level at which the layer is visible
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub maxzoom: Option<f64>,
+            /// Layout properties of the layer
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub layout: Option<$layout>,
+            /// Paint properties of the layer
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub paint: Option<$paint>,
+        }
+
+        impl $name {
+            /// Create a layer specification with the given identifier
+            pub fn new(id: impl Into<String>) -> Self {
+                Self {
+                    id: id.into(),
+                    metadata: None,
+                    minzoom: None,
+                    maxzoom: None,
+                    layout: None,
+                    paint: None,
+                }
+            }
+
+            declare_layer_specification!(@builders $layout, $paint);
+        }
+    };
+    (
+        $(#[doc = $docs:expr])*
+        $name:ident {
+            kind: raster,
+            layout: $layout:ty,
+            paint: $paint:ty,
+        }
+    ) => {
+        $(#[doc = $docs])*
+        #[derive(Debug, PartialEq, Clone, ::serde::Serialize, ::serde::Deserialize)]
+        #[serde(deny_unknown_fields)]
+        pub struct $name {
+            /// Unique identifier of the layer
+            pub id: String,
+            /// Arbitrary properties useful to track with the layer
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub metadata: Option<serde_json::Value>,
+            /// Identifier of the source of the layer
+            pub source: String,
+            /// Minimum zoom level at which the layer is visible
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub minzoom: Option<f64>,
+            /// Maximum zoom level at which the layer is visible
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub maxzoom: Option<f64>,
+            /// Layout properties of the layer
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub layout: Option<$layout>,
+            /// Paint properties of the layer
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub paint: Option<$paint>,
+        }
+
+        impl $name {
+            /// Create a layer specification with the given identifier drawing the source with
+            /// the given identifier
+            pub fn new(id: impl Into<String>, source: impl Into<String>) -> Self {
+                Self {
+                    id: id.into(),
+                    metadata: None,
+                    source: source.into(),
+                    minzoom: None,
+                    maxzoom: None,
+                    layout: None,
+                    paint: None,
+                }
+            }
+
+            declare_layer_specification!(@builders $layout, $paint);
+        }
+    };
+    (
+        $(#[doc = $docs:expr])*
+        $name:ident {
+            kind: vector,
+            layout: $layout:ty,
+            paint: $paint:ty,
+        }
+    ) => {
+        $(#[doc = $docs])*
+        #[derive(Debug, PartialEq, Clone, ::serde::Serialize, ::serde::Deserialize)]
+        #[serde(deny_unknown_fields)]
+        pub struct $name {
+            /// Unique identifier of the layer
+            pub id: String,
+            /// Arbitrary properties useful to track with the layer
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub metadata: Option<serde_json::Value>,
+            /// Identifier of the source of the layer
+            pub source: String,
+            /// Layer of the vector tile source to use. Required for vector tile sources and
+            /// prohibited for the rest
+            #[serde(rename = "source-layer", skip_serializing_if = "Option::is_none")]
+            pub source_layer: Option<String>,
+            /// Minimum zoom level at which the layer is visible
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub minzoom: Option<f64>,
+            /// Maximum zoom level at which the layer is visible
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub maxzoom: Option<f64>,
+            /// Expression specifying the conditions the features of the source must match to be
+            /// displayed
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub filter: Option<$crate::interface::style::Expression<bool>>,
+            /// Layout properties of the layer
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub layout: Option<$layout>,
+            /// Paint properties of the layer
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub paint: Option<$paint>,
+        }
+
+        impl $name {
+            /// Create a layer specification with the given identifier drawing the source with
+            /// the given identifier
+            pub fn new(id: impl Into<String>, source: impl Into<String>) -> Self {
+                Self {
+                    id: id.into(),
+                    metadata: None,
+                    source: source.into(),
+                    source_layer: None,
+                    minzoom: None,
+                    maxzoom: None,
+                    filter: None,
+                    layout: None,
+                    paint: None,
+                }
+            }
+
+            /// Specify the layer of the vector tile source to use
+            #[must_use]
+            pub fn with_source_layer(self, source_layer: impl Into<String>) -> Self {
+                Self {
+                    source_layer: Some(source_layer.into()),
+                    ..self
+                }
+            }
+
+            /// Specify the expression the features of the source must match to be displayed
+            #[must_use]
+            pub fn with_filter(
+                self,
+                filter: impl Into<$crate::interface::style::Expression<bool>>,
+            ) -> Self {
+                Self {
+                    filter: Some(filter.into()),
+                    ..self
+                }
+            }
+
+            declare_layer_specification!(@builders $layout, $paint);
+        }
+    };
+    (@builders $layout:ty, $paint:ty) => {
+        /// Specify arbitrary properties useful to track with the layer
+        #[must_use]
+        pub fn with_metadata(self, metadata: impl Into<serde_json::Value>) -> Self {
+            Self {
+                metadata: Some(metadata.into()),
+                ..self
+            }
+        }
+
+        /// Specify the minimum zoom level at which the layer is visible
+        #[must_use]
+        pub fn with_minzoom(self, minzoom: impl Into<f64>) -> Self {
+            Self {
+                minzoom: Some(minzoom.into()),
+                ..self
+            }
+        }
+
+        /// Specify the maximum zoom level at which the layer is visible
+        #[must_use]
+        pub fn with_maxzoom(self, maxzoom: impl Into<f64>) -> Self {
+            Self {
+                maxzoom: Some(maxzoom.into()),
+                ..self
+            }
+        }
+
+        /// Specify the layout properties of the layer
+        #[must_use]
+        pub fn with_layout(self, layout: $layout) -> Self {
+            Self {
+                layout: Some(layout),
+                ..self
+            }
+        }
+
+        /// Specify the paint properties of the layer
+        #[must_use]
+        pub fn with_paint(self, paint: $paint) -> Self {
+            Self {
+                paint: Some(paint),
+                ..self
+            }
+        }
+    };
+}
+
+pub(crate) use declare_layer_properties;
+pub(crate) use declare_layer_specification;
+
+/// Specification of a layer of the style, tagged with its type.
+///
+/// MapLibre docs: <https://maplibre.org/maplibre-style-spec/layers/>
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+pub enum LayerSpecification {
+    /// Layer filling the whole map with a color or a pattern
+    Background(Box<BackgroundLayerSpecification>),
+    /// Layer filling polygons
+    Fill(Box<FillLayerSpecification>),
+    /// Layer drawing lines
+    Line(Box<LineLayerSpecification>),
+    /// Layer drawing icons and texts
+    Symbol(Box<SymbolLayerSpecification>),
+    /// Layer drawing circles at points
+    Circle(Box<CircleLayerSpecification>),
+    /// Layer drawing a heatmap of the density of points
+    Heatmap(Box<HeatmapLayerSpecification>),
+    /// Layer extruding polygons in 3D
+    FillExtrusion(Box<FillExtrusionLayerSpecification>),
+    /// Layer drawing raster tiles
+    Raster(Box<RasterLayerSpecification>),
+    /// Layer shading the relief of a `raster-dem` source
+    Hillshade(Box<HillshadeLayerSpecification>),
+    /// Layer coloring the elevation of a `raster-dem` source
+    ColorRelief(Box<ColorReliefLayerSpecification>),
+}
+
+impl LayerSpecification {
+    /// Get the identifier of the layer
+    #[must_use]
+    pub fn id(&self) -> &str {
+        match self {
+            Self::Background(layer) => &layer.id,
+            Self::Fill(layer) => &layer.id,
+            Self::Line(layer) => &layer.id,
+            Self::Symbol(layer) => &layer.id,
+            Self::Circle(layer) => &layer.id,
+            Self::Heatmap(layer) => &layer.id,
+            Self::FillExtrusion(layer) => &layer.id,
+            Self::Raster(layer) => &layer.id,
+            Self::Hillshade(layer) => &layer.id,
+            Self::ColorRelief(layer) => &layer.id,
+        }
+    }
+
+    /// Try converting `LayerSpecification` into the equivalent `JsValue`
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn as_js_value(&self) -> Result<JsValue, Error> {
+        self.serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+    }
+}
+
+/// Implement the conversion of each layer specification into `LayerSpecification`
+macro_rules! impl_from_layer_specification {
+    ($($variant:ident($specification:ty)),* $(,)?) => {
+        $(
+            impl From<$specification> for LayerSpecification {
+                fn from(value: $specification) -> Self {
+                    Self::$variant(Box::new(value))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_layer_specification!(
+    Background(BackgroundLayerSpecification),
+    Fill(FillLayerSpecification),
+    Line(LineLayerSpecification),
+    Symbol(SymbolLayerSpecification),
+    Circle(CircleLayerSpecification),
+    Heatmap(HeatmapLayerSpecification),
+    FillExtrusion(FillExtrusionLayerSpecification),
+    Raster(RasterLayerSpecification),
+    Hillshade(HillshadeLayerSpecification),
+    ColorRelief(ColorReliefLayerSpecification),
+);
+
+impl TryFrom<JsValue> for LayerSpecification {
+    type Error = Error;
+
+    fn try_from(value: JsValue) -> Result<Self, Self::Error> {
+        serde_wasm_bindgen::from_value(value)
+    }
+}
+
+impl TryFrom<LayerSpecification> for JsValue {
+    type Error = Error;
+
+    fn try_from(value: LayerSpecification) -> Result<Self, Self::Error> {
+        value.as_js_value()
+    }
+}
+
+impl TryFrom<&LayerSpecification> for JsValue {
+    type Error = Error;
+
+    fn try_from(value: &LayerSpecification) -> Result<Self, Self::Error> {
+        value.as_js_value()
+    }
+}
+
+/// Layer of the style of a `Map`, as returned by `Map::get_layer`
+#[derive(Debug, Clone, PartialEq)]
+pub struct StyleLayer {
+    /// JS object that the struct wraps
+    js_value: bindings::StyleLayer,
+}
+
+impl StyleLayer {
+    /// Get the identifier of the layer
+    #[must_use]
+    pub fn id(&self) -> String {
+        self.js_value.id()
+    }
+
+    /// Get the type of the layer, such as `fill` or `symbol`
+    #[must_use]
+    pub fn layer_type(&self) -> String {
+        self.js_value.layer_type()
+    }
+
+    /// Get the identifier of the source of the layer, if any
+    #[must_use]
+    pub fn source(&self) -> Option<String> {
+        self.js_value.source()
+    }
+
+    /// Get the layer of the vector tile source used by the layer, if any
+    #[must_use]
+    pub fn source_layer(&self) -> Option<String> {
+        self.js_value.source_layer()
+    }
+
+    /// Get the minimum zoom level at which the layer is visible, if set
+    #[must_use]
+    pub fn minzoom(&self) -> Option<f64> {
+        self.js_value.minzoom()
+    }
+
+    /// Get the maximum zoom level at which the layer is visible, if set
+    #[must_use]
+    pub fn maxzoom(&self) -> Option<f64> {
+        self.js_value.maxzoom()
+    }
+
+    /// Get the specification of the layer
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn specification<T: DeserializeOwned>(&self) -> Result<T, Error> {
+        serde_wasm_bindgen::from_value(self.js_value.serialize())
+    }
+
+    /// Get the underlying JS object
+    #[must_use]
+    pub fn into_inner(self) -> bindings::StyleLayer {
+        self.js_value
+    }
+}
+
+impl From<bindings::StyleLayer> for StyleLayer {
+    fn from(value: bindings::StyleLayer) -> Self {
+        Self { js_value: value }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::interface::style::{Color, Expression, Interpolation};
+    use serde_json::json;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn fill_layer_serialization() {
+        let layer: LayerSpecification = FillLayerSpecification::new("parks", "openmaptiles")
+            .with_source_layer("park")
+            .with_filter(Expression::geometry_type().equals("Polygon"))
+            .with_minzoom(4)
+            .with_paint(FillPaint {
+                fill_color: Some(Color::from("#2e7d32").into()),
+                fill_opacity: Some(
+                    Expression::interpolate(
+                        Interpolation::Linear,
+                        Expression::zoom(),
+                        [(4., 0.2), (10., 0.8)],
+                    )
+                    .into(),
+                ),
+                fill_translate_anchor: Some(TranslateAnchor::Viewport.into()),
+                ..Default::default()
+            })
+            .into();
+
+        assert_eq!(
+            serde_json::to_value(&layer).expect("Serializing should work"),
+            json!({
+                "id": "parks",
+                "type": "fill",
+                "source": "openmaptiles",
+                "source-layer": "park",
+                "minzoom": 4.0,
+                "filter": ["==", ["geometry-type"], "Polygon"],
+                "paint": {
+                    "fill-opacity": ["interpolate", ["linear"], ["zoom"], 4.0, 0.2, 10.0, 0.8],
+                    "fill-color": "#2e7d32",
+                    "fill-translate-anchor": "viewport",
+                },
+            })
+        );
+        assert_eq!(layer.id(), "parks");
+    }
+
+    #[wasm_bindgen_test]
+    fn symbol_layer_serialization() {
+        let layer: LayerSpecification = SymbolLayerSpecification::new("labels", "places")
+            .with_layout(SymbolLayout {
+                text_field: Some(Expression::get("name").into()),
+                text_font: Some(vec!["Noto Sans Regular".to_string()].into()),
+                text_variable_anchor: Some(vec![Anchor::Top, Anchor::BottomLeft].into()),
+                symbol_z_order: Some(SymbolZOrder::ViewportY.into()),
+                visibility: Some(Visibility::None.into()),
+                ..Default::default()
+            })
+            .into();
+
+        assert_eq!(
+            serde_json::to_value(&layer).expect("Serializing should work"),
+            json!({
+                "id": "labels",
+                "type": "symbol",
+                "source": "places",
+                "layout": {
+                    "symbol-z-order": "viewport-y",
+                    "text-field": ["get", "name"],
+                    "text-font": ["Noto Sans Regular"],
+                    "text-variable-anchor": ["top", "bottom-left"],
+                    "visibility": "none",
+                },
+            })
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn layer_specification_deserialization() {
+        let layers: Vec<LayerSpecification> = serde_json::from_value(json!([
+            {"id": "background", "type": "background", "paint": {"background-color": "#fff"}},
+            {"id": "roads", "type": "line", "source": "osm", "source-layer": "roads",
+             "layout": {"line-cap": "round"}, "paint": {"line-width": 2}},
+            {"id": "buildings", "type": "fill-extrusion", "source": "osm",
+             "paint": {"fill-extrusion-height": ["get", "height"]}},
+            {"id": "points", "type": "circle", "source": "points"},
+            {"id": "density", "type": "heatmap", "source": "points"},
+            {"id": "satellite", "type": "raster", "source": "satellite",
+             "paint": {"raster-resampling": "nearest"}},
+            {"id": "shading", "type": "hillshade", "source": "dem"},
+            {"id": "relief", "type": "color-relief", "source": "dem"},
+        ]))
+        .expect("Deserializing should work");
+
+        assert!(matches!(&layers[0], LayerSpecification::Background(_)));
+        assert!(matches!(&layers[2], LayerSpecification::FillExtrusion(_)));
+        assert!(matches!(&layers[7], LayerSpecification::ColorRelief(_)));
+        let LayerSpecification::Line(line) = &layers[1] else {
+            panic!("The second layer should be a line layer");
+        };
+        assert_eq!(line.source_layer.as_deref(), Some("roads"));
+        assert_eq!(
+            line.layout
+                .as_ref()
+                .and_then(|layout| layout.line_cap.clone()),
+            Some(LineCap::Round.into())
+        );
+        assert_eq!(
+            layers
+                .iter()
+                .map(LayerSpecification::id)
+                .collect::<Vec<_>>(),
+            vec![
+                "background",
+                "roads",
+                "buildings",
+                "points",
+                "density",
+                "satellite",
+                "shading",
+                "relief"
+            ]
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn layer_specification_js_round_trip() {
+        let layer: LayerSpecification = CircleLayerSpecification::new("points", "points")
+            .with_paint(CirclePaint {
+                circle_radius: Some(6.0.into()),
+                circle_color: Some(Color::from("#ff0000").into()),
+                ..Default::default()
+            })
+            .into();
+        let retrieved: LayerSpecification = layer
+            .as_js_value()
+            .expect("Conversion to JS should work")
+            .try_into()
+            .expect("Back conversion should work");
+
+        assert_eq!(retrieved, layer);
+    }
+}
@@ -0,0 +1,172 @@
+//! Submodule for the enumerations used as values of the layout and paint properties of the layers
+
+use serde::{Deserialize, Serialize};
+
+/// Whether a layer is displayed
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Visibility {
+    /// The layer is shown
+    #[default]
+    Visible,
+    /// The layer is not shown
+    None,
+}
+
+/// Frame of reference of a translation or an orientation
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TranslateAnchor {
+    /// Relative to the map
+    Map,
+    /// Relative to the viewport
+    Viewport,
+}
+
+/// Alignment of icons, texts or circles when the map is rotated or pitched
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Alignment {
+    /// Aligned to the plane of the map
+    Map,
+    /// Aligned to the plane of the viewport
+    Viewport,
+    /// Each glyph is aligned to the viewport. Only valid for `text-rotation-alignment`
+    ViewportGlyph,
+    /// Automatically chosen depending on the `symbol-placement`
+    Auto,
+}
+
+/// Display of the ends of a line
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LineCap {
+    /// Squared off at the exact endpoint
+    Butt,
+    /// Rounded, extending beyond the endpoint by half of the width
+    Round,
+    /// Squared off, extending beyond the endpoint by half of the width
+    Square,
+}
+
+/// Display of the joins between the segments of a line
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LineJoin {
+    /// Squared off join
+    Bevel,
+    /// Rounded join
+    Round,
+    /// Sharp, angled join
+    Miter,
+}
+
+/// Placement of a symbol relative to its geometry
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SymbolPlacement {
+    /// Placed at the point where the geometry is located
+    Point,
+    /// Placed along the line of the geometry
+    Line,
+    /// Placed at the center of the line of the geometry
+    LineCenter,
+}
+
+/// Order in which overlapping symbols are rendered
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SymbolZOrder {
+    /// Sorted by `symbol-sort-key` if set, otherwise by `viewport-y`
+    Auto,
+    /// Sorted by their y-position on the viewport
+    ViewportY,
+    /// Sorted by the order of the source data
+    Source,
+}
+
+/// Behavior of a symbol colliding with previously drawn symbols
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Overlap {
+    /// The symbol is hidden if it collides with any other symbol
+    Never,
+    /// The symbol is always shown
+    Always,
+    /// The symbol is shown only if it doesn't collide with symbols set to `never`
+    Cooperative,
+}
+
+/// How an icon is scaled to fit the text associated with it
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum IconTextFit {
+    /// The icon is displayed at its intrinsic size
+    None,
+    /// The icon is scaled in the x-dimension to fit the width of the text
+    Width,
+    /// The icon is scaled in the y-dimension to fit the height of the text
+    Height,
+    /// The icon is scaled in both dimensions to fit the text
+    Both,
+}
+
+/// Part of a symbol placed closest to its anchor
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Anchor {
+    /// The center of the symbol is placed closest to the anchor
+    Center,
+    /// The left side of the symbol is placed closest to the anchor
+    Left,
+    /// The right side of the symbol is placed closest to the anchor
+    Right,
+    /// The top of the symbol is placed closest to the anchor
+    Top,
+    /// The bottom of the symbol is placed closest to the anchor
+    Bottom,
+    /// The top left corner of the symbol is placed closest to the anchor
+    TopLeft,
+    /// The top right corner of the symbol is placed closest to the anchor
+    TopRight,
+    /// The bottom left corner of the symbol is placed closest to the anchor
+    BottomLeft,
+    /// The bottom right corner of the symbol is placed closest to the anchor
+    BottomRight,
+}
+
+/// Justification of the lines of a text
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TextJustify {
+    /// Justified towards the anchor position
+    Auto,
+    /// Left justified
+    Left,
+    /// Centered
+    Center,
+    /// Right justified
+    Right,
+}
+
+/// Capitalization transformation applied to a text
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TextTransform {
+    /// The text is displayed as is
+    None,
+    /// The text is displayed in uppercase
+    Uppercase,
+    /// The text is displayed in lowercase
+    Lowercase,
+}
+
+/// Resampling used when overscaling raster tiles
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RasterResampling {
+    /// Linear interpolation between the neighboring pixels
+    Linear,
+    /// Value of the nearest pixel
+    Nearest,
+}
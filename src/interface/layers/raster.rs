@@ -0,0 +1,44 @@
+//! Submodule for the specification of `raster` layers
+
+use super::{RasterResampling, Visibility, declare_layer_properties, declare_layer_specification};
+
+declare_layer_properties!(
+    /// Layout properties of a `raster` layer
+    RasterLayout {
+        /// Whether the layer is displayed. Defaults to `visible`
+        visibility: Visibility,
+    }
+);
+
+declare_layer_properties!(
+    /// Paint properties of a `raster` layer
+    RasterPaint {
+        /// Opacity of the raster, between `0` and `1`. Defaults to `1`
+        raster_opacity: f64,
+        /// Rotation of the hues around the color wheel in degrees. Defaults to `0`
+        raster_hue_rotate: f64,
+        /// Increase of the brightness, between `0` and `1`. Defaults to `0`
+        raster_brightness_min: f64,
+        /// Decrease of the brightness, between `0` and `1`. Defaults to `1`
+        raster_brightness_max: f64,
+        /// Increase or decrease of the saturation, between `-1` and `1`. Defaults to `0`
+        raster_saturation: f64,
+        /// Increase or decrease of the contrast, between `-1` and `1`. Defaults to `0`
+        raster_contrast: f64,
+        /// Resampling used when overscaling the tiles. Defaults to `linear`
+        raster_resampling: RasterResampling,
+        /// Duration in milliseconds of the fade-in of new tiles. Defaults to `300`
+        raster_fade_duration: f64,
+    }
+);
+
+declare_layer_specification!(
+    /// Layer drawing raster tiles.
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-style-spec/layers/#raster>
+    RasterLayerSpecification {
+        kind: raster,
+        layout: RasterLayout,
+        paint: RasterPaint,
+    }
+);
@@ -0,0 +1,164 @@
+//! Submodule for the specification of `symbol` layers
+
+use super::{
+    Alignment, Anchor, IconTextFit, Overlap, SymbolPlacement, SymbolZOrder, TextJustify,
+    TextTransform, TranslateAnchor, Visibility, declare_layer_properties,
+    declare_layer_specification,
+};
+use crate::interface::style::Color;
+
+declare_layer_properties!(
+    /// Layout properties of a `symbol` layer
+    SymbolLayout {
+        /// Placement of the symbols relative to their geometry. Defaults to `point`
+        symbol_placement: SymbolPlacement,
+        /// Distance in pixels between two symbols placed along a line. Defaults to `250`
+        symbol_spacing: f64,
+        /// Whether to avoid placing the symbols across the edges of the tiles. Defaults to
+        /// `false`
+        symbol_avoid_edges: bool,
+        /// Sorts the features in ascending order, features with a lower key are placed first
+        symbol_sort_key: f64,
+        /// Order in which overlapping symbols are rendered. Defaults to `auto`
+        symbol_z_order: SymbolZOrder,
+        /// Whether the icons are visible even if they collide with other symbols. Defaults to
+        /// `false`
+        icon_allow_overlap: bool,
+        /// Behavior of the icons colliding with other symbols. Overrides `icon-allow-overlap`
+        icon_overlap: Overlap,
+        /// Whether other symbols can be visible even if they collide with the icons. Defaults to
+        /// `false`
+        icon_ignore_placement: bool,
+        /// Whether the texts can be shown without their icons when they collide. Defaults to
+        /// `false`
+        icon_optional: bool,
+        /// Alignment of the icons when the map is rotated. Defaults to `auto`
+        icon_rotation_alignment: Alignment,
+        /// Scale factor of the icons. Defaults to `1`
+        icon_size: f64,
+        /// How the icons are scaled to fit their texts. Defaults to `none`
+        icon_text_fit: IconTextFit,
+        /// Padding in pixels around the texts used by `icon-text-fit`, in clockwise order from
+        /// the top. Defaults to `[0, 0, 0, 0]`
+        icon_text_fit_padding: [f64; 4],
+        /// Name of the image used as the icons
+        icon_image: String,
+        /// Clockwise rotation of the icons in degrees. Defaults to `0`
+        icon_rotate: f64,
+        /// Padding in pixels around the icons used for collision detection. Defaults to `[2]`
+        icon_padding: Vec<f64>,
+        /// Whether the icons may be flipped to prevent them from rendering upside down. Defaults
+        /// to `false`
+        icon_keep_upright: bool,
+        /// Offset of the icons from their anchor. Defaults to `[0, 0]`
+        icon_offset: [f64; 2],
+        /// Part of the icons placed closest to their anchor. Defaults to `center`
+        icon_anchor: Anchor,
+        /// Orientation of the icons when the map is pitched. Defaults to `auto`
+        icon_pitch_alignment: Alignment,
+        /// Orientation of the texts when the map is pitched. Defaults to `auto`
+        text_pitch_alignment: Alignment,
+        /// Alignment of the texts when the map is rotated. Defaults to `auto`
+        text_rotation_alignment: Alignment,
+        /// Value to use as the texts
+        text_field: String,
+        /// Font stack used to display the texts. Defaults to
+        /// `["Open Sans Regular", "Arial Unicode MS Regular"]`
+        text_font: Vec<String>,
+        /// Size of the font in pixels. Defaults to `16`
+        text_size: f64,
+        /// Maximum width in ems of a line of text before it is wrapped. Defaults to `10`
+        text_max_width: f64,
+        /// Height in ems of each line of text. Defaults to `1.2`
+        text_line_height: f64,
+        /// Spacing in ems between the letters of the texts. Defaults to `0`
+        text_letter_spacing: f64,
+        /// Justification of the lines of the texts. Defaults to `center`
+        text_justify: TextJustify,
+        /// Radial offset in ems of the texts from their anchor. Defaults to `0`
+        text_radial_offset: f64,
+        /// Candidate anchors used to place the texts, tried in order
+        text_variable_anchor: Vec<Anchor>,
+        /// Candidate anchors with their offsets used to place the texts, alternating an anchor
+        /// and a `[x, y]` offset in ems
+        text_variable_anchor_offset: Vec<serde_json::Value>,
+        /// Part of the texts placed closest to their anchor. Defaults to `center`
+        text_anchor: Anchor,
+        /// Maximum angle change in degrees between adjacent characters of texts placed along a
+        /// line. Defaults to `45`
+        text_max_angle: f64,
+        /// Writing modes tried in order to place the texts, either `horizontal` or `vertical`
+        text_writing_mode: Vec<String>,
+        /// Clockwise rotation of the texts in degrees. Defaults to `0`
+        text_rotate: f64,
+        /// Padding in pixels around the texts used for collision detection. Defaults to `2`
+        text_padding: f64,
+        /// Whether the texts may be flipped to prevent them from rendering upside down. Defaults
+        /// to `true`
+        text_keep_upright: bool,
+        /// Capitalization transformation applied to the texts. Defaults to `none`
+        text_transform: TextTransform,
+        /// Offset in ems of the texts from their anchor. Defaults to `[0, 0]`
+        text_offset: [f64; 2],
+        /// Whether the texts are visible even if they collide with other symbols. Defaults to
+        /// `false`
+        text_allow_overlap: bool,
+        /// Behavior of the texts colliding with other symbols. Overrides `text-allow-overlap`
+        text_overlap: Overlap,
+        /// Whether other symbols can be visible even if they collide with the texts. Defaults to
+        /// `false`
+        text_ignore_placement: bool,
+        /// Whether the icons can be shown without their texts when they collide. Defaults to
+        /// `false`
+        text_optional: bool,
+        /// Whether the layer is displayed. Defaults to `visible`
+        visibility: Visibility,
+    }
+);
+
+declare_layer_properties!(
+    /// Paint properties of a `symbol` layer
+    SymbolPaint {
+        /// Opacity of the icons, between `0` and `1`. Defaults to `1`
+        icon_opacity: f64,
+        /// Color of the icons. Only applies to SDF icons. Defaults to `#000000`
+        icon_color: Color,
+        /// Color of the halo of the icons. Only applies to SDF icons. Defaults to `transparent`
+        icon_halo_color: Color,
+        /// Width in pixels of the halo of the icons. Defaults to `0`
+        icon_halo_width: f64,
+        /// Fade out in pixels of the halo of the icons. Defaults to `0`
+        icon_halo_blur: f64,
+        /// Offset of the icons in pixels, positive values go right and down. Defaults to
+        /// `[0, 0]`
+        icon_translate: [f64; 2],
+        /// Frame of reference of `icon-translate`. Defaults to `map`
+        icon_translate_anchor: TranslateAnchor,
+        /// Opacity of the texts, between `0` and `1`. Defaults to `1`
+        text_opacity: f64,
+        /// Color of the texts. Defaults to `#000000`
+        text_color: Color,
+        /// Color of the halo of the texts. Defaults to `transparent`
+        text_halo_color: Color,
+        /// Width in pixels of the halo of the texts. Defaults to `0`
+        text_halo_width: f64,
+        /// Fade out in pixels of the halo of the texts. Defaults to `0`
+        text_halo_blur: f64,
+        /// Offset of the texts in pixels, positive values go right and down. Defaults to
+        /// `[0, 0]`
+        text_translate: [f64; 2],
+        /// Frame of reference of `text-translate`. Defaults to `map`
+        text_translate_anchor: TranslateAnchor,
+    }
+);
+
+declare_layer_specification!(
+    /// Layer drawing icons and texts.
+    ///
+    /// MapLibre docs: <https://maplibre.org/maplibre-style-spec/layers/#symbol>
+    SymbolLayerSpecification {
+        kind: vector,
+        layout: SymbolLayout,
+        paint: SymbolPaint,
+    }
+);
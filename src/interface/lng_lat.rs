@@ -110,6 +110,12 @@ impl TryFrom<(f64, f64)> for LngLat {
     }
 }
 
+impl From<bindings::LngLat> for LngLat {
+    fn from(value: bindings::LngLat) -> Self {
+        Self { js_value: value }
+    }
+}
+
 impl TryFrom<JsValue> for LngLat {
     type Error = super::Error;
 
@@ -0,0 +1,348 @@
+//! Rusty interface to the bindings of `LngLatBounds` of MapLibre GL JS
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    bindings,
+    interface::{LngLat, LngLatBoundsLike, LngLatLike},
+};
+
+/// `LngLatBounds` object representing a geographical bounding box, defined by its southwest and
+/// northeast points in longitude and latitude
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct LngLatBounds {
+    /// JS object that the struct wraps
+    #[serde(with = "serde_wasm_bindgen::preserve")]
+    js_value: bindings::LngLatBounds,
+}
+
+impl LngLatBounds {
+    /// Create a `LngLatBounds` object with the given southwest and northeast corners
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors generated from JS
+    pub fn new(sw: impl Into<LngLatLike>, ne: impl Into<LngLatLike>) -> Result<Self, super::Error> {
+        let js_value =
+            bindings::LngLatBounds::new(&sw.into().as_js_value()?, &ne.into().as_js_value()?)?;
+        Ok(Self { js_value })
+    }
+
+    /// Create a `LngLatBounds` object from the given coordinates extended by a radius in meters
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors generated from JS
+    pub fn from_lng_lat(center: impl Into<LngLatLike>, radius: f64) -> Result<Self, super::Error> {
+        let js_value = bindings::LngLatBounds::from_lng_lat(&center.into().as_js_value()?, radius)?;
+        Ok(Self { js_value })
+    }
+
+    /// Set the northeast corner of the bounding box
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors generated from JS
+    pub fn set_north_east(&mut self, ne: impl Into<LngLatLike>) -> Result<(), super::Error> {
+        self.js_value.set_north_east(&ne.into().as_js_value()?)?;
+        Ok(())
+    }
+
+    /// Set the southwest corner of the bounding box
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors generated from JS
+    pub fn set_south_west(&mut self, sw: impl Into<LngLatLike>) -> Result<(), super::Error> {
+        self.js_value.set_south_west(&sw.into().as_js_value()?)?;
+        Ok(())
+    }
+
+    /// Extend the bounds to include a given point
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors generated from JS
+    pub fn extend(&mut self, lnglat: impl Into<LngLatLike>) -> Result<(), super::Error> {
+        self.js_value.extend(&lnglat.into().as_js_value()?)?;
+        Ok(())
+    }
+
+    /// Extend the bounds to include a given bounding box
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors generated from JS
+    pub fn extend_bounds(
+        &mut self,
+        bounds: impl Into<LngLatBoundsLike>,
+    ) -> Result<(), super::Error> {
+        self.js_value.extend_bounds(&bounds.into().as_js_value()?)?;
+        Ok(())
+    }
+
+    /// Returns the geographical coordinate equidistant from the bounding box's corners
+    #[must_use]
+    pub fn get_center(&self) -> LngLat {
+        self.js_value.get_center().into()
+    }
+
+    /// Returns the southwest corner of the bounding box
+    #[must_use]
+    pub fn get_south_west(&self) -> LngLat {
+        self.js_value.get_south_west().into()
+    }
+
+    /// Returns the northeast corner of the bounding box
+    #[must_use]
+    pub fn get_north_east(&self) -> LngLat {
+        self.js_value.get_north_east().into()
+    }
+
+    /// Returns the northwest corner of the bounding box
+    #[must_use]
+    pub fn get_north_west(&self) -> LngLat {
+        self.js_value.get_north_west().into()
+    }
+
+    /// Returns the southeast corner of the bounding box
+    #[must_use]
+    pub fn get_south_east(&self) -> LngLat {
+        self.js_value.get_south_east().into()
+    }
+
+    /// Returns the west edge of the bounding box
+    #[must_use]
+    pub fn get_west(&self) -> f64 {
+        self.js_value.get_west()
+    }
+
+    /// Returns the south edge of the bounding box
+    #[must_use]
+    pub fn get_south(&self) -> f64 {
+        self.js_value.get_south()
+    }
+
+    /// Returns the east edge of the bounding box
+    #[must_use]
+    pub fn get_east(&self) -> f64 {
+        self.js_value.get_east()
+    }
+
+    /// Returns the north edge of the bounding box
+    #[must_use]
+    pub fn get_north(&self) -> f64 {
+        self.js_value.get_north()
+    }
+
+    /// Returns the bounding box represented as the southwest and northeast coordinates
+    #[must_use]
+    pub fn to_array(&self) -> [[f64; 2]; 2] {
+        [
+            self.get_south_west().to_array(),
+            self.get_north_east().to_array(),
+        ]
+    }
+
+    /// Check if the bounding box is an empty/null-type box
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.js_value.is_empty()
+    }
+
+    /// Check if the point is within the bounding box
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn contains(&self, lnglat: impl Into<LngLatLike>) -> Result<bool, super::Error> {
+        Ok(self.js_value.contains(&lnglat.into().as_js_value()?))
+    }
+
+    /// Check if the bounding box intersects with another one
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn intersects(&self, other: impl Into<LngLatBoundsLike>) -> Result<bool, super::Error> {
+        Ok(self.js_value.intersects(&other.into().as_js_value()?))
+    }
+
+    /// Try converting `LngLatBounds` into the equivalent `JsValue`
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn as_js_value(&self) -> Result<JsValue, super::Error> {
+        serde_wasm_bindgen::to_value(self)
+    }
+
+    /// Get the underlying JS object
+    #[must_use]
+    pub fn into_inner(self) -> bindings::LngLatBounds {
+        self.js_value
+    }
+}
+
+impl std::fmt::Display for LngLatBounds {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "LngLatBounds({}, {})",
+            self.get_south_west(),
+            self.get_north_east()
+        )
+    }
+}
+
+impl From<bindings::LngLatBounds> for LngLatBounds {
+    fn from(value: bindings::LngLatBounds) -> Self {
+        Self { js_value: value }
+    }
+}
+
+impl TryFrom<[f64; 4]> for LngLatBounds {
+    type Error = super::Error;
+
+    fn try_from(value: [f64; 4]) -> Result<Self, Self::Error> {
+        Self::new([value[0], value[1]], [value[2], value[3]])
+    }
+}
+
+impl TryFrom<JsValue> for LngLatBounds {
+    type Error = super::Error;
+
+    fn try_from(value: JsValue) -> Result<Self, Self::Error> {
+        serde_wasm_bindgen::from_value(value)
+    }
+}
+
+impl TryFrom<LngLatBounds> for JsValue {
+    type Error = super::Error;
+
+    fn try_from(value: LngLatBounds) -> Result<Self, Self::Error> {
+        value.as_js_value()
+    }
+}
+
+impl TryFrom<&LngLatBounds> for JsValue {
+    type Error = super::Error;
+
+    fn try_from(value: &LngLatBounds) -> Result<Self, Self::Error> {
+        value.as_js_value()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_utils::load_maplibre_gl;
+
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    async fn new() {
+        load_maplibre_gl().await;
+        LngLatBounds::new([-10., -20.], [10., 20.]).expect("Creating LngLatBounds should work");
+    }
+
+    #[wasm_bindgen_test]
+    async fn from_lng_lat() {
+        load_maplibre_gl().await;
+        let bounds =
+            LngLatBounds::from_lng_lat([0., 0.], 1000.).expect("Creating LngLatBounds should work");
+
+        assert!(bounds.get_west() < 0.);
+        assert!(bounds.get_east() > 0.);
+    }
+
+    #[wasm_bindgen_test]
+    async fn to_string() {
+        load_maplibre_gl().await;
+        let bounds =
+            LngLatBounds::new([-10., -20.], [10., 20.]).expect("Creating LngLatBounds should work");
+
+        assert_eq!(bounds.to_string(), bounds.js_value.to_string(),);
+    }
+
+    #[wasm_bindgen_test]
+    async fn to_array() {
+        load_maplibre_gl().await;
+        let bounds: LngLatBounds = [-10., -20., 10., 20.]
+            .try_into()
+            .expect("Creating LngLatBounds should work");
+        let [sw, ne] = bounds.to_array();
+
+        assert!((sw[0] + 10.).abs() < 0.01);
+        assert!((sw[1] + 20.).abs() < 0.01);
+        assert!((ne[0] - 10.).abs() < 0.01);
+        assert!((ne[1] - 20.).abs() < 0.01);
+    }
+
+    #[wasm_bindgen_test]
+    async fn set_corners() {
+        load_maplibre_gl().await;
+        let mut bounds =
+            LngLatBounds::new([-10., -20.], [10., 20.]).expect("Creating LngLatBounds should work");
+        bounds
+            .set_south_west([-1., -2.])
+            .expect("Setting the southwest corner should work");
+        bounds
+            .set_north_east([1., 2.])
+            .expect("Setting the northeast corner should work");
+
+        assert_eq!(bounds.get_south_west().to_string(), "LngLat(-1, -2)");
+        assert_eq!(bounds.get_north_east().to_string(), "LngLat(1, 2)");
+        assert_eq!(bounds.get_center().to_string(), "LngLat(0, 0)");
+    }
+
+    #[wasm_bindgen_test]
+    async fn extend() {
+        load_maplibre_gl().await;
+        let mut bounds =
+            LngLatBounds::new([-10., -20.], [10., 20.]).expect("Creating LngLatBounds should work");
+        bounds.extend([30., 40.]).expect("Extending should work");
+        bounds
+            .extend_bounds([-50., -60., 0., 0.])
+            .expect("Extending with bounds should work");
+
+        assert_eq!(bounds.get_north_east().to_string(), "LngLat(30, 40)");
+        assert_eq!(bounds.get_south_west().to_string(), "LngLat(-50, -60)");
+    }
+
+    #[wasm_bindgen_test]
+    async fn contains_and_intersects() {
+        load_maplibre_gl().await;
+        let bounds =
+            LngLatBounds::new([-10., -20.], [10., 20.]).expect("Creating LngLatBounds should work");
+
+        assert!(bounds.contains([0., 0.]).expect("Checking should work"));
+        assert!(!bounds.contains([30., 0.]).expect("Checking should work"));
+        assert!(
+            bounds
+                .intersects([5., 5., 15., 15.])
+                .expect("Checking should work")
+        );
+        assert!(
+            !bounds
+                .intersects([50., 50., 60., 60.])
+                .expect("Checking should work")
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn serialization() {
+        load_maplibre_gl().await;
+        let bounds =
+            LngLatBounds::new([-10., -20.], [10., 20.]).expect("Creating LngLatBounds should work");
+        let bounds_js = bounds
+            .as_js_value()
+            .expect("Converting to JS Value should work");
+        let retrieved_bounds: LngLatBounds =
+            bounds_js.try_into().expect("Backconverting should work");
+
+        assert_eq!(retrieved_bounds, bounds);
+    }
+}
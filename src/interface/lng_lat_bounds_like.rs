@@ -0,0 +1,159 @@
+//! Rusty interface to the bindings of `LngLatBoundsLike` of MapLibre GL JS
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsValue;
+
+use crate::{
+    bindings,
+    interface::{LngLatBounds, LngLatLike},
+};
+
+/// Different options to represent a `LngLatBounds` without necessarily having one yet
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum LngLatBoundsLike {
+    /// The `LngLatBoundsLike` is an actual `LngLatBounds`
+    LngLatBounds(LngLatBounds),
+    /// The `LngLatBoundsLike` is expressed as a four index array: west, south, east and north
+    Array([f64; 4]),
+    /// The `LngLatBoundsLike` is expressed as its southwest and northeast corners
+    Corners([LngLatLike; 2]),
+}
+
+impl LngLatBoundsLike {
+    /// Try converting `LngLatBoundsLike` into the equivalent bindings type
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn as_js_value(&self) -> Result<bindings::LngLatBoundsLike, super::Error> {
+        Ok(bindings::LngLatBoundsLike::from(
+            serde_wasm_bindgen::to_value(self)?,
+        ))
+    }
+}
+
+impl From<LngLatBounds> for LngLatBoundsLike {
+    fn from(value: LngLatBounds) -> Self {
+        Self::LngLatBounds(value)
+    }
+}
+
+impl From<[f64; 4]> for LngLatBoundsLike {
+    fn from(value: [f64; 4]) -> Self {
+        Self::Array(value)
+    }
+}
+
+impl From<[LngLatLike; 2]> for LngLatBoundsLike {
+    fn from(value: [LngLatLike; 2]) -> Self {
+        Self::Corners(value)
+    }
+}
+
+impl From<[[f64; 2]; 2]> for LngLatBoundsLike {
+    fn from(value: [[f64; 2]; 2]) -> Self {
+        Self::Corners(value.map(Into::into))
+    }
+}
+
+impl TryFrom<bindings::LngLatBoundsLike> for LngLatBoundsLike {
+    type Error = super::Error;
+
+    fn try_from(value: bindings::LngLatBoundsLike) -> Result<Self, Self::Error> {
+        serde_wasm_bindgen::from_value(JsValue::from(value))
+    }
+}
+
+impl TryFrom<LngLatBoundsLike> for bindings::LngLatBoundsLike {
+    type Error = super::Error;
+
+    fn try_from(value: LngLatBoundsLike) -> Result<Self, Self::Error> {
+        value.as_js_value()
+    }
+}
+
+impl TryFrom<&LngLatBoundsLike> for bindings::LngLatBoundsLike {
+    type Error = super::Error;
+
+    fn try_from(value: &LngLatBoundsLike) -> Result<Self, Self::Error> {
+        value.as_js_value()
+    }
+}
+
+impl TryFrom<LngLatBoundsLike> for LngLatBounds {
+    type Error = super::Error;
+
+    fn try_from(value: LngLatBoundsLike) -> Result<Self, Self::Error> {
+        match value {
+            LngLatBoundsLike::LngLatBounds(bounds) => Ok(bounds),
+            LngLatBoundsLike::Array(v) => v.try_into(),
+            LngLatBoundsLike::Corners([sw, ne]) => Self::new(sw, ne),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_utils::load_maplibre_gl;
+
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    async fn lnglatboundslike_bounds_to_js() {
+        load_maplibre_gl().await;
+        let bounds =
+            LngLatBounds::new([-10., -20.], [10., 20.]).expect("Creating LngLatBounds should work");
+        let boundslike = LngLatBoundsLike::LngLatBounds(bounds);
+        let retrieved_boundslike: LngLatBoundsLike = boundslike
+            .as_js_value()
+            .expect("Conversion from LngLatBoundsLike with LngLatBounds should work")
+            .try_into()
+            .expect("Conversion should work");
+
+        assert_eq!(boundslike, retrieved_boundslike);
+    }
+
+    #[wasm_bindgen_test]
+    fn lnglatboundslike_array_to_js() {
+        let boundslike = LngLatBoundsLike::Array([-10., -20., 10., 20.]);
+        let retrieved_boundslike: LngLatBoundsLike = boundslike
+            .as_js_value()
+            .expect("Conversion from LngLatBoundsLike with Array should work")
+            .try_into()
+            .expect("Conversion should work");
+
+        assert_eq!(boundslike, retrieved_boundslike);
+    }
+
+    #[wasm_bindgen_test]
+    fn lnglatboundslike_corners_to_js() {
+        let boundslike: LngLatBoundsLike = [[-10., -20.], [10., 20.]].into();
+        let retrieved_boundslike: LngLatBoundsLike = boundslike
+            .as_js_value()
+            .expect("Conversion from LngLatBoundsLike with Corners should work")
+            .try_into()
+            .expect("Conversion should work");
+
+        assert_eq!(boundslike, retrieved_boundslike);
+    }
+
+    #[wasm_bindgen_test]
+    async fn lnglatboundslike_to_lnglatbounds() {
+        load_maplibre_gl().await;
+        let expected =
+            LngLatBounds::new([-10., -20.], [10., 20.]).expect("Creating LngLatBounds should work");
+
+        for boundslike in [
+            LngLatBoundsLike::Array([-10., -20., 10., 20.]),
+            [[-10., -20.], [10., 20.]].into(),
+            expected.clone().into(),
+        ] {
+            let bounds: LngLatBounds = boundslike
+                .try_into()
+                .expect("Conversion to LngLatBounds should work");
+            assert_eq!(bounds.to_string(), expected.to_string());
+        }
+    }
+}
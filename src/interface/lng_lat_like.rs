@@ -97,7 +97,7 @@ impl From<[f64; 2]> for LngLatLike {
 
 impl From<(f64, f64)> for LngLatLike {
     fn from(value: (f64, f64)) -> Self {
-        Self::Array([value.0, value.1])
+        Self::Array(value.into())
     }
 }
 
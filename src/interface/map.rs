@@ -1,11 +1,55 @@
 //! Rusty interface to the bindings of `Map` of MapLibre GL JS
-use crate::{bindings, interface::MapOptions};
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+use serde::{Serialize, de::DeserializeOwned};
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    bindings,
+    interface::{
+        CameraOptions, FeatureIdentifier, LngLat, LngLatBounds, LngLatBoundsLike, LngLatLike,
+        MapGeoJsonFeature, MapLoad, MapOptions, TransformRequest,
+        camera_options::{
+            AnimationOptions, CameraTransition, EaseToOptions, FitBoundsOptions, FlyToOptions,
+        },
+        controls::{Control, ControlPosition},
+        events::{self, MapEventListener, MapEventType, MapLayerEventType},
+        images::{ImageLoad, MapImage, StyleImageOptions},
+        layers::StyleLayer,
+        map_options::MapZoom,
+        query_options::{QueryGeometry, QueryRenderedFeaturesOptions, QuerySourceFeaturesOptions},
+        sources::Source,
+    },
+};
 
 /// Representation of the map in the page
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, Clone)]
 pub struct Map {
     /// JS object that the struct wraps
     js_value: bindings::Map,
+    /// State kept alive while the map or any of its clones exists
+    state: Rc<MapState>,
+}
+
+/// State of a `Map` shared by its clones
+#[derive(Debug, Default)]
+struct MapState {
+    /// Tracker of the `load` event. `None` when the map was created in JS and wrapped afterwards
+    load_tracker: Option<LoadTracker>,
+    /// Callback given to MapLibre as `transformRequest`, freed when replaced
+    transform_request: RefCell<Option<TransformRequest>>,
+}
+
+/// Records whether a map has fired its `load` event, listening to it since the map was created
+#[derive(Debug)]
+struct LoadTracker {
+    /// Whether the `load` event has been fired
+    fired: Rc<Cell<bool>>,
+    /// Listener of the `load` event
+    _listener: MapEventListener,
 }
 
 impl Map {
@@ -15,8 +59,22 @@ impl Map {
     ///
     /// Propagates errors generated on converting the given options into JS
     pub fn new(options: MapOptions) -> Result<Self, super::Error> {
+        let transform_request = options.transform_request_callback();
         let js_value = bindings::Map::new(options.try_into()?)?;
-        Ok(Self { js_value })
+        let mut map = Self::from(js_value);
+        let fired = Rc::new(Cell::new(false));
+        let listener = map.once(events::Load, {
+            let fired = Rc::clone(&fired);
+            move |_| fired.set(true)
+        });
+        map.state = Rc::new(MapState {
+            load_tracker: Some(LoadTracker {
+                fired,
+                _listener: listener,
+            }),
+            transform_request: RefCell::new(transform_request),
+        });
+        Ok(map)
     }
 
     /// Convert `Map` into the inner bindings value
@@ -29,13 +87,660 @@ impl Map {
         self.js_value
     }
 
+    /// Returns whether the map is fully loaded
+    #[must_use]
+    pub fn loaded(&self) -> bool {
+        self.js_value.loaded()
+    }
+
+    /// Returns whether the map has already fired its `load` event. Unlike `loaded`, it stays
+    /// `true` while tiles are being loaded afterwards. Maps created in JS and wrapped afterwards
+    /// don't track the event, so `loaded` is used instead
+    pub(crate) fn load_fired(&self) -> bool {
+        self.state
+            .load_tracker
+            .as_ref()
+            .map_or_else(|| self.loaded(), |tracker| tracker.fired.get())
+    }
+
+    /// Requests a new frame to be rendered, for example to keep animating a `StyleImage`
+    pub fn trigger_repaint(&self) {
+        self.js_value.trigger_repaint();
+    }
+
+    /// Returns a future which resolves when the map fires its `load` event, or fails with the
+    /// first `error` event fired before it which prevents the map from loading, such as the style
+    /// failing to load. Resolves immediately if the map has already fired its `load` event, even
+    /// if it is still loading tiles
+    ///
+    /// Maps created in JS and wrapped with `Map::from` don't track the `load` event, so they
+    /// resolve immediately only while `Map::loaded` is `true`
+    pub fn wait_loaded(&self) -> MapLoad {
+        MapLoad::new(self)
+    }
+
+    /// Returns the geographical centerpoint of the map
+    #[must_use]
+    pub fn get_center(&self) -> LngLat {
+        self.js_value.get_center().into()
+    }
+
+    /// Sets the geographical centerpoint of the map without an animated transition
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn set_center(&self, center: impl Into<LngLatLike>) -> Result<(), super::Error> {
+        self.js_value.set_center(&center.into().as_js_value()?);
+        Ok(())
+    }
+
+    /// Returns the current zoom level of the map
+    #[must_use]
+    pub fn get_zoom(&self) -> MapZoom {
+        self.js_value.get_zoom().into()
+    }
+
+    /// Sets the zoom level of the map without an animated transition
+    pub fn set_zoom(&self, zoom: impl Into<MapZoom>) {
+        self.js_value.set_zoom(*zoom.into());
+    }
+
+    /// Returns the current bearing of the map in degrees counter-clockwise from north
+    #[must_use]
+    pub fn get_bearing(&self) -> f64 {
+        self.js_value.get_bearing()
+    }
+
+    /// Sets the bearing of the map, in degrees counter-clockwise from north, without an
+    /// animated transition
+    pub fn set_bearing(&self, bearing: impl Into<f64>) {
+        self.js_value.set_bearing(bearing.into());
+    }
+
+    /// Returns the current pitch of the map in degrees away from the plane of the screen
+    #[must_use]
+    pub fn get_pitch(&self) -> f64 {
+        self.js_value.get_pitch()
+    }
+
+    /// Sets the pitch of the map, in degrees away from the plane of the screen, without an
+    /// animated transition
+    pub fn set_pitch(&self, pitch: impl Into<f64>) {
+        self.js_value.set_pitch(pitch.into());
+    }
+
+    /// Returns the current roll of the map in degrees counter-clockwise about the camera
+    /// boresight
+    #[must_use]
+    pub fn get_roll(&self) -> f64 {
+        self.js_value.get_roll()
+    }
+
+    /// Sets the roll of the map, in degrees counter-clockwise about the camera boresight,
+    /// without an animated transition
+    pub fn set_roll(&self, roll: impl Into<f64>) {
+        self.js_value.set_roll(roll.into());
+    }
+
+    /// Returns the elevation of the geographical centerpoint of the map in meters above sea
+    /// level
+    #[must_use]
+    pub fn get_center_elevation(&self) -> f64 {
+        self.js_value.get_center_elevation()
+    }
+
+    /// Sets the elevation of the geographical centerpoint of the map, in meters above sea
+    /// level, without an animated transition
+    pub fn set_center_elevation(&self, elevation: impl Into<f64>) {
+        self.js_value.set_center_elevation(elevation.into());
+    }
+
+    /// Changes any combination of center, zoom, bearing, pitch, roll and elevation without an
+    /// animated transition
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn jump_to(&self, options: &CameraOptions) -> Result<(), super::Error> {
+        self.js_value.jump_to(&options.as_js_value()?);
+        Ok(())
+    }
+
+    /// Returns whether the map is moving due to a camera transition or a user interaction
+    #[must_use]
+    pub fn is_moving(&self) -> bool {
+        self.js_value.is_moving()
+    }
+
+    /// Changes any combination of center, zoom, bearing, pitch, roll and elevation with an
+    /// animated transition between the old and new values.
+    ///
+    /// Any transition underway is stopped and the new one starts immediately. The returned future
+    /// resolves on the `moveend` event fired when it ends or is interrupted. It resolves with an
+    /// error if the given arguments can't be converted into JS
+    pub fn ease_to(&self, options: &EaseToOptions) -> CameraTransition {
+        self.transition(options.as_js_value(), |map, options, event_data| {
+            map.ease_to(&options, event_data);
+        })
+    }
+
+    /// Changes any combination of center, zoom, bearing, pitch, roll and elevation with an
+    /// animated transition that evokes flight.
+    ///
+    /// Any transition underway is stopped and the new one starts immediately. The returned future
+    /// resolves on the `moveend` event fired when it ends or is interrupted. It resolves with an
+    /// error if the given arguments can't be converted into JS
+    pub fn fly_to(&self, options: &FlyToOptions) -> CameraTransition {
+        self.transition(options.as_js_value(), |map, options, event_data| {
+            map.fly_to(&options, event_data);
+        })
+    }
+
+    /// Pans the map to the specified location with an animated transition.
+    ///
+    /// Any transition underway is stopped and the new one starts immediately. The returned future
+    /// resolves on the `moveend` event fired when it ends or is interrupted. It resolves with an
+    /// error if the given arguments can't be converted into JS
+    pub fn pan_to(
+        &self,
+        lnglat: impl Into<LngLatLike>,
+        options: &EaseToOptions,
+    ) -> CameraTransition {
+        let arguments = lnglat
+            .into()
+            .as_js_value()
+            .and_then(|lnglat| Ok((lnglat, options.as_js_value()?)));
+        self.transition(arguments, |map, (lnglat, options), event_data| {
+            map.pan_to(&lnglat, &options, event_data);
+        })
+    }
+
+    /// Zooms the map to the specified zoom level with an animated transition.
+    ///
+    /// Any transition underway is stopped and the new one starts immediately. The returned future
+    /// resolves on the `moveend` event fired when it ends or is interrupted. It resolves with an
+    /// error if the given arguments can't be converted into JS
+    pub fn zoom_to(&self, zoom: impl Into<MapZoom>, options: &EaseToOptions) -> CameraTransition {
+        let zoom = *zoom.into();
+        self.transition(options.as_js_value(), move |map, options, event_data| {
+            map.zoom_to(zoom, &options, event_data);
+        })
+    }
+
+    /// Rotates the map to the specified bearing, in degrees counter-clockwise from north, with an
+    /// animated transition.
+    ///
+    /// Any transition underway is stopped and the new one starts immediately. The returned future
+    /// resolves on the `moveend` event fired when it ends or is interrupted. It resolves with an
+    /// error if the given arguments can't be converted into JS
+    pub fn rotate_to(&self, bearing: impl Into<f64>, options: &EaseToOptions) -> CameraTransition {
+        let bearing = bearing.into();
+        self.transition(options.as_js_value(), move |map, options, event_data| {
+            map.rotate_to(bearing, &options, event_data);
+        })
+    }
+
+    /// Rotates the map so that north is up with an animated transition.
+    ///
+    /// Any transition underway is stopped and the new one starts immediately. The returned future
+    /// resolves on the `moveend` event fired when it ends or is interrupted. It resolves with an
+    /// error if the given arguments can't be converted into JS
+    pub fn reset_north(&self, options: &AnimationOptions) -> CameraTransition {
+        self.transition(options.as_js_value(), |map, options, event_data| {
+            map.reset_north(&options, event_data);
+        })
+    }
+
+    /// Pans and zooms the map to contain its visible area within the specified geographical
+    /// bounds.
+    ///
+    /// Any transition underway is stopped and the new one starts immediately. The returned future
+    /// resolves on the `moveend` event fired when it ends or is interrupted. It resolves with an
+    /// error if the given arguments can't be converted into JS or if the bounds can't fit in the
+    /// viewport, in which case the camera doesn't move
+    pub fn fit_bounds(
+        &self,
+        bounds: impl Into<LngLatBoundsLike>,
+        options: &FitBoundsOptions,
+    ) -> CameraTransition {
+        let arguments = bounds
+            .into()
+            .as_js_value()
+            .and_then(|bounds| Ok((bounds, options.as_js_value()?)))
+            .and_then(|(bounds, options)| {
+                // MapLibre doesn't move nor fire `moveend` when the bounds can't fit
+                match self.js_value.camera_for_bounds(&bounds, &options) {
+                    Some(_) => Ok((bounds, options)),
+                    None => Err(super::Error::new(
+                        "The bounds can't fit in the viewport with the given options",
+                    )),
+                }
+            });
+        self.transition(arguments, |map, (bounds, options), event_data| {
+            map.fit_bounds(&bounds, &options, event_data);
+        })
+    }
+
+    /// Returns the camera position which fits the specified geographical bounds inside the
+    /// viewport, or `None` if the bounds can't fit
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn camera_for_bounds(
+        &self,
+        bounds: impl Into<LngLatBoundsLike>,
+        options: &FitBoundsOptions,
+    ) -> Result<Option<CameraOptions>, super::Error> {
+        self.js_value
+            .camera_for_bounds(&bounds.into().as_js_value()?, &options.as_js_value()?)
+            .map(TryInto::try_into)
+            .transpose()
+    }
+
+    /// Returns the geographical bounds visible in the current map view
+    #[must_use]
+    pub fn get_bounds(&self) -> LngLatBounds {
+        self.js_value.get_bounds().into()
+    }
+
+    /// Returns the maximum geographical bounds the map is constrained to, if any
+    #[must_use]
+    pub fn get_max_bounds(&self) -> Option<LngLatBounds> {
+        self.js_value.get_max_bounds().map(Into::into)
+    }
+
+    /// Constrains the map to the given geographical bounds
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn set_max_bounds(&self, bounds: impl Into<LngLatBoundsLike>) -> Result<(), super::Error> {
+        self.js_value
+            .set_max_bounds(Some(bounds.into().as_js_value()?));
+        Ok(())
+    }
+
+    /// Removes the geographical bounds constraint of the map, if any
+    pub fn clear_max_bounds(&self) {
+        self.js_value.set_max_bounds(None);
+    }
+
+    /// Adds a source with the given identifier to the style of the map
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors and errors generated from JS, such as
+    /// the style not being loaded yet or the identifier being already in use
+    pub fn add_source(&self, id: &str, source: &impl Serialize) -> Result<(), super::Error> {
+        let source = source.serialize(&serde_wasm_bindgen::Serializer::json_compatible())?;
+        self.js_value.add_source(id, &source)?;
+        Ok(())
+    }
+
+    /// Removes the source with the given identifier from the style of the map
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors generated from JS
+    pub fn remove_source(&self, id: &str) -> Result<(), super::Error> {
+        self.js_value.remove_source(id)?;
+        Ok(())
+    }
+
+    /// Returns the source with the given identifier of the style of the map, if any
+    #[must_use]
+    pub fn get_source(&self, id: &str) -> Option<Source> {
+        self.js_value.get_source(id).map(Into::into)
+    }
+
+    /// Returns whether the source with the given identifier is loaded
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors generated from JS, such as the source not existing
+    pub fn is_source_loaded(&self, id: &str) -> Result<bool, super::Error> {
+        Ok(self.js_value.is_source_loaded(id)?)
+    }
+
+    /// Adds a layer to the style of the map. It is placed before the layer with identifier
+    /// `before_id` if specified, or on top of all the other layers otherwise
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors and errors generated from JS, such as
+    /// the style not being loaded yet or the identifier being already in use
+    pub fn add_layer(
+        &self,
+        layer: &impl Serialize,
+        before_id: Option<&str>,
+    ) -> Result<(), super::Error> {
+        let layer = layer.serialize(&serde_wasm_bindgen::Serializer::json_compatible())?;
+        self.js_value.add_layer(&layer, before_id)?;
+        Ok(())
+    }
+
+    /// Removes the layer with the given identifier from the style of the map
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors generated from JS
+    pub fn remove_layer(&self, id: &str) -> Result<(), super::Error> {
+        self.js_value.remove_layer(id)?;
+        Ok(())
+    }
+
+    /// Moves the layer with the given identifier before the layer with identifier `before_id` if
+    /// specified, or on top of all the other layers otherwise
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors generated from JS
+    pub fn move_layer(&self, id: &str, before_id: Option<&str>) -> Result<(), super::Error> {
+        self.js_value.move_layer(id, before_id)?;
+        Ok(())
+    }
+
+    /// Returns the layer with the given identifier of the style of the map, if any
+    #[must_use]
+    pub fn get_layer(&self, id: &str) -> Option<StyleLayer> {
+        self.js_value.get_layer(id).map(Into::into)
+    }
+
+    /// Returns the identifiers of the layers of the style of the map in their rendering order
+    #[must_use]
+    pub fn get_layers_order(&self) -> Vec<String> {
+        self.js_value.get_layers_order()
+    }
+
+    /// Sets the state of the given feature, merging the keys of `state` with its current state.
+    /// The state can be used by the expressions of the style layers with `feature-state`
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors and errors generated from JS, such as
+    /// the source not existing or the source layer being missing for a vector source
+    pub fn set_feature_state(
+        &self,
+        feature: &FeatureIdentifier,
+        state: &impl Serialize,
+    ) -> Result<(), super::Error> {
+        let state = state.serialize(&serde_wasm_bindgen::Serializer::json_compatible())?;
+        self.js_value
+            .set_feature_state(&feature.as_js_value()?, &state)?;
+        Ok(())
+    }
+
+    /// Returns the state of the given feature
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors, such as the state not matching `T`,
+    /// and errors generated from JS
+    pub fn get_feature_state<T: DeserializeOwned>(
+        &self,
+        feature: &FeatureIdentifier,
+    ) -> Result<T, super::Error> {
+        serde_wasm_bindgen::from_value(self.js_value.get_feature_state(&feature.as_js_value()?)?)
+    }
+
+    /// Removes the state of the given feature, or only the given key of it if specified
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors and errors generated from JS
+    pub fn remove_feature_state(
+        &self,
+        feature: &FeatureIdentifier,
+        key: Option<&str>,
+    ) -> Result<(), super::Error> {
+        self.js_value
+            .remove_feature_state(&feature.as_js_value()?, key)?;
+        Ok(())
+    }
+
+    /// Replaces the callback run before the map requests a resource, for example to refresh an
+    /// authentication token. Only affects the requests made from then on.
+    ///
+    /// The callback is kept alive while the map or any of its clones exists, and the previous one
+    /// is freed
+    pub fn set_transform_request(&self, transform_request: impl Into<TransformRequest>) {
+        let transform_request = transform_request.into();
+        self.js_value
+            .set_transform_request(transform_request.as_function());
+        self.state
+            .transform_request
+            .replace(Some(transform_request));
+    }
+
+    /// Returns the features rendered in the given point or box of the screen, or in the whole
+    /// viewport with `QueryGeometry::Viewport`, from top to bottom in rendering order. The
+    /// properties of the features are deserialized into `P`
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors, such as the properties not matching
+    /// `P`, and errors generated from JS. Layers in the options that don't exist are reported
+    /// through an error event of the map instead, returning no features
+    pub fn query_rendered_features<P: DeserializeOwned>(
+        &self,
+        geometry: impl Into<QueryGeometry>,
+        options: &QueryRenderedFeaturesOptions,
+    ) -> Result<Vec<MapGeoJsonFeature<P>>, super::Error> {
+        let features = self
+            .js_value
+            .query_rendered_features(&geometry.into().as_js_value()?, &options.as_js_value()?)?;
+        serde_wasm_bindgen::from_value(features)
+    }
+
+    /// Returns the features of the source with the given identifier in the loaded tiles,
+    /// whether they are rendered or not. The properties of the features are deserialized into
+    /// `P`
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors, such as the properties not matching
+    /// `P`, and errors generated from JS
+    pub fn query_source_features<P: DeserializeOwned>(
+        &self,
+        source_id: &str,
+        options: &QuerySourceFeaturesOptions,
+    ) -> Result<Vec<MapGeoJsonFeature<P>>, super::Error> {
+        let features = self
+            .js_value
+            .query_source_features(source_id, &options.as_js_value()?)?;
+        serde_wasm_bindgen::from_value(features)
+    }
+
+    /// Adds an image to the style of the map with the given identifier, to be used by the
+    /// `icon-image` and pattern properties of the layers
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors and errors generated from JS. An
+    /// existing image with the same identifier or an image with an invalid size are reported
+    /// with an `error` event instead
+    pub fn add_image(
+        &self,
+        id: &str,
+        image: impl Into<MapImage>,
+        options: &StyleImageOptions,
+    ) -> Result<(), super::Error> {
+        self.js_value
+            .add_image(id, &image.into().as_js_value()?, &options.as_js_value()?)?;
+        Ok(())
+    }
+
+    /// Replaces the pixels of the image with the given identifier, which must have the same
+    /// size as the new image
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors and errors generated from JS. A missing
+    /// image or a different size are reported with an `error` event instead
+    pub fn update_image(&self, id: &str, image: impl Into<MapImage>) -> Result<(), super::Error> {
+        self.js_value
+            .update_image(id, &image.into().as_js_value()?)?;
+        Ok(())
+    }
+
+    /// Returns whether an image with the given identifier exists in the style of the map
+    #[must_use]
+    pub fn has_image(&self, id: &str) -> bool {
+        self.js_value.has_image(id)
+    }
+
+    /// Removes the image with the given identifier from the style of the map
+    pub fn remove_image(&self, id: &str) {
+        self.js_value.remove_image(id);
+    }
+
+    /// Returns the identifiers of the images of the style of the map, including the ones of the
+    /// sprite
+    #[must_use]
+    pub fn list_images(&self) -> Vec<String> {
+        self.js_value.list_images()
+    }
+
+    /// Fetches and decodes the image at the given URL, applying the `transformRequest` of the
+    /// map. The image can then be added with `Map::add_image`
+    pub fn load_image(&self, url: &str) -> ImageLoad {
+        ImageLoad::new(self.js_value.load_image(url))
+    }
+
+    /// Adds a listener for events of the given type fired by the map.
+    ///
+    /// The listener is removed when the returned handle is dropped
+    pub fn on<E: MapEventType>(
+        &self,
+        _event: E,
+        mut listener: impl FnMut(E::Event) + 'static,
+    ) -> MapEventListener {
+        let closure = Closure::<dyn FnMut(JsValue)>::new(move |event: JsValue| {
+            listener(event.into());
+        });
+        self.js_value.on(E::NAME, closure.as_ref().unchecked_ref());
+        MapEventListener::new(self.js_value.clone(), E::NAME, None, closure)
+    }
+
+    /// Adds a listener that will be called only the next time an event of the given type is
+    /// fired by the map.
+    ///
+    /// The listener is removed when the returned handle is dropped, even if it hasn't been called
+    pub fn once<E: MapEventType>(
+        &self,
+        _event: E,
+        listener: impl FnOnce(E::Event) + 'static,
+    ) -> MapEventListener {
+        let closure = Closure::<dyn FnMut(JsValue)>::once(move |event: JsValue| {
+            listener(event.into());
+        });
+        self.js_value
+            .once(E::NAME, closure.as_ref().unchecked_ref());
+        MapEventListener::new(self.js_value.clone(), E::NAME, None, closure)
+    }
+
+    /// Adds a listener for events of the given type occurring on the features of the style layer
+    /// with the given identifier.
+    ///
+    /// The listener is removed when the returned handle is dropped
+    pub fn on_layer<E: MapLayerEventType>(
+        &self,
+        _event: E,
+        layer_id: impl Into<String>,
+        mut listener: impl FnMut(E::Event) + 'static,
+    ) -> MapEventListener {
+        let layer_id = layer_id.into();
+        let closure = Closure::<dyn FnMut(JsValue)>::new(move |event: JsValue| {
+            listener(event.into());
+        });
+        self.js_value
+            .on_layer(E::NAME, &layer_id, closure.as_ref().unchecked_ref());
+        MapEventListener::new(self.js_value.clone(), E::NAME, Some(layer_id), closure)
+    }
+
+    /// Adds a control to the map at the given position, or at the default position of the control
+    /// when `None` is given
+    pub fn add_control(&self, control: &impl Control, position: Option<ControlPosition>) {
+        self.js_value.add_control(
+            control.as_control(),
+            position.map(|position| position.as_str().to_string()),
+        );
+    }
+
+    /// Removes a control from the map
+    pub fn remove_control(&self, control: &impl Control) {
+        self.js_value.remove_control(control.as_control());
+    }
+
+    /// Returns whether the control has been added to the map
+    #[must_use]
+    pub fn has_control(&self, control: &impl Control) -> bool {
+        self.js_value.has_control(control.as_control())
+    }
+
+    /// Start a camera transition with the already converted `arguments` and return a future
+    /// which resolves on the `moveend` event fired by it. Any transition underway is stopped
+    /// first, and the listener is registered before starting the new one, so transitions which
+    /// end synchronously are not missed. The events are tagged with the given event data to
+    /// ignore the ones fired by other movements
+    fn transition<T>(
+        &self,
+        arguments: Result<T, super::Error>,
+        start: impl FnOnce(&bindings::Map, T, &JsValue),
+    ) -> CameraTransition {
+        match arguments {
+            Ok(arguments) => {
+                self.js_value.stop();
+                let (transition, event_data) = CameraTransition::listen(&self.js_value);
+                start(&self.js_value, arguments, &event_data);
+                transition
+            }
+            Err(error) => CameraTransition::failed(error),
+        }
+    }
+
     // TODO: support all methods
 }
 
+impl PartialEq for Map {
+    fn eq(&self, other: &Self) -> bool {
+        self.js_value == other.js_value
+    }
+}
+
+impl From<bindings::Map> for Map {
+    fn from(value: bindings::Map) -> Self {
+        Self {
+            js_value: value,
+            state: Rc::default(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::test_utils::{gen_html_element, load_maplibre_gl};
+    use crate::{
+        interface::{
+            MapLoadError, add_protocol,
+            controls::{
+                NavigationControl, NavigationControlOptions, ScaleControl, ScaleControlOptions,
+                ScaleUnit,
+            },
+            events,
+            layers::{BackgroundLayerSpecification, BackgroundPaint, LayerSpecification},
+            style::Color,
+        },
+        test_utils::{gen_html_element, gen_sized_container, load_maplibre_gl},
+    };
+    use serde_json::json;
+    use std::{
+        cell::{Cell, RefCell},
+        rc::Rc,
+    };
+    use wasm_bindgen_futures::JsFuture;
     use wasm_bindgen_test::*;
 
     #[wasm_bindgen_test]
@@ -69,4 +774,774 @@ mod test {
             .build()
             .expect("Creating a map should work");
     }
+
+    #[wasm_bindgen_test]
+    async fn map_set_center() {
+        load_maplibre_gl().await;
+        let html_element = gen_html_element("div").cloned_ref();
+        let map = MapOptions::new(html_element)
+            .build()
+            .expect("Creating a map should work");
+        map.set_center([12.23, 14.42])
+            .expect("Setting the center should work");
+        let center = map.get_center();
+
+        assert!((center.lng() - 12.23).abs() < 0.01);
+        assert!((center.lat() - 14.42).abs() < 0.01);
+    }
+
+    #[wasm_bindgen_test]
+    async fn map_set_zoom() {
+        load_maplibre_gl().await;
+        let html_element = gen_html_element("div").cloned_ref();
+        let map = MapOptions::new(html_element)
+            .build()
+            .expect("Creating a map should work");
+        map.set_zoom(4.5);
+
+        assert!((*map.get_zoom() - 4.5).abs() < 0.01);
+    }
+
+    #[wasm_bindgen_test]
+    async fn map_set_bearing_pitch_roll() {
+        load_maplibre_gl().await;
+        let html_element = gen_html_element("div").cloned_ref();
+        let map = MapOptions::new(html_element)
+            .build()
+            .expect("Creating a map should work");
+        map.set_bearing(30);
+        map.set_pitch(20);
+        map.set_roll(10);
+
+        assert!((map.get_bearing() - 30.).abs() < 0.01);
+        assert!((map.get_pitch() - 20.).abs() < 0.01);
+        assert!((map.get_roll() - 10.).abs() < 0.01);
+    }
+
+    #[wasm_bindgen_test]
+    async fn map_set_center_elevation() {
+        load_maplibre_gl().await;
+        let html_element = gen_html_element("div").cloned_ref();
+        let map = MapOptions::new(html_element)
+            .build()
+            .expect("Creating a map should work");
+        map.set_center_elevation(100);
+
+        assert!((map.get_center_elevation() - 100.).abs() < 0.01);
+    }
+
+    #[wasm_bindgen_test]
+    async fn map_jump_to() {
+        load_maplibre_gl().await;
+        let html_element = gen_html_element("div").cloned_ref();
+        let map = MapOptions::new(html_element)
+            .build()
+            .expect("Creating a map should work");
+        map.jump_to(
+            &CameraOptions::new()
+                .with_center([1.1, 2.2])
+                .with_zoom(3.)
+                .with_bearing(15),
+        )
+        .expect("Jumping should work");
+        let center = map.get_center();
+
+        assert!((center.lng() - 1.1).abs() < 0.01);
+        assert!((center.lat() - 2.2).abs() < 0.01);
+        assert!((*map.get_zoom() - 3.).abs() < 0.01);
+        assert!((map.get_bearing() - 15.).abs() < 0.01);
+    }
+
+    #[wasm_bindgen_test]
+    async fn map_ease_to() {
+        load_maplibre_gl().await;
+        let html_element = gen_html_element("div").cloned_ref();
+        let map = MapOptions::new(html_element)
+            .build()
+            .expect("Creating a map should work");
+        map.ease_to(
+            &EaseToOptions::new()
+                .with_camera(CameraOptions::new().with_zoom(4.).with_pitch(10))
+                .with_animation(AnimationOptions::new().with_duration(50).with_essential()),
+        )
+        .await
+        .expect("Easing should work");
+
+        assert!((*map.get_zoom() - 4.).abs() < 0.01);
+        assert!((map.get_pitch() - 10.).abs() < 0.01);
+    }
+
+    #[wasm_bindgen_test]
+    async fn map_chained_transitions() {
+        load_maplibre_gl().await;
+        let html_element = gen_html_element("div").cloned_ref();
+        let map = MapOptions::new(html_element)
+            .build()
+            .expect("Creating a map should work");
+        let first = map.ease_to(
+            &EaseToOptions::new()
+                .with_camera(CameraOptions::new().with_zoom(6.))
+                .with_animation(
+                    AnimationOptions::new()
+                        .with_duration(10_000)
+                        .with_essential(),
+                ),
+        );
+        let second = map.ease_to(
+            &EaseToOptions::new()
+                .with_camera(CameraOptions::new().with_zoom(2.).with_bearing(20))
+                .with_animation(AnimationOptions::new().with_duration(50).with_essential()),
+        );
+        first
+            .await
+            .expect("The interrupted transition should resolve");
+        map.js_value.fire("moveend", &JsValue::UNDEFINED);
+
+        assert!(map.is_moving());
+
+        second.await.expect("Easing should work");
+
+        assert!(!map.is_moving());
+        assert!((*map.get_zoom() - 2.).abs() < 0.01);
+        assert!((map.get_bearing() - 20.).abs() < 0.01);
+    }
+
+    #[wasm_bindgen_test]
+    async fn map_fly_to() {
+        load_maplibre_gl().await;
+        let html_element = gen_html_element("div").cloned_ref();
+        let map = MapOptions::new(html_element)
+            .build()
+            .expect("Creating a map should work");
+        map.fly_to(
+            &FlyToOptions::new()
+                .with_camera(CameraOptions::new().with_center([10., 20.]).with_zoom(3.))
+                .with_animation(AnimationOptions::new().with_duration(50).with_essential()),
+        )
+        .await
+        .expect("Flying should work");
+        let center = map.get_center();
+
+        assert!((center.lng() - 10.).abs() < 0.01);
+        assert!((center.lat() - 20.).abs() < 0.01);
+        assert!((*map.get_zoom() - 3.).abs() < 0.01);
+    }
+
+    #[wasm_bindgen_test]
+    async fn map_pan_zoom_rotate_to() {
+        load_maplibre_gl().await;
+        let html_element = gen_html_element("div").cloned_ref();
+        let map = MapOptions::new(html_element)
+            .build()
+            .expect("Creating a map should work");
+        let options =
+            EaseToOptions::new().with_animation(AnimationOptions::new().without_animation());
+        map.pan_to([5., 6.], &options)
+            .await
+            .expect("Panning should work");
+        map.zoom_to(2.5, &options)
+            .await
+            .expect("Zooming should work");
+        map.rotate_to(45, &options)
+            .await
+            .expect("Rotating should work");
+        let center = map.get_center();
+
+        assert!((center.lng() - 5.).abs() < 0.01);
+        assert!((center.lat() - 6.).abs() < 0.01);
+        assert!((*map.get_zoom() - 2.5).abs() < 0.01);
+        assert!((map.get_bearing() - 45.).abs() < 0.01);
+    }
+
+    #[wasm_bindgen_test]
+    async fn map_reset_north() {
+        load_maplibre_gl().await;
+        let html_element = gen_html_element("div").cloned_ref();
+        let map = MapOptions::new(html_element)
+            .with_bearing(40)
+            .build()
+            .expect("Creating a map should work");
+        map.reset_north(&AnimationOptions::new().without_animation())
+            .await
+            .expect("Resetting north should work");
+
+        assert!(map.get_bearing().abs() < 0.01);
+    }
+
+    #[wasm_bindgen_test]
+    async fn map_fit_bounds() {
+        load_maplibre_gl().await;
+        let html_element = gen_html_element("div").cloned_ref();
+        let map = MapOptions::new(html_element)
+            .build()
+            .expect("Creating a map should work");
+        map.fit_bounds(
+            [-10., -20., 10., 20.],
+            &FitBoundsOptions::new().with_linear().with_fly_to(
+                FlyToOptions::new().with_animation(AnimationOptions::new().without_animation()),
+            ),
+        )
+        .await
+        .expect("Fitting bounds should work");
+        let center = map.get_center();
+
+        assert!(center.lng().abs() < 0.01);
+        assert!(center.lat().abs() < 0.01);
+    }
+
+    #[wasm_bindgen_test]
+    async fn map_fit_bounds_not_fitting() {
+        load_maplibre_gl().await;
+        let html_element = gen_html_element("div").cloned_ref();
+        let map = MapOptions::new(html_element)
+            .build()
+            .expect("Creating a map should work");
+        let center = map.get_center();
+        let options = FitBoundsOptions::new().with_fly_to(FlyToOptions::new().with_padding(1e6));
+
+        assert!(
+            map.camera_for_bounds([-10., -20., 10., 20.], &options)
+                .expect("Conversion should work")
+                .is_none()
+        );
+        map.fit_bounds([-10., -20., 10., 20.], &options)
+            .await
+            .expect_err("Fitting bounds which can't fit should fail");
+
+        assert!((map.get_center().lng() - center.lng()).abs() < 0.01);
+        assert!((map.get_center().lat() - center.lat()).abs() < 0.01);
+    }
+
+    #[wasm_bindgen_test]
+    async fn map_camera_for_bounds() {
+        load_maplibre_gl().await;
+        let html_element = gen_html_element("div").cloned_ref();
+        let map = MapOptions::new(html_element)
+            .build()
+            .expect("Creating a map should work");
+        let camera = map
+            .camera_for_bounds([[-10., -20.], [10., 20.]], &FitBoundsOptions::new())
+            .expect("Conversion should work");
+
+        let camera = camera.expect("The bounds should fit in the viewport");
+        let center = camera.center().expect("The camera should have a center");
+
+        assert!(center.lng().abs() < 0.01);
+        assert!(center.lat().abs() < 0.01);
+    }
+
+    #[wasm_bindgen_test]
+    async fn map_max_bounds() {
+        load_maplibre_gl().await;
+        let html_element = gen_html_element("div").cloned_ref();
+        let map = MapOptions::new(html_element)
+            .build()
+            .expect("Creating a map should work");
+        assert!(map.get_max_bounds().is_none());
+
+        map.set_max_bounds([-10., -20., 10., 20.])
+            .expect("Setting max bounds should work");
+        let max_bounds = map
+            .get_max_bounds()
+            .expect("Max bounds should have been set");
+        assert!((max_bounds.get_west() + 10.).abs() < 0.01);
+        assert!((max_bounds.get_north() - 20.).abs() < 0.01);
+        assert!(map.get_bounds().get_west() >= -10.01);
+
+        map.clear_max_bounds();
+        assert!(map.get_max_bounds().is_none());
+    }
+
+    #[wasm_bindgen_test]
+    async fn map_on() {
+        load_maplibre_gl().await;
+        let html_element = gen_html_element("div").cloned_ref();
+        let map = MapOptions::new(html_element)
+            .build()
+            .expect("Creating a map should work");
+        let event_types = Rc::new(RefCell::new(Vec::new()));
+        let listener = {
+            let event_types = event_types.clone();
+            map.on(events::Zoom, move |event| {
+                event_types.borrow_mut().push(event.event_type());
+            })
+        };
+        map.set_zoom(2.);
+        map.set_zoom(3.);
+        drop(listener);
+        map.set_zoom(4.);
+
+        assert_eq!(*event_types.borrow(), vec!["zoom", "zoom"]);
+    }
+
+    #[wasm_bindgen_test]
+    async fn map_once() {
+        load_maplibre_gl().await;
+        let html_element = gen_html_element("div").cloned_ref();
+        let map = MapOptions::new(html_element)
+            .build()
+            .expect("Creating a map should work");
+        let count = Rc::new(Cell::new(0));
+        let _listener = {
+            let count = count.clone();
+            map.once(events::MoveEnd, move |_| count.set(count.get() + 1))
+        };
+        map.set_zoom(2.);
+        map.set_zoom(3.);
+
+        assert_eq!(count.get(), 1);
+    }
+
+    #[wasm_bindgen_test]
+    async fn map_once_dropped_before_firing() {
+        load_maplibre_gl().await;
+        let html_element = gen_html_element("div").cloned_ref();
+        let map = MapOptions::new(html_element)
+            .build()
+            .expect("Creating a map should work");
+        let count = Rc::new(Cell::new(0));
+        let listener = {
+            let count = count.clone();
+            map.once(events::MoveEnd, move |_| count.set(count.get() + 1))
+        };
+        drop(listener);
+        map.set_zoom(2.);
+
+        assert_eq!(count.get(), 0);
+    }
+
+    #[wasm_bindgen_test]
+    async fn map_on_layer() {
+        load_maplibre_gl().await;
+        let container = gen_sized_container(400, 300);
+        let map = MapOptions::new(container.cloned_ref())
+            .with_style(json!({"version": 8, "sources": {}, "layers": []}))
+            .build_async()
+            .await
+            .expect("Creating a map should work");
+        map.add_source(
+            "points",
+            &json!({"type": "geojson", "data": {"type": "FeatureCollection", "features": [{
+                "type": "Feature",
+                "geometry": {"type": "Point", "coordinates": [0, 0]},
+                "properties": {},
+            }]}}),
+        )
+        .expect("Adding a source should work");
+        map.add_layer(
+            &json!({"id": "points", "type": "circle", "source": "points",
+                    "paint": {"circle-radius": 10}}),
+            None,
+        )
+        .expect("Adding a layer should work");
+        JsFuture::from(map.js_value.once_promise("idle"))
+            .await
+            .expect("The map should become idle");
+        let serializer = serde_wasm_bindgen::Serializer::json_compatible();
+        let on_feature = json!({"point": [200, 150]})
+            .serialize(&serializer)
+            .expect("Conversion should work");
+        let off_feature = json!({"point": [10, 10]})
+            .serialize(&serializer)
+            .expect("Conversion should work");
+        let clicks = Rc::new(Cell::new(0));
+        let listener = {
+            let clicks = clicks.clone();
+            map.on_layer(events::Click, "points", move |_| {
+                clicks.set(clicks.get() + 1);
+            })
+        };
+
+        assert_eq!(listener.event_type(), "click");
+        assert_eq!(listener.layer_id(), Some("points"));
+
+        map.js_value.fire("click", &off_feature);
+        assert_eq!(clicks.get(), 0);
+
+        map.js_value.fire("click", &on_feature);
+        assert_eq!(clicks.get(), 1);
+
+        drop(listener);
+        map.js_value.fire("click", &on_feature);
+        assert_eq!(clicks.get(), 1);
+    }
+
+    #[wasm_bindgen_test]
+    async fn map_wait_loaded() {
+        load_maplibre_gl().await;
+        let html_element = gen_html_element("div").cloned_ref();
+        let map = MapOptions::new(html_element)
+            .with_style(json!({"version": 8, "sources": {}, "layers": []}))
+            .build()
+            .expect("Creating a map should work");
+        map.wait_loaded().await.expect("Loading should work");
+
+        assert!(map.loaded());
+        map.wait_loaded()
+            .await
+            .expect("Waiting on a loaded map should work");
+        Map::from(map.clone().into_inner())
+            .wait_loaded()
+            .await
+            .expect("Waiting on a loaded map wrapped from JS should work");
+    }
+
+    #[wasm_bindgen_test]
+    async fn map_wait_loaded_error() {
+        load_maplibre_gl().await;
+        let html_element = gen_html_element("div").cloned_ref();
+        let map = MapOptions::new(html_element)
+            .with_style("http://localhost:1/missing-style.json")
+            .build()
+            .expect("Creating a map should work");
+        let error = map
+            .wait_loaded()
+            .await
+            .expect_err("Loading a missing style should fail");
+
+        assert!(matches!(error, MapLoadError::Map(_)));
+    }
+
+    #[wasm_bindgen_test]
+    async fn map_wait_loaded_while_loading_tiles() {
+        load_maplibre_gl().await;
+        let container = gen_sized_container(400, 300);
+        let map = MapOptions::new(container.cloned_ref())
+            .with_style(json!({
+                "version": 8,
+                "sources": {"broken": {
+                    "type": "raster",
+                    "tiles": ["http://localhost:1/{z}/{x}/{y}.png"],
+                }},
+                "layers": [{"id": "broken", "type": "raster", "source": "broken"}],
+            }))
+            .build_async()
+            .await
+            .expect("Failing tiles shouldn't prevent the map from loading");
+        map.add_source(
+            "points",
+            &json!({"type": "geojson", "data": {"type": "FeatureCollection", "features": []}}),
+        )
+        .expect("Adding a source should work");
+
+        assert!(!map.loaded());
+        map.wait_loaded()
+            .await
+            .expect("Waiting on a map which already fired load should work");
+    }
+
+    #[wasm_bindgen_test]
+    async fn map_add_remove_source() {
+        load_maplibre_gl().await;
+        let html_element = gen_html_element("div").cloned_ref();
+        let map = MapOptions::new(html_element)
+            .with_style(json!({"version": 8, "sources": {}, "layers": []}))
+            .build_async()
+            .await
+            .expect("Creating a map should work");
+        map.add_source(
+            "points",
+            &json!({"type": "geojson", "data": {"type": "FeatureCollection", "features": []}}),
+        )
+        .expect("Adding a source should work");
+        let source = map.get_source("points").expect("The source should exist");
+
+        assert_eq!(source.id(), "points");
+        assert_eq!(source.source_type(), "geojson");
+        assert!(map.is_source_loaded("points").is_ok());
+        assert!(
+            map.add_source("points", &json!({"type": "geojson", "data": null}))
+                .is_err()
+        );
+
+        map.remove_source("points")
+            .expect("Removing a source should work");
+        assert!(map.get_source("points").is_none());
+        assert!(map.is_source_loaded("points").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    async fn map_add_move_remove_layer() {
+        load_maplibre_gl().await;
+        let html_element = gen_html_element("div").cloned_ref();
+        let map = MapOptions::new(html_element)
+            .with_style(json!({"version": 8, "sources": {}, "layers": []}))
+            .build_async()
+            .await
+            .expect("Creating a map should work");
+        map.add_layer(
+            &json!({"id": "first", "type": "background", "minzoom": 2}),
+            None,
+        )
+        .expect("Adding a layer should work");
+        map.add_layer(
+            &json!({"id": "second", "type": "background"}),
+            Some("first"),
+        )
+        .expect("Adding a layer before another should work");
+        assert_eq!(map.get_layers_order(), vec!["second", "first"]);
+
+        map.move_layer("second", None)
+            .expect("Moving a layer should work");
+        assert_eq!(map.get_layers_order(), vec!["first", "second"]);
+
+        let layer = map.get_layer("first").expect("The layer should exist");
+        let specification: serde_json::Value = layer
+            .specification()
+            .expect("Retrieving the specification should work");
+        assert_eq!(layer.id(), "first");
+        assert_eq!(layer.layer_type(), "background");
+        assert_eq!(layer.minzoom(), Some(2.));
+        assert_eq!(layer.maxzoom(), None);
+        assert_eq!(specification["id"], "first");
+
+        map.remove_layer("first")
+            .expect("Removing a layer should work");
+        assert!(map.get_layer("first").is_none());
+        assert_eq!(map.get_layers_order(), vec!["second"]);
+    }
+
+    #[wasm_bindgen_test]
+    async fn map_add_typed_layer() {
+        load_maplibre_gl().await;
+        let html_element = gen_html_element("div").cloned_ref();
+        let map = MapOptions::new(html_element)
+            .with_style(json!({"version": 8, "sources": {}, "layers": []}))
+            .build_async()
+            .await
+            .expect("Creating a map should work");
+        let layer: LayerSpecification = BackgroundLayerSpecification::new("background")
+            .with_paint(BackgroundPaint {
+                background_color: Some(Color::from("#123456").into()),
+                ..Default::default()
+            })
+            .into();
+        map.add_layer(&layer, None)
+            .expect("Adding a typed layer should work");
+        let retrieved: LayerSpecification = map
+            .get_layer("background")
+            .expect("The layer should exist")
+            .specification()
+            .expect("Retrieving the specification should work");
+
+        assert_eq!(retrieved, layer);
+    }
+
+    #[wasm_bindgen_test]
+    async fn map_controls() {
+        load_maplibre_gl().await;
+        let html_element = gen_html_element("div").cloned_ref();
+        let map = MapOptions::new(html_element)
+            .build()
+            .expect("Creating a map should work");
+        let navigation = NavigationControl::new(&NavigationControlOptions::new().without_compass())
+            .expect("Creating a navigation control should work");
+        let scale = ScaleControl::new(&ScaleControlOptions::new().with_unit(ScaleUnit::Nautical))
+            .expect("Creating a scale control should work");
+
+        map.add_control(&navigation, Some(ControlPosition::TopLeft));
+        map.add_control(&scale, None);
+        scale.set_unit(ScaleUnit::Metric);
+
+        assert!(map.has_control(&navigation));
+        assert!(map.has_control(&scale));
+
+        map.remove_control(&navigation);
+
+        assert!(!map.has_control(&navigation));
+        assert!(map.has_control(&scale));
+    }
+
+    #[wasm_bindgen_test]
+    async fn map_feature_state() {
+        #[derive(Debug, PartialEq, Default, Serialize, serde::Deserialize)]
+        #[serde(default)]
+        struct HighlightState {
+            hover: bool,
+            selected: bool,
+        }
+
+        load_maplibre_gl().await;
+        let html_element = gen_html_element("div").cloned_ref();
+        let map = MapOptions::new(html_element)
+            .with_style(json!({"version": 8, "sources": {}, "layers": []}))
+            .build_async()
+            .await
+            .expect("Creating a map should work");
+        map.add_source(
+            "points",
+            &json!({"type": "geojson", "data": {"type": "FeatureCollection", "features": [{
+                "type": "Feature",
+                "id": 1,
+                "geometry": {"type": "Point", "coordinates": [0, 0]},
+                "properties": {},
+            }]}}),
+        )
+        .expect("Adding a source should work");
+        let feature = FeatureIdentifier::new("points", 1);
+
+        map.set_feature_state(
+            &feature,
+            &HighlightState {
+                hover: true,
+                selected: true,
+            },
+        )
+        .expect("Setting the state should work");
+        map.remove_feature_state(&feature, Some("selected"))
+            .expect("Removing a key of the state should work");
+        let state: HighlightState = map
+            .get_feature_state(&feature)
+            .expect("Getting the state should work");
+        assert_eq!(
+            state,
+            HighlightState {
+                hover: true,
+                selected: false
+            }
+        );
+
+        map.remove_feature_state(&feature, None)
+            .expect("Removing the state should work");
+        let state: HighlightState = map
+            .get_feature_state(&feature)
+            .expect("Getting the state should work");
+        assert_eq!(state, HighlightState::default());
+    }
+
+    #[wasm_bindgen_test]
+    async fn map_query_features() {
+        #[derive(Debug, PartialEq, serde::Deserialize)]
+        struct Parcel {
+            owner: String,
+        }
+
+        load_maplibre_gl().await;
+        let container = gen_sized_container(400, 300);
+        let map = MapOptions::new(container.cloned_ref())
+            .with_style(json!({"version": 8, "sources": {}, "layers": []}))
+            .build_async()
+            .await
+            .expect("Creating a map should work");
+        map.add_source(
+            "parcels",
+            &json!({"type": "geojson", "data": {"type": "FeatureCollection", "features": [{
+                "type": "Feature",
+                "id": 1,
+                "geometry": {"type": "Point", "coordinates": [0, 0]},
+                "properties": {"owner": "Ana"},
+            }]}}),
+        )
+        .expect("Adding a source should work");
+        map.add_layer(
+            &json!({"id": "parcels", "type": "circle", "source": "parcels"}),
+            None,
+        )
+        .expect("Adding a layer should work");
+        JsFuture::from(map.js_value.once_promise("idle"))
+            .await
+            .expect("The map should become idle");
+
+        let features: Vec<MapGeoJsonFeature<Parcel>> = map
+            .query_source_features("parcels", &QuerySourceFeaturesOptions::new())
+            .expect("Querying the source should work");
+        assert_eq!(features.len(), 1);
+        assert_eq!(features[0].properties.owner, "Ana");
+        assert_eq!(features[0].source, "parcels");
+
+        let rendered: Vec<MapGeoJsonFeature<Parcel>> = map
+            .query_rendered_features(
+                QueryGeometry::Viewport,
+                &QueryRenderedFeaturesOptions::new().with_layers(["parcels"]),
+            )
+            .expect("Querying the rendered features should work");
+        assert_ne!(rendered.len(), 0);
+        assert!(
+            rendered
+                .iter()
+                .all(|feature| feature.properties.owner == "Ana")
+        );
+
+        let error_messages = Rc::new(RefCell::new(Vec::new()));
+        let _listener = {
+            let error_messages = error_messages.clone();
+            map.on(events::Error, move |event| {
+                error_messages.borrow_mut().push(event.message());
+            })
+        };
+        let missing: Vec<MapGeoJsonFeature<Parcel>> = map
+            .query_rendered_features(
+                [0., 0.],
+                &QueryRenderedFeaturesOptions::new().with_layers(["missing"]),
+            )
+            .expect("Querying a missing layer should not throw");
+        assert_eq!(missing.len(), 0);
+        assert_eq!(error_messages.borrow().len(), 1);
+        assert!(error_messages.borrow()[0].contains("missing"));
+    }
+
+    #[wasm_bindgen_test]
+    async fn map_add_update_remove_image() {
+        load_maplibre_gl().await;
+        let html_element = gen_html_element("div").cloned_ref();
+        let map = MapOptions::new(html_element)
+            .with_style(json!({"version": 8, "sources": {}, "layers": []}))
+            .build_async()
+            .await
+            .expect("Creating a map should work");
+        let red = MapImage::from_rgba(2, 2, [255, 0, 0, 255].repeat(4)).expect("Valid size");
+        let blue = MapImage::from_rgba(2, 2, [0, 0, 255, 255].repeat(4)).expect("Valid size");
+
+        map.add_image(
+            "dot",
+            red,
+            &StyleImageOptions::new().with_pixel_ratio(2).with_sdf(),
+        )
+        .expect("Adding an image should work");
+        assert!(map.has_image("dot"));
+        assert!(map.list_images().contains(&"dot".to_string()));
+
+        map.update_image("dot", blue)
+            .expect("Updating an image should work");
+
+        map.remove_image("dot");
+        assert!(!map.has_image("dot"));
+        assert!(map.load_image("missing://image.png").await.is_err());
+    }
+
+    #[wasm_bindgen_test]
+    async fn map_load_image() {
+        /// PNG image of a single grey pixel
+        const PIXEL_PNG: [u8; 68] = [
+            0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x48,
+            0x44, 0x52, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x08, 0x04, 0x00, 0x00,
+            0x00, 0xb5, 0x1c, 0x0c, 0x02, 0x00, 0x00, 0x00, 0x0b, 0x49, 0x44, 0x41, 0x54, 0x78,
+            0xda, 0x63, 0x64, 0x60, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x30, 0x81, 0xd0, 0x2f,
+            0x00, 0x00, 0x00, 0x00, 0x49, 0x45, 0x4e, 0x44, 0xae, 0x42, 0x60, 0x82,
+        ];
+
+        load_maplibre_gl().await;
+        let _protocol = add_protocol("rust-image", |_| async { Ok(PIXEL_PNG.to_vec()) });
+        let html_element = gen_html_element("div").cloned_ref();
+        let map = MapOptions::new(html_element)
+            .with_style(json!({"version": 8, "sources": {}, "layers": []}))
+            .build_async()
+            .await
+            .expect("Creating a map should work");
+
+        let image = map
+            .load_image("rust-image://pixel.png")
+            .await
+            .expect("Loading the image should work");
+        let size = match &image {
+            MapImage::Element(element) => (element.width(), element.height()),
+            MapImage::Bitmap(bitmap) => (bitmap.width(), bitmap.height()),
+            other => panic!("Expected a decoded image, got {other:?}"),
+        };
+        assert_eq!(size, (1, 1));
+
+        map.add_image("pixel", image, &StyleImageOptions::new())
+            .expect("Adding the loaded image should work");
+        assert!(map.has_image("pixel"));
+    }
 }
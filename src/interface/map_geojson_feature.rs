@@ -0,0 +1,65 @@
+//! Rusty interface to the `MapGeoJSONFeature` returned by the feature queries of MapLibre GL JS
+
+use serde::{Deserialize, Serialize};
+
+use crate::interface::sources::GeoJSONFeatureId;
+
+/// Feature of a source of the style of a `Map`, as returned by `Map::query_rendered_features`
+/// and `Map::query_source_features`. The properties are deserialized into `P`.
+///
+/// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/type-aliases/MapGeoJSONFeature/>
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MapGeoJsonFeature<P = serde_json::Value> {
+    /// Identifier of the feature, if it has one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<GeoJSONFeatureId>,
+    /// GeoJSON geometry of the feature
+    pub geometry: serde_json::Value,
+    /// Properties of the feature
+    pub properties: P,
+    /// Identifier of the source of the feature
+    pub source: String,
+    /// Layer of the vector source containing the feature
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_layer: Option<String>,
+    /// Specification of the style layer in which the feature was rendered. Only present in the
+    /// results of `Map::query_rendered_features`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub layer: Option<serde_json::Value>,
+    /// State of the feature, as set with `Map::set_feature_state`
+    #[serde(default)]
+    pub state: serde_json::Value,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn map_geojson_feature_deserialization() {
+        #[derive(Debug, PartialEq, Eq, Deserialize)]
+        struct Parcel {
+            owner: String,
+        }
+
+        let feature: MapGeoJsonFeature<Parcel> = serde_json::from_value(json!({
+            "type": "Feature",
+            "id": 7,
+            "geometry": {"type": "Point", "coordinates": [0, 0]},
+            "properties": {"owner": "Ana"},
+            "source": "parcels",
+            "sourceLayer": "cadastre",
+            "state": {"hover": true},
+        }))
+        .expect("Deserializing should work");
+
+        assert_eq!(feature.id, Some(GeoJSONFeatureId::Number(7)));
+        assert_eq!(feature.properties.owner, "Ana");
+        assert_eq!(feature.source_layer.as_deref(), Some("cadastre"));
+        assert_eq!(feature.layer, None);
+        assert_eq!(feature.state, json!({"hover": true}));
+    }
+}
@@ -0,0 +1,108 @@
+//! Submodule for the `MapLoad` future which waits for a `Map` to finish loading
+
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::js_sys::Promise;
+
+use super::{
+    Error, Map,
+    events::{self, ErrorEvent, MapEventListener},
+};
+
+/// Error raised while waiting for a `Map` to load
+#[derive(Debug)]
+pub enum MapLoadError {
+    /// The options of the map couldn't be converted into JS
+    Conversion(Error),
+    /// The map fired an `error` event before finishing loading, for example because the style
+    /// or the sprite couldn't be fetched
+    Map(ErrorEvent),
+}
+
+impl std::fmt::Display for MapLoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Conversion(error) => write!(f, "Failed to create the map: {error}"),
+            Self::Map(event) => write!(f, "Failed to load the map: {event}"),
+        }
+    }
+}
+
+impl std::error::Error for MapLoadError {}
+
+impl From<Error> for MapLoadError {
+    fn from(value: Error) -> Self {
+        Self::Conversion(value)
+    }
+}
+
+impl From<ErrorEvent> for MapLoadError {
+    fn from(value: ErrorEvent) -> Self {
+        Self::Map(value)
+    }
+}
+
+/// Future which resolves when a `Map` fires its `load` event, or fails with the first `error`
+/// event fired before it which prevents the map from loading.
+///
+/// Errors raised by a source, such as a tile failing to load, are ignored, since the map still
+/// loads. The listeners are registered as soon as the future is created, so it is not necessary to
+/// poll it for the events to be caught. They are removed once the future completes or is dropped
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct MapLoad {
+    /// Promise settled by the `load` and `error` listeners
+    loaded: JsFuture,
+    /// Listeners of the `load` and `error` events, if still registered
+    listeners: Vec<MapEventListener>,
+}
+
+impl MapLoad {
+    /// Create a `MapLoad` for the given map. Resolves immediately if it has already fired its
+    /// `load` event
+    pub(crate) fn new(map: &Map) -> Self {
+        let mut listeners = Vec::new();
+        let promise = Promise::new(&mut |resolve, reject| {
+            listeners.push(map.once(events::Load, move |_| {
+                let _ = resolve.call0(&JsValue::UNDEFINED);
+            }));
+            listeners.push(map.on(events::Error, move |event| {
+                if event.source_id().is_none() {
+                    let _ = reject.call1(&JsValue::UNDEFINED, &event.into_inner());
+                }
+            }));
+        });
+
+        // The listeners are registered before checking, so that a `load` event can't be missed
+        if map.load_fired() {
+            return Self {
+                loaded: JsFuture::from(Promise::resolve(&JsValue::UNDEFINED)),
+                listeners: Vec::new(),
+            };
+        }
+
+        Self {
+            loaded: JsFuture::from(promise),
+            listeners,
+        }
+    }
+}
+
+impl Future for MapLoad {
+    type Output = Result<(), MapLoadError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let result = std::task::ready!(Pin::new(&mut self.loaded).poll(cx));
+        self.listeners.clear();
+        Poll::Ready(
+            result
+                .map(|_| ())
+                .map_err(|event| ErrorEvent::from(event).into()),
+        )
+    }
+}
@@ -0,0 +1,112 @@
+//! Submodule for the `AttributionControlOption` field of `MapOptions` and associated tests
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use wasm_bindgen::JsValue;
+
+use crate::interface::controls::AttributionControlOptions;
+
+/// Attribution control added by the map. It may be disabled or configured with
+/// `AttributionControlOptions`
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum AttributionControlOption {
+    /// No attribution control is added to the map
+    Disabled,
+    /// An attribution control with the given options is added to the map
+    Enabled(AttributionControlOptions),
+}
+
+impl AttributionControlOption {
+    /// Try converting `AttributionControlOption` into the equivalent `JsValue`
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn as_js_value(&self) -> Result<JsValue, super::Error> {
+        self.serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+    }
+}
+
+impl Serialize for AttributionControlOption {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Disabled => serializer.serialize_bool(false),
+            Self::Enabled(options) => options.serialize(serializer),
+        }
+    }
+}
+
+// MapLibre accepts `false` to disable the control and the options to configure it, so the
+// variant is chosen depending on whether the value is a boolean
+impl<'de> Deserialize<'de> for AttributionControlOption {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Value {
+            Flag(bool),
+            Options(AttributionControlOptions),
+        }
+
+        Ok(match Value::deserialize(deserializer)? {
+            Value::Flag(false) => Self::Disabled,
+            Value::Flag(true) => Self::Enabled(AttributionControlOptions::default()),
+            Value::Options(options) => Self::Enabled(options),
+        })
+    }
+}
+
+impl From<AttributionControlOptions> for AttributionControlOption {
+    fn from(value: AttributionControlOptions) -> Self {
+        Self::Enabled(value)
+    }
+}
+
+impl TryFrom<JsValue> for AttributionControlOption {
+    type Error = super::Error;
+
+    fn try_from(value: JsValue) -> Result<Self, Self::Error> {
+        serde_wasm_bindgen::from_value(value)
+    }
+}
+
+impl TryFrom<AttributionControlOption> for JsValue {
+    type Error = super::Error;
+
+    fn try_from(value: AttributionControlOption) -> Result<Self, Self::Error> {
+        value.as_js_value()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn attribution_control_option_conversion() {
+        let disabled_js = AttributionControlOption::Disabled
+            .as_js_value()
+            .expect("Should be able to convert to JsValue");
+        let enabled = AttributionControlOption::from(
+            AttributionControlOptions::new()
+                .with_compact()
+                .with_custom_attribution(vec!["A", "B"]),
+        );
+        let enabled_retrieved = AttributionControlOption::try_from(
+            enabled
+                .as_js_value()
+                .expect("Should be able to convert to JsValue"),
+        )
+        .expect("Should be able to back convert the options");
+
+        assert_eq!(disabled_js.as_bool(), Some(false));
+        assert_eq!(
+            AttributionControlOption::try_from(disabled_js)
+                .expect("Should be able to back convert the disabled control"),
+            AttributionControlOption::Disabled
+        );
+        assert_eq!(enabled_retrieved, enabled);
+    }
+}
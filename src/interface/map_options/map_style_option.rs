@@ -3,8 +3,10 @@
 use serde::{Deserialize, Serialize};
 use wasm_bindgen::JsValue;
 
+use crate::interface::style::{StyleSpecification, StyleValidationError, validate_style};
+
 /// The map style
-#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 #[non_exhaustive]
 pub enum MapStyleOption {
@@ -12,7 +14,9 @@ pub enum MapStyleOption {
     URL(String),
     /// JSON representing a non-validated MapLibre `StyleSpecification`
     JsonStyleSpecification(serde_json::Value),
-    // TODO: Support validated style spec
+    /// Strongly typed MapLibre `StyleSpecification`. Since any JSON can be deserialized into
+    /// `JsonStyleSpecification`, deserializing a `MapStyleOption` never yields this variant
+    StyleSpecification(Box<StyleSpecification>),
 }
 
 impl MapStyleOption {
@@ -24,6 +28,20 @@ impl MapStyleOption {
     pub fn as_js_value(&self) -> Result<JsValue, super::Error> {
         self.serialize(&serde_wasm_bindgen::Serializer::json_compatible())
     }
+
+    /// Validate the style against the MapLibre Style Specification without needing a browser,
+    /// returning every error found. A `URL` is not fetched, so it never has errors
+    #[must_use]
+    pub fn validate(&self) -> Vec<StyleValidationError> {
+        match self {
+            Self::URL(_) => Vec::new(),
+            Self::JsonStyleSpecification(style) => validate_style(style),
+            Self::StyleSpecification(style) => serde_json::to_value(style).map_or_else(
+                |error| vec![StyleValidationError::new("", error.to_string())],
+                |style| validate_style(&style),
+            ),
+        }
+    }
 }
 
 impl From<&str> for MapStyleOption {
@@ -44,6 +62,12 @@ impl From<serde_json::Value> for MapStyleOption {
     }
 }
 
+impl From<StyleSpecification> for MapStyleOption {
+    fn from(value: StyleSpecification) -> Self {
+        Self::StyleSpecification(Box::new(value))
+    }
+}
+
 impl TryFrom<JsValue> for MapStyleOption {
     type Error = super::Error;
 
@@ -73,6 +97,7 @@ mod test {
     use crate::test_utils::{get_key_list_from_object, get_value_from_object};
 
     use super::*;
+    use crate::interface::layers::BackgroundLayerSpecification;
     use serde_json::json;
     use wasm_bindgen_test::*;
     use web_sys::js_sys::Array;
@@ -135,4 +160,45 @@ mod test {
         assert_eq!(sources_keys, vec!["satellite"]);
         assert_eq!(Array::from(&layers).to_vec().len(), 1);
     }
+
+    #[wasm_bindgen_test]
+    fn map_style_typed_spec_conversion() {
+        let style =
+            StyleSpecification::new().with_layer(BackgroundLayerSpecification::new("background"));
+        let map_style: MapStyleOption = style.clone().into();
+        let map_style_js = map_style
+            .as_js_value()
+            .expect("Should be able to convert to JsValue");
+        let map_style_retrieved = MapStyleOption::try_from(map_style_js.clone())
+            .expect("Should be able to back convert map style");
+        let style_retrieved = StyleSpecification::try_from(map_style_js)
+            .expect("Should be able to back convert the style specification");
+
+        assert_eq!(
+            map_style_retrieved,
+            MapStyleOption::JsonStyleSpecification(
+                serde_json::to_value(&style).expect("Serializing should work")
+            )
+        );
+        assert_eq!(style_retrieved, style);
+    }
+
+    #[test]
+    fn map_style_validation() {
+        let valid: MapStyleOption = StyleSpecification::new()
+            .with_layer(BackgroundLayerSpecification::new("background"))
+            .into();
+        let invalid: MapStyleOption = json!({
+            "version": 8,
+            "sources": {},
+            "layers": [{"id": "roads", "type": "line", "source": "osm"}]
+        })
+        .into();
+        let errors = invalid.validate();
+
+        assert_eq!(valid.validate(), vec![]);
+        assert_eq!(MapStyleOption::from("url").validate(), vec![]);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].path(), "layers[0].source");
+    }
 }
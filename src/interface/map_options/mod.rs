@@ -1,9 +1,11 @@
 //! Rusty interface to the bindings of `MapOptions` and additional type checks of MapLibre GL JS
 
+mod attribution_control_option;
 mod map_container;
 mod map_style_option;
 mod map_zoom;
 
+pub use attribution_control_option::AttributionControlOption;
 pub use map_container::MapContainer;
 pub use map_style_option::MapStyleOption;
 pub use map_zoom::MapZoom;
@@ -13,7 +15,10 @@ use serde::Serialize;
 use super::Error;
 use crate::{
     bindings,
-    interface::{LngLatLike, Map},
+    interface::{
+        LngLatBoundsLike, LngLatLike, Map, MapLoadError, TransformRequest,
+        camera_options::FitBoundsOptions,
+    },
 };
 
 /// Generate `MapOptions` struct with builder functions
@@ -65,6 +70,20 @@ macro_rules! declare_map_options {
                 Map::new(self)
             }
 
+            /// Create a `Map` with the specified options and wait until it fires its `load`
+            /// event
+            ///
+            /// # Errors
+            ///
+            /// Propagates errors generated on converting the given options into JS and the first
+            /// `error` event which prevents the map from loading, such as the style failing to
+            /// load. Errors raised by the sources, such as tiles failing to load, are ignored
+            pub async fn build_async(self) -> Result<Map, MapLoadError> {
+                let map = self.build()?;
+                map.wait_loaded().await?;
+                Ok(map)
+            }
+
             /// Try converting `MapOptions` into the equivalent bindings type
             ///
             /// # Errors
@@ -96,6 +115,20 @@ macro_rules! declare_map_options {
             }
         }
     };
+    (@impl set_type_or_disabled, $name:ident, $inner_type:ty, $implement_docs:expr) => {
+        declare_map_options!(@impl set_type, $name, $inner_type, $implement_docs);
+
+        paste::paste! {
+            #[doc = "Disable the option, removing the element it adds to the map"]
+            #[must_use]
+            pub fn [<without_ $name>](self) -> Self {
+                Self {
+                    $name: Some(<$inner_type>::Disabled),
+                    ..self
+                }
+            }
+        }
+    };
     (@impl set_true, $name:ident, $inner_type:ty, $implement_docs:expr) => {
         paste::paste! {
             #[doc = $implement_docs]
@@ -126,10 +159,11 @@ declare_map_options!(
     {
         name: attribution_control,
         serde_rename: "attributionControl",
-        inner_type: (), // TODO: create type
-        type_docs: "Attribution control configuration",
-        implement: nothing,
-        implement_docs: "Specify the initial bearing of the map",
+        inner_type: AttributionControlOption,
+        type_docs: "Attribution control added to the map, or `false` to disable it. Defaults to \
+                    an attribution control with the default options",
+        implement: set_type_or_disabled,
+        implement_docs: "Specify the options of the attribution control of the map",
     },
     {
         name: bearing,
@@ -151,9 +185,9 @@ declare_map_options!(
     {
         name: bounds,
         serde_rename: "bounds",
-        inner_type: (), // TODO: create type
+        inner_type: LngLatBoundsLike,
         type_docs: "Initial bounds of the map. Overrides `center` and `zoom`",
-        implement: nothing,
+        implement: set_type,
         implement_docs: "Specify the initial bounds of the map. Overrides `center` and `zoom`",
     },
     {
@@ -297,9 +331,9 @@ declare_map_options!(
     {
         name: fit_bounds_options,
         serde_rename: "fitBoundsOptions",
-        inner_type: (), // TODO: create type
+        inner_type: FitBoundsOptions,
         type_docs: "Additional options to use when specifying `bounds`",
-        implement: nothing,
+        implement: set_type,
         implement_docs: "Set additional options to use when specifying `bounds`",
     },
     {
@@ -364,10 +398,10 @@ declare_map_options!(
     {
         name: max_bounds,
         serde_rename: "maxBounds",
-        inner_type: (), // TODO: create type
-        type_docs: "Whether to constrain the map bounds and with which",
-        implement: nothing,
-        implement_docs: "Set map bounds",
+        inner_type: LngLatBoundsLike,
+        type_docs: "Bounds to constrain the map to. Defaults to unconstrained",
+        implement: set_type,
+        implement_docs: "Constrain the map to the given bounds",
     },
     {
         name: max_canvas_size,
@@ -561,10 +595,11 @@ declare_map_options!(
     {
         name: transform_request,
         serde_rename: "transformRequest",
-        inner_type: (), // TODO: create type
+        inner_type: TransformRequest,
         type_docs: "Callback run before the Map makes a request for an external URL",
-        implement: nothing,
-        implement_docs: "",
+        implement: set_type,
+        implement_docs: "Specify the callback run before the map requests a resource, returning \
+                         the parameters of the request to make instead",
     },
     {
         name: validate_style,
@@ -584,6 +619,13 @@ declare_map_options!(
     },
 );
 
+impl MapOptions {
+    /// Get the `transformRequest` callback, which the `Map` must keep alive
+    pub(crate) fn transform_request_callback(&self) -> Option<TransformRequest> {
+        self.transform_request.clone()
+    }
+}
+
 impl TryFrom<MapOptions> for bindings::MapOptions {
     type Error = super::Error;
 
@@ -602,9 +644,12 @@ impl TryFrom<&MapOptions> for bindings::MapOptions {
 
 #[cfg(test)]
 mod test {
-    use crate::test_utils::{gen_html_element, get_key_list_from_object, get_value_from_object};
+    use crate::test_utils::{
+        gen_html_element, get_key_list_from_object, get_value_from_object, load_maplibre_gl,
+    };
 
     use super::*;
+    use crate::interface::controls::AttributionControlOptions;
     use serde_json::json;
     use wasm_bindgen_test::*;
     use web_sys::js_sys::{Array, Boolean};
@@ -640,6 +685,39 @@ mod test {
         assert_eq!(map_rust.container, retreived_container_rs);
     }
 
+    #[wasm_bindgen_test]
+    fn map_with_attribution_control() {
+        let map_rust = MapOptions::new("identifier_of_map").with_attribution_control(
+            AttributionControlOptions::new()
+                .with_compact()
+                .with_custom_attribution("© Contributors"),
+        );
+        let map_js = map_rust
+            .as_js_value()
+            .expect("Conversion from MapContainer with identifier to JS should work");
+        let retreived_rs: AttributionControlOption =
+            get_value_from_object(&map_js, "attributionControl")
+                .try_into()
+                .expect("Back conversion from JS with identifier should work");
+        let keys = get_key_list_from_object(&map_js);
+
+        assert_eq!(keys.len(), 2);
+        assert_eq!(map_rust.attribution_control.unwrap(), retreived_rs);
+    }
+
+    #[wasm_bindgen_test]
+    fn map_without_attribution_control() {
+        let map_rust = MapOptions::new("identifier_of_map").without_attribution_control();
+        let map_js = map_rust
+            .as_js_value()
+            .expect("Conversion from MapContainer with identifier to JS should work");
+        let retreived_rs: Boolean = get_value_from_object(&map_js, "attributionControl").into();
+        let keys = get_key_list_from_object(&map_js);
+
+        assert_eq!(keys.len(), 2);
+        assert_eq!(retreived_rs, false);
+    }
+
     #[wasm_bindgen_test]
     fn map_with_bearing() {
         let map_rust = MapOptions::new("identifier_of_map").with_bearing(140);
@@ -670,6 +748,22 @@ mod test {
         assert!(map_rust.bearing_snap.unwrap() - retreived_rs < 0.001);
     }
 
+    #[wasm_bindgen_test]
+    fn map_with_bounds() {
+        let map_rust = MapOptions::new("identifier_of_map").with_bounds([-10., -20., 10., 20.]);
+        let map_js = map_rust
+            .as_js_value()
+            .expect("Conversion from MapContainer with identifier to JS should work");
+        let retreived_rs: LngLatBoundsLike =
+            bindings::LngLatBoundsLike::from(get_value_from_object(&map_js, "bounds"))
+                .try_into()
+                .expect("Back conversion from JS with identifier should work");
+        let keys = get_key_list_from_object(&map_js);
+
+        assert_eq!(keys.len(), 2);
+        assert_eq!(map_rust.bounds.unwrap(), retreived_rs);
+    }
+
     #[wasm_bindgen_test]
     fn map_without_box_zoom_interaction() {
         let map_rust = MapOptions::new("identifier_of_map").without_box_zoom_interaction();
@@ -854,6 +948,22 @@ mod test {
         assert!(map_rust.fade_duration.unwrap() - retreived_rs < 0.001);
     }
 
+    #[wasm_bindgen_test]
+    fn map_with_fit_bounds_options() {
+        let map_rust = MapOptions::new("identifier_of_map")
+            .with_fit_bounds_options(FitBoundsOptions::new().with_linear().with_max_zoom(5.));
+        let map_js = map_rust
+            .as_js_value()
+            .expect("Conversion from MapContainer with identifier to JS should work");
+        let fit_bounds_options_js = get_value_from_object(&map_js, "fitBoundsOptions");
+        let mut fit_bounds_keys = get_key_list_from_object(&fit_bounds_options_js);
+        fit_bounds_keys.sort();
+        let keys = get_key_list_from_object(&map_js);
+
+        assert_eq!(keys.len(), 2);
+        assert_eq!(fit_bounds_keys, vec!["linear", "maxZoom"]);
+    }
+
     #[wasm_bindgen_test]
     fn map_without_interactivity() {
         let map_rust = MapOptions::new("identifier_of_map").without_interactivity();
@@ -893,6 +1003,23 @@ mod test {
         assert_eq!(retreived_rs, true);
     }
 
+    #[wasm_bindgen_test]
+    fn map_with_max_bounds() {
+        let map_rust =
+            MapOptions::new("identifier_of_map").with_max_bounds([[-10., -20.], [10., 20.]]);
+        let map_js = map_rust
+            .as_js_value()
+            .expect("Conversion from MapContainer with identifier to JS should work");
+        let retreived_rs: LngLatBoundsLike =
+            bindings::LngLatBoundsLike::from(get_value_from_object(&map_js, "maxBounds"))
+                .try_into()
+                .expect("Back conversion from JS with identifier should work");
+        let keys = get_key_list_from_object(&map_js);
+
+        assert_eq!(keys.len(), 2);
+        assert_eq!(map_rust.max_bounds.unwrap(), retreived_rs);
+    }
+
     #[wasm_bindgen_test]
     #[allow(clippy::cast_possible_truncation)]
     #[allow(clippy::cast_sign_loss)]
@@ -1200,4 +1327,17 @@ mod test {
         assert_eq!(keys.len(), 2);
         assert_eq!(map_rust.zoom.unwrap(), retreived_zoom_rs);
     }
+
+    #[wasm_bindgen_test]
+    async fn map_build_async() {
+        load_maplibre_gl().await;
+        let html_element = gen_html_element("div").cloned_ref();
+        let map = MapOptions::new(html_element)
+            .with_style(json!({"version": 8, "sources": {}, "layers": []}))
+            .build_async()
+            .await
+            .expect("Creating and loading a map should work");
+
+        assert!(map.loaded());
+    }
 }
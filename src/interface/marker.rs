@@ -0,0 +1,287 @@
+//! Rusty interface to the bindings of `Marker` of MapLibre GL JS
+
+use wasm_bindgen::prelude::*;
+use web_sys::HtmlElement;
+
+use super::Error;
+use crate::{
+    bindings,
+    interface::{
+        LngLat, LngLatLike, Map, MarkerOptions, Point, PointLike, Popup,
+        events::{EventListener, MarkerEventType},
+    },
+};
+
+/// HTML element placed on the map at a given longitude and latitude
+///
+/// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Marker/>
+#[derive(Debug, PartialEq, Clone)]
+pub struct Marker {
+    /// JS object that the struct wraps
+    js_value: bindings::Marker,
+}
+
+impl Marker {
+    /// Create a `Marker` with the specified options. It has to be placed with `set_lng_lat` and
+    /// attached with `add_to` to be shown
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors generated on converting the given options into JS and the ones thrown by
+    /// MapLibre on creating the marker
+    pub fn new(options: &MarkerOptions) -> Result<Self, Error> {
+        let js_value = bindings::Marker::new(&options.as_js_value()?)?;
+        Ok(Self { js_value })
+    }
+
+    /// Convert `Marker` into the inner bindings value
+    #[must_use]
+    pub fn into_inner(self) -> bindings::Marker {
+        self.js_value
+    }
+
+    /// Attaches the marker to the given map
+    pub fn add_to(&self, map: &Map) {
+        self.js_value.add_to(&map.clone().into_inner());
+    }
+
+    /// Removes the marker from the map it is attached to
+    pub fn remove(&self) {
+        self.js_value.remove();
+    }
+
+    /// Returns the geographical location of the marker
+    #[must_use]
+    pub fn get_lng_lat(&self) -> LngLat {
+        self.js_value.get_lng_lat().into()
+    }
+
+    /// Sets the geographical location of the marker
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn set_lng_lat(&self, lng_lat: impl Into<LngLatLike>) -> Result<(), Error> {
+        self.js_value.set_lng_lat(&lng_lat.into().as_js_value()?);
+        Ok(())
+    }
+
+    /// Returns the HTML element of the marker
+    #[must_use]
+    pub fn get_element(&self) -> HtmlElement {
+        self.js_value.get_element()
+    }
+
+    /// Returns the offset in pixels of the element relative to its center
+    #[must_use]
+    pub fn get_offset(&self) -> Point {
+        self.js_value.get_offset().into()
+    }
+
+    /// Sets the offset in pixels of the element relative to its center
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn set_offset(&self, offset: impl Into<PointLike>) -> Result<(), Error> {
+        self.js_value.set_offset(&offset.into().as_js_value()?);
+        Ok(())
+    }
+
+    /// Returns whether the marker can be dragged
+    #[must_use]
+    pub fn is_draggable(&self) -> bool {
+        self.js_value.is_draggable()
+    }
+
+    /// Sets whether the marker can be dragged
+    pub fn set_draggable(&self, draggable: bool) {
+        self.js_value.set_draggable(draggable);
+    }
+
+    /// Returns the rotation of the marker in degrees
+    #[must_use]
+    pub fn get_rotation(&self) -> f64 {
+        self.js_value.get_rotation()
+    }
+
+    /// Sets the rotation of the marker in degrees
+    pub fn set_rotation(&self, rotation: impl Into<f64>) {
+        self.js_value.set_rotation(rotation.into());
+    }
+
+    /// Sets the opacity of the marker when it is visible and when it is covered by the terrain.
+    /// `None` keeps the current value
+    pub fn set_opacity(&self, opacity: Option<f64>, opacity_when_covered: Option<f64>) {
+        self.js_value.set_opacity(opacity, opacity_when_covered);
+    }
+
+    /// Adds a CSS class to the element of the marker
+    pub fn add_class_name(&self, class_name: &str) {
+        self.js_value.add_class_name(class_name);
+    }
+
+    /// Removes a CSS class from the element of the marker
+    pub fn remove_class_name(&self, class_name: &str) {
+        self.js_value.remove_class_name(class_name);
+    }
+
+    /// Toggles a CSS class of the element of the marker, returning whether the class is now
+    /// present
+    #[must_use]
+    pub fn toggle_class_name(&self, class_name: &str) -> bool {
+        self.js_value.toggle_class_name(class_name)
+    }
+
+    /// Binds a popup to the marker, opened when the marker is clicked, or unbinds the current one
+    /// when `None` is given
+    pub fn set_popup(&self, popup: Option<&Popup>) {
+        self.js_value
+            .set_popup(popup.cloned().map(Popup::into_inner));
+    }
+
+    /// Returns the popup bound to the marker, if any
+    #[must_use]
+    pub fn get_popup(&self) -> Option<Popup> {
+        self.js_value.get_popup().map(Into::into)
+    }
+
+    /// Opens or closes the popup bound to the marker
+    pub fn toggle_popup(&self) {
+        self.js_value.toggle_popup();
+    }
+
+    /// Adds a listener for events of the given type fired by the marker.
+    ///
+    /// The listener is removed when the returned handle is dropped
+    pub fn on<E: MarkerEventType>(
+        &self,
+        _event: E,
+        mut listener: impl FnMut(E::Event) + 'static,
+    ) -> EventListener {
+        let closure = Closure::<dyn FnMut(JsValue)>::new(move |event: JsValue| {
+            listener(event.into());
+        });
+        self.js_value.on(E::NAME, closure.as_ref().unchecked_ref());
+        EventListener::new(self.js_value.clone().into(), E::NAME, closure)
+    }
+}
+
+impl From<bindings::Marker> for Marker {
+    fn from(value: bindings::Marker) -> Self {
+        Self { js_value: value }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        interface::{MapOptions, events},
+        test_utils::{gen_html_element, gen_sized_container, load_maplibre_gl},
+    };
+    use std::{cell::RefCell, rc::Rc};
+    use wasm_bindgen_test::*;
+    use web_sys::{MouseEvent, MouseEventInit};
+
+    /// Dispatch a bubbling mouse event of the given type on the element at the given client
+    /// coordinates
+    fn dispatch_mouse_event(element: &HtmlElement, event_type: &str, x: i32, y: i32) {
+        let init = MouseEventInit::new();
+        init.set_bubbles(true);
+        init.set_cancelable(true);
+        init.set_client_x(x);
+        init.set_client_y(y);
+        let event = MouseEvent::new_with_mouse_event_init_dict(event_type, &init)
+            .expect("Creating a mouse event should work");
+        element
+            .dispatch_event(&event)
+            .expect("Dispatching the event should work");
+    }
+
+    /// Drag the marker with the given element by the given amount of pixels, as a pointer would
+    #[allow(clippy::cast_possible_truncation)]
+    fn drag_element(element: &HtmlElement, dx: i32, dy: i32) {
+        let rect = element.get_bounding_client_rect();
+        let x = (rect.left() + rect.width() / 2.) as i32;
+        let y = (rect.top() + rect.height() / 2.) as i32;
+        dispatch_mouse_event(element, "mousedown", x, y);
+        dispatch_mouse_event(element, "mousemove", x + dx, y + dy);
+        dispatch_mouse_event(element, "mouseup", x + dx, y + dy);
+    }
+
+    #[wasm_bindgen_test]
+    async fn marker_add_to_map() {
+        load_maplibre_gl().await;
+        let container = gen_sized_container(400, 300);
+        let map = MapOptions::new(container.cloned_ref())
+            .with_center([12.5, 41.9])
+            .with_zoom(4.)
+            .build()
+            .expect("Creating a map should work");
+        let marker = MarkerOptions::new()
+            .with_color("#ff0000")
+            .with_draggable()
+            .with_rotation(45)
+            .build()
+            .expect("Creating a marker should work");
+
+        marker
+            .set_lng_lat([12.5, 41.9])
+            .expect("Setting the location should work");
+        marker.add_to(&map);
+        let lng_lat = marker.get_lng_lat();
+
+        assert!((lng_lat.lng() - 12.5).abs() < 0.01);
+        assert!((lng_lat.lat() - 41.9).abs() < 0.01);
+        assert!(marker.is_draggable());
+        assert!((marker.get_rotation() - 45.).abs() < 0.01);
+
+        let drag_events = Rc::new(RefCell::new(Vec::new()));
+        let listeners = [
+            marker.on(events::DragStart, {
+                let drag_events = Rc::clone(&drag_events);
+                move |event| drag_events.borrow_mut().push(event.event_type())
+            }),
+            marker.on(events::Drag, {
+                let drag_events = Rc::clone(&drag_events);
+                move |event| drag_events.borrow_mut().push(event.event_type())
+            }),
+            marker.on(events::DragEnd, {
+                let drag_events = Rc::clone(&drag_events);
+                move |event| drag_events.borrow_mut().push(event.event_type())
+            }),
+        ];
+        assert_eq!(listeners[2].event_type(), "dragend");
+
+        drag_element(&marker.get_element(), 40, 20);
+        assert_eq!(*drag_events.borrow(), vec!["dragstart", "drag", "dragend"]);
+        assert!((marker.get_lng_lat().lng() - 12.5).abs() > 0.01);
+
+        drop(listeners);
+        drag_element(&marker.get_element(), -40, -20);
+        assert_eq!(drag_events.borrow().len(), 3);
+
+        marker.remove();
+    }
+
+    #[wasm_bindgen_test]
+    async fn marker_custom_element_and_offset() {
+        load_maplibre_gl().await;
+        let html_element = gen_html_element("div").cloned_ref();
+        let marker = MarkerOptions::new()
+            .with_element(html_element.clone())
+            .with_offset([4., -8.])
+            .build()
+            .expect("Creating a marker should work");
+
+        assert_eq!(marker.get_element(), html_element);
+        assert_eq!(marker.get_offset(), Point::new(4., -8.));
+
+        marker
+            .set_offset([1., 2.])
+            .expect("Setting the offset should work");
+        assert_eq!(marker.get_offset(), Point::new(1., 2.));
+        assert!(marker.toggle_class_name("selected"));
+    }
+}
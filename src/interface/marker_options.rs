@@ -0,0 +1,307 @@
+//! Submodule for `MarkerOptions` and associated tests
+
+use serde::Serialize;
+use web_sys::HtmlElement;
+
+use super::Error;
+use crate::interface::{Marker, PointLike, layers::Anchor, style::Color};
+
+/// Alignment of the rotation or the pitch of a marker
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MarkerAlignment {
+    /// Aligned to the plane of the map
+    Map,
+    /// Aligned to the plane of the viewport
+    Viewport,
+    /// Same as `Viewport` for the rotation and as `Map` for the pitch
+    Auto,
+}
+
+/// Options of a marker
+///
+/// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/type-aliases/MarkerOptions/>
+#[derive(Debug, PartialEq, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkerOptions {
+    /// Custom element used as the marker. Defaults to a light blue, droplet-shaped SVG marker
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_element"
+    )]
+    element: Option<HtmlElement>,
+    /// Space-separated CSS class names added to the element of the marker
+    #[serde(skip_serializing_if = "Option::is_none")]
+    class_name: Option<String>,
+    /// Offset in pixels of the element relative to its center
+    #[serde(skip_serializing_if = "Option::is_none")]
+    offset: Option<PointLike>,
+    /// Part of the marker placed closest to its coordinates. Defaults to `center`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    anchor: Option<Anchor>,
+    /// Color of the default marker. Ignored when a custom element is given
+    #[serde(skip_serializing_if = "Option::is_none")]
+    color: Option<Color>,
+    /// Scale of the default marker. Ignored when a custom element is given. Defaults to `1`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scale: Option<f64>,
+    /// Whether the marker can be dragged to a new position. Defaults to `false`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    draggable: Option<bool>,
+    /// Maximum number of pixels the pointer can move between press and release to be considered a
+    /// click. Defaults to `0`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    click_tolerance: Option<f64>,
+    /// Rotation of the marker in degrees relative to its alignment. Defaults to `0`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rotation: Option<f64>,
+    /// Alignment of the marker rotation. Defaults to `auto`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rotation_alignment: Option<MarkerAlignment>,
+    /// Alignment of the marker when the map is pitched. Defaults to `auto`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pitch_alignment: Option<MarkerAlignment>,
+    /// Opacity of the marker when it is visible. Defaults to `1`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    opacity: Option<f64>,
+    /// Opacity of the marker when it is covered by the terrain. Defaults to `0.2`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    opacity_when_covered: Option<f64>,
+    /// Whether the marker is positioned with subpixel precision. Defaults to `false`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subpixel_positioning: Option<bool>,
+}
+
+/// Serialize the custom element of a marker passing the JS object through unchanged
+#[allow(clippy::ref_option)]
+fn serialize_element<S: serde::Serializer>(
+    element: &Option<HtmlElement>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match element {
+        Some(element) => serde_wasm_bindgen::preserve::serialize(element, serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+impl MarkerOptions {
+    /// Create new empty `MarkerOptions`
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a `Marker` with the specified options
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors generated on converting the given options into JS and the ones thrown by
+    /// MapLibre on creating the marker
+    pub fn build(&self) -> Result<Marker, Error> {
+        Marker::new(self)
+    }
+
+    /// Specify a custom element to use as the marker
+    #[must_use]
+    pub fn with_element(self, element: impl Into<HtmlElement>) -> Self {
+        Self {
+            element: Some(element.into()),
+            ..self
+        }
+    }
+
+    /// Specify space-separated CSS class names to add to the element of the marker
+    #[must_use]
+    pub fn with_class_name(self, class_name: impl Into<String>) -> Self {
+        Self {
+            class_name: Some(class_name.into()),
+            ..self
+        }
+    }
+
+    /// Specify the offset in pixels of the element relative to its center
+    #[must_use]
+    pub fn with_offset(self, offset: impl Into<PointLike>) -> Self {
+        Self {
+            offset: Some(offset.into()),
+            ..self
+        }
+    }
+
+    /// Specify the part of the marker placed closest to its coordinates
+    #[must_use]
+    pub fn with_anchor(self, anchor: Anchor) -> Self {
+        Self {
+            anchor: Some(anchor),
+            ..self
+        }
+    }
+
+    /// Specify the color of the default marker
+    #[must_use]
+    pub fn with_color(self, color: impl Into<Color>) -> Self {
+        Self {
+            color: Some(color.into()),
+            ..self
+        }
+    }
+
+    /// Specify the scale of the default marker
+    #[must_use]
+    pub fn with_scale(self, scale: impl Into<f64>) -> Self {
+        Self {
+            scale: Some(scale.into()),
+            ..self
+        }
+    }
+
+    /// Allow the marker to be dragged to a new position
+    #[must_use]
+    pub fn with_draggable(self) -> Self {
+        Self {
+            draggable: Some(true),
+            ..self
+        }
+    }
+
+    /// Prevent the marker from being dragged to a new position
+    #[must_use]
+    pub fn without_draggable(self) -> Self {
+        Self {
+            draggable: Some(false),
+            ..self
+        }
+    }
+
+    /// Specify the maximum number of pixels the pointer can move between press and release to be
+    /// considered a click
+    #[must_use]
+    pub fn with_click_tolerance(self, click_tolerance: impl Into<f64>) -> Self {
+        Self {
+            click_tolerance: Some(click_tolerance.into()),
+            ..self
+        }
+    }
+
+    /// Specify the rotation of the marker in degrees relative to its alignment
+    #[must_use]
+    pub fn with_rotation(self, rotation: impl Into<f64>) -> Self {
+        Self {
+            rotation: Some(rotation.into()),
+            ..self
+        }
+    }
+
+    /// Specify the alignment of the marker rotation
+    #[must_use]
+    pub fn with_rotation_alignment(self, rotation_alignment: MarkerAlignment) -> Self {
+        Self {
+            rotation_alignment: Some(rotation_alignment),
+            ..self
+        }
+    }
+
+    /// Specify the alignment of the marker when the map is pitched
+    #[must_use]
+    pub fn with_pitch_alignment(self, pitch_alignment: MarkerAlignment) -> Self {
+        Self {
+            pitch_alignment: Some(pitch_alignment),
+            ..self
+        }
+    }
+
+    /// Specify the opacity of the marker when it is visible
+    #[must_use]
+    pub fn with_opacity(self, opacity: impl Into<f64>) -> Self {
+        Self {
+            opacity: Some(opacity.into()),
+            ..self
+        }
+    }
+
+    /// Specify the opacity of the marker when it is covered by the terrain
+    #[must_use]
+    pub fn with_opacity_when_covered(self, opacity_when_covered: impl Into<f64>) -> Self {
+        Self {
+            opacity_when_covered: Some(opacity_when_covered.into()),
+            ..self
+        }
+    }
+
+    /// Position the marker with subpixel precision
+    #[must_use]
+    pub fn with_subpixel_positioning(self) -> Self {
+        Self {
+            subpixel_positioning: Some(true),
+            ..self
+        }
+    }
+
+    /// Try converting `MarkerOptions` into the equivalent `JsValue`
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn as_js_value(&self) -> Result<wasm_bindgen::JsValue, Error> {
+        self.serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utils::{gen_html_element, get_key_list_from_object, get_value_from_object};
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn marker_options_empty_conversion() {
+        let options_js = MarkerOptions::new()
+            .as_js_value()
+            .expect("Conversion from MarkerOptions to JS should work");
+
+        assert_eq!(get_key_list_from_object(&options_js).len(), 0);
+    }
+
+    #[wasm_bindgen_test]
+    fn marker_options_conversion() {
+        let html_element = gen_html_element("div").cloned_ref();
+        let options_js = MarkerOptions::new()
+            .with_element(html_element.clone())
+            .with_anchor(Anchor::Bottom)
+            .with_rotation_alignment(MarkerAlignment::Map)
+            .with_pitch_alignment(MarkerAlignment::Viewport)
+            .with_draggable()
+            .with_opacity(0.5)
+            .as_js_value()
+            .expect("Conversion from MarkerOptions to JS should work");
+
+        assert_eq!(get_key_list_from_object(&options_js).len(), 6);
+        assert_eq!(
+            get_value_from_object(&options_js, "element")
+                .dyn_into::<HtmlElement>()
+                .expect("The element should be kept as an HtmlElement"),
+            html_element
+        );
+        assert_eq!(
+            get_value_from_object(&options_js, "anchor").as_string(),
+            Some("bottom".to_string())
+        );
+        assert_eq!(
+            get_value_from_object(&options_js, "rotationAlignment").as_string(),
+            Some("map".to_string())
+        );
+        assert_eq!(
+            get_value_from_object(&options_js, "pitchAlignment").as_string(),
+            Some("viewport".to_string())
+        );
+        assert_eq!(
+            get_value_from_object(&options_js, "draggable").as_bool(),
+            Some(true)
+        );
+        assert_eq!(
+            get_value_from_object(&options_js, "opacity").as_f64(),
+            Some(0.5)
+        );
+    }
+}
@@ -1,14 +1,53 @@
 //! Rusty interface to the bindings of MapLibre GL JS
 
+pub mod camera_options;
+pub mod controls;
+pub mod events;
+mod feature_identifier;
+pub mod images;
+pub mod layers;
 mod lng_lat;
+mod lng_lat_bounds;
+mod lng_lat_bounds_like;
 mod lng_lat_like;
 mod map;
+mod map_geojson_feature;
+mod map_load;
 pub mod map_options;
+mod marker;
+mod marker_options;
+mod point;
+mod point_like;
+mod popup;
+mod popup_options;
+mod protocol;
+pub mod query_options;
+mod request_parameters;
+pub mod sources;
+pub mod style;
+mod transform_request;
 
 pub use serde_wasm_bindgen::Error;
 pub use web_sys::HtmlElement;
 
+pub use camera_options::CameraOptions;
+pub use feature_identifier::FeatureIdentifier;
 pub use lng_lat::LngLat;
+pub use lng_lat_bounds::LngLatBounds;
+pub use lng_lat_bounds_like::LngLatBoundsLike;
 pub use lng_lat_like::LngLatLike;
 pub use map::Map;
+pub use map_geojson_feature::MapGeoJsonFeature;
+pub use map_load::{MapLoad, MapLoadError};
 pub use map_options::MapOptions;
+pub use marker::Marker;
+pub use marker_options::{MarkerAlignment, MarkerOptions};
+pub use point::Point;
+pub use point_like::PointLike;
+pub use popup::Popup;
+pub use popup_options::{PopupOffset, PopupOptions};
+pub use protocol::{Protocol, ProtocolData, ProtocolResponse, add_protocol};
+pub use request_parameters::{
+    RequestCredentials, RequestMethod, RequestParameters, ResourceType, ResponseType,
+};
+pub use transform_request::TransformRequest;
@@ -0,0 +1,130 @@
+//! Rusty interface to the bindings of `Point` used by MapLibre GL JS
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsValue;
+
+use crate::bindings;
+
+/// `Point` representing a position in screen pixels, relative to the top left corner of the map
+/// container
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct Point {
+    /// Horizontal position in pixels
+    pub x: f64,
+    /// Vertical position in pixels
+    pub y: f64,
+}
+
+impl Point {
+    /// Create a `Point` with the given coordinates in pixels
+    #[must_use]
+    pub const fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+
+    /// Try converting `Point` into the equivalent `JsValue`
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn as_js_value(&self) -> Result<JsValue, super::Error> {
+        serde_wasm_bindgen::to_value(self)
+    }
+}
+
+impl From<[f64; 2]> for Point {
+    fn from(value: [f64; 2]) -> Self {
+        Self::new(value[0], value[1])
+    }
+}
+
+impl From<(f64, f64)> for Point {
+    fn from(value: (f64, f64)) -> Self {
+        Self::new(value.0, value.1)
+    }
+}
+
+impl From<Point> for [f64; 2] {
+    fn from(value: Point) -> Self {
+        [value.x, value.y]
+    }
+}
+
+impl From<&bindings::Point> for Point {
+    fn from(value: &bindings::Point) -> Self {
+        Self::new(value.x(), value.y())
+    }
+}
+
+impl From<bindings::Point> for Point {
+    fn from(value: bindings::Point) -> Self {
+        (&value).into()
+    }
+}
+
+impl TryFrom<JsValue> for Point {
+    type Error = super::Error;
+
+    fn try_from(value: JsValue) -> Result<Self, Self::Error> {
+        serde_wasm_bindgen::from_value(value)
+    }
+}
+
+impl TryFrom<Point> for JsValue {
+    type Error = super::Error;
+
+    fn try_from(value: Point) -> Result<Self, Self::Error> {
+        value.as_js_value()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_utils::{get_value_from_object, load_maplibre_gl};
+
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    async fn from_bindings() {
+        load_maplibre_gl().await;
+        let point: Point = bindings::Point::new(2.5, 3.5)
+            .expect("Creating Point should work")
+            .into();
+
+        assert_eq!(point, Point::new(2.5, 3.5));
+    }
+
+    #[wasm_bindgen_test]
+    async fn try_from_bindings_js_value() {
+        load_maplibre_gl().await;
+        let point: Point =
+            JsValue::from(bindings::Point::new(2.5, 3.5).expect("Creating Point should work"))
+                .try_into()
+                .expect("Conversion should work");
+
+        assert_eq!(point, Point::new(2.5, 3.5));
+    }
+
+    #[wasm_bindgen_test]
+    fn serialization() {
+        let point = Point::new(2.5, 3.5);
+        let point_js = point
+            .as_js_value()
+            .expect("Converting to JS Value should work");
+        let retrieved_point: Point = point_js
+            .clone()
+            .try_into()
+            .expect("Backconverting should work");
+
+        assert!(
+            (get_value_from_object(&point_js, "x")
+                .as_f64()
+                .expect("x should be a f64")
+                - 2.5)
+                .abs()
+                < 0.01
+        );
+        assert_eq!(retrieved_point, point);
+    }
+}
@@ -0,0 +1,132 @@
+//! Rusty interface to the bindings of `PointLike` used by MapLibre GL JS
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsValue;
+
+use crate::{bindings, interface::Point};
+
+/// Different options to represent a `Point`
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PointLike {
+    /// The `PointLike` is an actual `Point`
+    Point(Point),
+    /// The `PointLike` is expressed as a two index array, the first being the `x` and the second
+    /// being the `y`
+    Array([f64; 2]),
+}
+
+impl PointLike {
+    /// Try converting `PointLike` into the equivalent bindings type
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn as_js_value(&self) -> Result<bindings::PointLike, super::Error> {
+        Ok(bindings::PointLike::from(serde_wasm_bindgen::to_value(
+            self,
+        )?))
+    }
+
+    /// Get the horizontal position of the active variant
+    #[must_use]
+    pub const fn x(&self) -> f64 {
+        match self {
+            Self::Point(Point { x, y: _ }) | Self::Array([x, _]) => *x,
+        }
+    }
+
+    /// Get the vertical position of the active variant
+    #[must_use]
+    pub const fn y(&self) -> f64 {
+        match self {
+            Self::Point(Point { x: _, y }) | Self::Array([_, y]) => *y,
+        }
+    }
+}
+
+impl From<Point> for PointLike {
+    fn from(value: Point) -> Self {
+        Self::Point(value)
+    }
+}
+
+impl From<[f64; 2]> for PointLike {
+    fn from(value: [f64; 2]) -> Self {
+        Self::Array(value)
+    }
+}
+
+impl From<(f64, f64)> for PointLike {
+    fn from(value: (f64, f64)) -> Self {
+        Self::Array(value.into())
+    }
+}
+
+impl From<PointLike> for Point {
+    fn from(value: PointLike) -> Self {
+        Self::new(value.x(), value.y())
+    }
+}
+
+impl TryFrom<bindings::PointLike> for PointLike {
+    type Error = super::Error;
+
+    fn try_from(value: bindings::PointLike) -> Result<Self, Self::Error> {
+        serde_wasm_bindgen::from_value(JsValue::from(value))
+    }
+}
+
+impl TryFrom<PointLike> for bindings::PointLike {
+    type Error = super::Error;
+
+    fn try_from(value: PointLike) -> Result<Self, Self::Error> {
+        value.as_js_value()
+    }
+}
+
+impl TryFrom<&PointLike> for bindings::PointLike {
+    type Error = super::Error;
+
+    fn try_from(value: &PointLike) -> Result<Self, Self::Error> {
+        value.as_js_value()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn pointlike_point_to_js() {
+        let pointlike = PointLike::Point(Point::new(1.5, 2.5));
+        let retrieved_pointlike: PointLike = pointlike
+            .as_js_value()
+            .expect("Conversion from PointLike with Point should work")
+            .try_into()
+            .expect("Conversion should work");
+
+        assert_eq!(pointlike, retrieved_pointlike);
+    }
+
+    #[wasm_bindgen_test]
+    fn pointlike_array_to_js() {
+        let pointlike = PointLike::Array([1.5, 2.5]);
+        let retrieved_pointlike: PointLike = pointlike
+            .as_js_value()
+            .expect("Conversion from PointLike with Array should work")
+            .try_into()
+            .expect("Conversion should work");
+
+        assert_eq!(pointlike, retrieved_pointlike);
+    }
+
+    #[wasm_bindgen_test]
+    fn from_tuple() {
+        assert_eq!(
+            Into::<PointLike>::into((1.5, 2.5)),
+            PointLike::Array([1.5, 2.5])
+        );
+    }
+}
@@ -0,0 +1,235 @@
+//! Rusty interface to the bindings of `Popup` of MapLibre GL JS
+
+use wasm_bindgen::prelude::*;
+use web_sys::{HtmlElement, Node};
+
+use super::Error;
+use crate::{
+    bindings,
+    interface::{
+        LngLat, LngLatLike, Map, PopupOffset, PopupOptions,
+        events::{EventListener, PopupEventType},
+    },
+};
+
+/// Popup component placed on the map at a given longitude and latitude
+///
+/// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/classes/Popup/>
+#[derive(Debug, PartialEq, Clone)]
+pub struct Popup {
+    /// JS object that the struct wraps
+    js_value: bindings::Popup,
+}
+
+impl Popup {
+    /// Create a `Popup` with the specified options. It has to be placed with `set_lng_lat` or
+    /// `track_pointer` and added with `add_to` to be shown, unless it is bound to a `Marker`
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors generated on converting the given options into JS and the ones thrown by
+    /// MapLibre on creating the popup
+    pub fn new(options: &PopupOptions) -> Result<Self, Error> {
+        let js_value = bindings::Popup::new(&options.as_js_value()?)?;
+        Ok(Self { js_value })
+    }
+
+    /// Convert `Popup` into the inner bindings value
+    #[must_use]
+    pub fn into_inner(self) -> bindings::Popup {
+        self.js_value
+    }
+
+    /// Adds the popup to the given map, opening it
+    pub fn add_to(&self, map: &Map) {
+        self.js_value.add_to(&map.clone().into_inner());
+    }
+
+    /// Returns whether the popup is open
+    #[must_use]
+    pub fn is_open(&self) -> bool {
+        self.js_value.is_open()
+    }
+
+    /// Removes the popup from the map it has been added to, closing it
+    pub fn remove(&self) {
+        self.js_value.remove();
+    }
+
+    /// Returns the geographical location of the popup's anchor, if it has been set
+    #[must_use]
+    pub fn get_lng_lat(&self) -> Option<LngLat> {
+        self.js_value.get_lng_lat().map(Into::into)
+    }
+
+    /// Sets the geographical location of the popup's anchor, stopping tracking the pointer
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn set_lng_lat(&self, lng_lat: impl Into<LngLatLike>) -> Result<(), Error> {
+        self.js_value.set_lng_lat(&lng_lat.into().as_js_value()?);
+        Ok(())
+    }
+
+    /// Tracks the popup anchor to the cursor position on screens with a pointer device
+    pub fn track_pointer(&self) {
+        self.js_value.track_pointer();
+    }
+
+    /// Returns the container element of the popup, if it has been added to a map
+    #[must_use]
+    pub fn get_element(&self) -> Option<HtmlElement> {
+        self.js_value.get_element()
+    }
+
+    /// Sets the content of the popup to a string of text, without parsing it as HTML
+    pub fn set_text(&self, text: &str) {
+        self.js_value.set_text(text);
+    }
+
+    /// Sets the content of the popup to the given HTML string. Only use it with trusted content
+    pub fn set_html(&self, html: &str) {
+        self.js_value.set_html(html);
+    }
+
+    /// Sets the content of the popup to the given DOM node, which can be used as the mount point
+    /// of a component tree
+    pub fn set_dom_content(&self, node: &Node) {
+        self.js_value.set_dom_content(node);
+    }
+
+    /// Returns the CSS max width of the popup, if it has been added to a map
+    #[must_use]
+    pub fn get_max_width(&self) -> Option<String> {
+        self.js_value.get_max_width()
+    }
+
+    /// Sets the CSS max width of the popup, such as `300px` or `none`
+    pub fn set_max_width(&self, max_width: &str) {
+        self.js_value.set_max_width(max_width);
+    }
+
+    /// Sets the offset of the popup relative to its location
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn set_offset(&self, offset: impl Into<PopupOffset>) -> Result<(), Error> {
+        self.js_value.set_offset(&offset.into().as_js_value()?);
+        Ok(())
+    }
+
+    /// Adds a CSS class to the container element of the popup
+    pub fn add_class_name(&self, class_name: &str) {
+        self.js_value.add_class_name(class_name);
+    }
+
+    /// Removes a CSS class from the container element of the popup
+    pub fn remove_class_name(&self, class_name: &str) {
+        self.js_value.remove_class_name(class_name);
+    }
+
+    /// Toggles a CSS class of the container element of the popup, returning whether the class is
+    /// now present. Returns `None` if the popup hasn't been added to a map
+    #[must_use]
+    pub fn toggle_class_name(&self, class_name: &str) -> Option<bool> {
+        self.js_value.toggle_class_name(class_name)
+    }
+
+    /// Adds a listener for events of the given type fired by the popup.
+    ///
+    /// The listener is removed when the returned handle is dropped
+    pub fn on<E: PopupEventType>(
+        &self,
+        _event: E,
+        mut listener: impl FnMut(E::Event) + 'static,
+    ) -> EventListener {
+        let closure = Closure::<dyn FnMut(JsValue)>::new(move |event: JsValue| {
+            listener(event.into());
+        });
+        self.js_value.on(E::NAME, closure.as_ref().unchecked_ref());
+        EventListener::new(self.js_value.clone().into(), E::NAME, closure)
+    }
+}
+
+impl From<bindings::Popup> for Popup {
+    fn from(value: bindings::Popup) -> Self {
+        Self { js_value: value }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        interface::{MapOptions, MarkerOptions, events},
+        test_utils::{gen_html_element, load_maplibre_gl},
+    };
+    use std::{cell::Cell, rc::Rc};
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    async fn popup_open_and_close() {
+        load_maplibre_gl().await;
+        let html_element = gen_html_element("div").cloned_ref();
+        let map = MapOptions::new(html_element)
+            .build()
+            .expect("Creating a map should work");
+        let popup = PopupOptions::new()
+            .with_max_width("300px")
+            .build()
+            .expect("Creating a popup should work");
+        let opened = Rc::new(Cell::new(false));
+        let closed = Rc::new(Cell::new(false));
+        let _open_listener = popup.on(events::Open, {
+            let opened = opened.clone();
+            move |_| opened.set(true)
+        });
+        let _close_listener = popup.on(events::Close, {
+            let closed = closed.clone();
+            move |_| closed.set(true)
+        });
+
+        popup
+            .set_lng_lat([1., 2.])
+            .expect("Setting the location should work");
+        popup.set_text("Hello <b>world</b>");
+        popup.add_to(&map);
+
+        assert!(popup.is_open());
+        assert!(opened.get());
+        assert_eq!(popup.get_max_width(), Some("300px".to_string()));
+        assert_eq!(
+            popup
+                .get_element()
+                .expect("An open popup should have an element")
+                .text_content()
+                .map(|text| text.contains("Hello <b>world</b>")),
+            Some(true)
+        );
+
+        popup.remove();
+        assert!(!popup.is_open());
+        assert!(closed.get());
+    }
+
+    #[wasm_bindgen_test]
+    async fn popup_dom_content_bound_to_marker() {
+        load_maplibre_gl().await;
+        let content = gen_html_element("span").cloned_ref();
+        let popup = PopupOptions::new()
+            .build()
+            .expect("Creating a popup should work");
+        popup.set_dom_content(&content);
+        let marker = MarkerOptions::new()
+            .build()
+            .expect("Creating a marker should work");
+
+        marker.set_popup(Some(&popup));
+        assert_eq!(marker.get_popup(), Some(popup));
+
+        marker.set_popup(None);
+        assert_eq!(marker.get_popup(), None);
+    }
+}
@@ -0,0 +1,277 @@
+//! Submodule for `PopupOptions`, `PopupOffset` and associated tests
+
+use serde::{Serialize, Serializer, ser::SerializeMap};
+use wasm_bindgen::JsValue;
+
+use super::Error;
+use crate::interface::{PointLike, Popup, layers::Anchor};
+
+/// Offset of a popup relative to its location
+///
+/// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/type-aliases/Offset/>
+#[derive(Debug, PartialEq, Clone)]
+pub enum PopupOffset {
+    /// Distance in pixels from the location, applied in the direction of the popup anchor
+    Distance(f64),
+    /// Offset in pixels applied to every anchor
+    Point(PointLike),
+    /// Offset in pixels applied when the popup has the given anchor. Missing anchors aren't
+    /// offset
+    PerAnchor(Vec<(Anchor, PointLike)>),
+}
+
+impl PopupOffset {
+    /// Try converting `PopupOffset` into the equivalent `JsValue`
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn as_js_value(&self) -> Result<JsValue, Error> {
+        self.serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+    }
+}
+
+impl Serialize for PopupOffset {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Distance(distance) => serializer.serialize_f64(*distance),
+            Self::Point(point) => [point.x(), point.y()].serialize(serializer),
+            Self::PerAnchor(offsets) => {
+                let mut map = serializer.serialize_map(Some(offsets.len()))?;
+                for (anchor, point) in offsets {
+                    map.serialize_entry(anchor, &[point.x(), point.y()])?;
+                }
+                map.end()
+            }
+        }
+    }
+}
+
+impl From<f64> for PopupOffset {
+    fn from(value: f64) -> Self {
+        Self::Distance(value)
+    }
+}
+
+impl From<PointLike> for PopupOffset {
+    fn from(value: PointLike) -> Self {
+        Self::Point(value)
+    }
+}
+
+impl From<[f64; 2]> for PopupOffset {
+    fn from(value: [f64; 2]) -> Self {
+        Self::Point(value.into())
+    }
+}
+
+impl From<(f64, f64)> for PopupOffset {
+    fn from(value: (f64, f64)) -> Self {
+        Self::Point(value.into())
+    }
+}
+
+impl From<Vec<(Anchor, PointLike)>> for PopupOffset {
+    fn from(value: Vec<(Anchor, PointLike)>) -> Self {
+        Self::PerAnchor(value)
+    }
+}
+
+/// Options of a popup
+///
+/// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/type-aliases/PopupOptions/>
+#[derive(Debug, PartialEq, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PopupOptions {
+    /// Whether a close button is shown in the top right corner. Defaults to `true`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    close_button: Option<bool>,
+    /// Whether the popup is closed when the map is clicked. Defaults to `true`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    close_on_click: Option<bool>,
+    /// Whether the popup is closed when the map moves. Defaults to `false`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    close_on_move: Option<bool>,
+    /// Whether the first focusable element of the popup is focused when it opens. Defaults to
+    /// `true`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    focus_after_open: Option<bool>,
+    /// Part of the popup placed closest to its location. Dynamically set by default to keep the
+    /// popup within the map container
+    #[serde(skip_serializing_if = "Option::is_none")]
+    anchor: Option<Anchor>,
+    /// Offset of the popup relative to its location
+    #[serde(skip_serializing_if = "Option::is_none")]
+    offset: Option<PopupOffset>,
+    /// Space-separated CSS class names added to the container of the popup
+    #[serde(skip_serializing_if = "Option::is_none")]
+    class_name: Option<String>,
+    /// CSS max width of the popup, such as `300px` or `none`. Defaults to `240px`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_width: Option<String>,
+    /// Whether the popup is positioned with subpixel precision. Defaults to `false`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subpixel_positioning: Option<bool>,
+}
+
+impl PopupOptions {
+    /// Create new empty `PopupOptions`
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a `Popup` with the specified options
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors generated on converting the given options into JS and the ones thrown by
+    /// MapLibre on creating the popup
+    pub fn build(&self) -> Result<Popup, Error> {
+        Popup::new(self)
+    }
+
+    /// Hide the close button of the popup
+    #[must_use]
+    pub fn without_close_button(self) -> Self {
+        Self {
+            close_button: Some(false),
+            ..self
+        }
+    }
+
+    /// Keep the popup open when the map is clicked
+    #[must_use]
+    pub fn without_close_on_click(self) -> Self {
+        Self {
+            close_on_click: Some(false),
+            ..self
+        }
+    }
+
+    /// Close the popup when the map moves
+    #[must_use]
+    pub fn with_close_on_move(self) -> Self {
+        Self {
+            close_on_move: Some(true),
+            ..self
+        }
+    }
+
+    /// Don't focus the first focusable element of the popup when it opens
+    #[must_use]
+    pub fn without_focus_after_open(self) -> Self {
+        Self {
+            focus_after_open: Some(false),
+            ..self
+        }
+    }
+
+    /// Specify the part of the popup placed closest to its location
+    #[must_use]
+    pub fn with_anchor(self, anchor: Anchor) -> Self {
+        Self {
+            anchor: Some(anchor),
+            ..self
+        }
+    }
+
+    /// Specify the offset of the popup relative to its location
+    #[must_use]
+    pub fn with_offset(self, offset: impl Into<PopupOffset>) -> Self {
+        Self {
+            offset: Some(offset.into()),
+            ..self
+        }
+    }
+
+    /// Specify space-separated CSS class names to add to the container of the popup
+    #[must_use]
+    pub fn with_class_name(self, class_name: impl Into<String>) -> Self {
+        Self {
+            class_name: Some(class_name.into()),
+            ..self
+        }
+    }
+
+    /// Specify the CSS max width of the popup, such as `300px` or `none`
+    #[must_use]
+    pub fn with_max_width(self, max_width: impl Into<String>) -> Self {
+        Self {
+            max_width: Some(max_width.into()),
+            ..self
+        }
+    }
+
+    /// Position the popup with subpixel precision
+    #[must_use]
+    pub fn with_subpixel_positioning(self) -> Self {
+        Self {
+            subpixel_positioning: Some(true),
+            ..self
+        }
+    }
+
+    /// Try converting `PopupOptions` into the equivalent `JsValue`
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn as_js_value(&self) -> Result<JsValue, Error> {
+        self.serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utils::{get_key_list_from_object, get_value_from_object};
+    use serde_json::json;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn popup_options_conversion() {
+        let options_js = PopupOptions::new()
+            .without_close_button()
+            .with_close_on_move()
+            .with_anchor(Anchor::TopLeft)
+            .with_max_width("300px")
+            .as_js_value()
+            .expect("Conversion from PopupOptions to JS should work");
+        let options: serde_json::Value = serde_wasm_bindgen::from_value(options_js.clone())
+            .expect("Back conversion from JS should work");
+
+        assert_eq!(get_key_list_from_object(&options_js).len(), 4);
+        assert_eq!(
+            options,
+            json!({
+                "closeButton": false,
+                "closeOnMove": true,
+                "anchor": "top-left",
+                "maxWidth": "300px",
+            })
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn popup_offset_conversion() {
+        let distance_js = PopupOffset::from(12.)
+            .as_js_value()
+            .expect("Conversion from PopupOffset to JS should work");
+        let point_js = PopupOffset::from([1., -2.])
+            .as_js_value()
+            .expect("Conversion from PopupOffset to JS should work");
+        let per_anchor_js = PopupOffset::from(vec![(Anchor::Bottom, [0., -10.].into())])
+            .as_js_value()
+            .expect("Conversion from PopupOffset to JS should work");
+        let point: serde_json::Value =
+            serde_wasm_bindgen::from_value(point_js).expect("Back conversion should work");
+        let per_anchor: serde_json::Value = serde_wasm_bindgen::from_value(per_anchor_js.clone())
+            .expect("Back conversion should work");
+
+        assert_eq!(distance_js.as_f64(), Some(12.));
+        assert_eq!(point, json!([1., -2.]));
+        assert_eq!(per_anchor, json!({"bottom": [0., -10.]}));
+        assert!(get_value_from_object(&per_anchor_js, "bottom").is_array());
+    }
+}
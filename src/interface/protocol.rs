@@ -0,0 +1,448 @@
+//! Rusty interface to the custom protocols of MapLibre GL JS, serving resources from Rust
+
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    pin::Pin,
+    sync::atomic::{AtomicU32, Ordering},
+    task::{Context, Poll},
+};
+
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::{JsFuture, future_to_promise};
+use web_sys::{
+    AbortController, HtmlImageElement, ImageBitmap,
+    js_sys::{Object, Promise, Reflect, Uint8Array},
+};
+
+use super::{Error, RequestParameters};
+use crate::bindings;
+
+/// Data of the resource served by a custom protocol. It must match the `response_type` of the
+/// request, for example `Bytes` for tiles and `Json` for styles
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ProtocolData {
+    /// Raw bytes, for `ResponseType::ArrayBuffer`
+    Bytes(Vec<u8>),
+    /// JSON, for `ResponseType::Json`
+    Json(serde_json::Value),
+    /// Text, for `ResponseType::String`
+    Text(String),
+    /// Decoded image element, for `ResponseType::Image`
+    Image(HtmlImageElement),
+    /// Decoded image bitmap, for `ResponseType::Image`
+    ImageBitmap(ImageBitmap),
+}
+
+impl ProtocolData {
+    /// Try converting `ProtocolData` into the equivalent `JsValue`
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn as_js_value(&self) -> Result<JsValue, Error> {
+        Ok(match self {
+            Self::Bytes(bytes) => Uint8Array::from(bytes.as_slice()).buffer().into(),
+            Self::Json(json) => {
+                json.serialize(&serde_wasm_bindgen::Serializer::json_compatible())?
+            }
+            Self::Text(text) => JsValue::from_str(text),
+            Self::Image(image) => image.into(),
+            Self::ImageBitmap(image) => image.into(),
+        })
+    }
+}
+
+impl From<Vec<u8>> for ProtocolData {
+    fn from(value: Vec<u8>) -> Self {
+        Self::Bytes(value)
+    }
+}
+
+impl From<&[u8]> for ProtocolData {
+    fn from(value: &[u8]) -> Self {
+        Self::Bytes(value.to_vec())
+    }
+}
+
+impl From<serde_json::Value> for ProtocolData {
+    fn from(value: serde_json::Value) -> Self {
+        Self::Json(value)
+    }
+}
+
+impl From<String> for ProtocolData {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&str> for ProtocolData {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+impl From<HtmlImageElement> for ProtocolData {
+    fn from(value: HtmlImageElement) -> Self {
+        Self::Image(value)
+    }
+}
+
+impl From<ImageBitmap> for ProtocolData {
+    fn from(value: ImageBitmap) -> Self {
+        Self::ImageBitmap(value)
+    }
+}
+
+/// Response of a custom protocol to a request of MapLibre.
+///
+/// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/type-aliases/GetResourceResponse/>
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ProtocolResponse {
+    /// Data of the resource
+    data: ProtocolData,
+    /// Value of the `Cache-Control` header of the resource
+    cache_control: Option<String>,
+    /// Value of the `Expires` header of the resource
+    expires: Option<String>,
+}
+
+impl ProtocolResponse {
+    /// Create a `ProtocolResponse` with the given data
+    #[must_use]
+    pub fn new(data: impl Into<ProtocolData>) -> Self {
+        Self {
+            data: data.into(),
+            cache_control: None,
+            expires: None,
+        }
+    }
+
+    /// Specify the value of the `Cache-Control` header of the resource
+    #[must_use]
+    pub fn with_cache_control(self, cache_control: impl Into<String>) -> Self {
+        Self {
+            cache_control: Some(cache_control.into()),
+            ..self
+        }
+    }
+
+    /// Specify the value of the `Expires` header of the resource
+    #[must_use]
+    pub fn with_expires(self, expires: impl Into<String>) -> Self {
+        Self {
+            expires: Some(expires.into()),
+            ..self
+        }
+    }
+
+    /// Get the data of the resource
+    #[must_use]
+    pub const fn data(&self) -> &ProtocolData {
+        &self.data
+    }
+
+    /// Try converting `ProtocolResponse` into the equivalent `JsValue`
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors and errors generated from JS
+    pub fn as_js_value(&self) -> Result<JsValue, Error> {
+        let response = Object::new();
+        Reflect::set(&response, &"data".into(), &self.data.as_js_value()?)?;
+        if let Some(cache_control) = &self.cache_control {
+            Reflect::set(&response, &"cacheControl".into(), &cache_control.into())?;
+        }
+        if let Some(expires) = &self.expires {
+            Reflect::set(&response, &"expires".into(), &expires.into())?;
+        }
+        Ok(response.into())
+    }
+}
+
+impl<T: Into<ProtocolData>> From<T> for ProtocolResponse {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+/// Source of the generations identifying each registration of a protocol
+static NEXT_GENERATION: AtomicU32 = AtomicU32::new(0);
+
+thread_local! {
+    /// Generation of the current registration of each protocol, indexed by its name
+    static PROTOCOL_OWNERS: RefCell<HashMap<String, u32>> = RefCell::new(HashMap::new());
+}
+
+/// Handle to a custom protocol registered with `add_protocol`.
+///
+/// The protocol is removed and its closure is freed when the handle is dropped, unless another
+/// protocol with the same name has been registered since
+#[derive(Debug)]
+#[must_use = "the protocol is removed as soon as the handle is dropped"]
+pub struct Protocol {
+    /// Name of the protocol, without `://`
+    name: String,
+    /// Generation of the registration, used to only remove the protocol while it's current
+    generation: u32,
+    /// Closure called by MapLibre for each request, kept alive while the protocol is registered
+    _closure: Closure<dyn FnMut(JsValue, AbortController) -> Promise>,
+}
+
+impl Protocol {
+    /// Get the name of the protocol, without `://`
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Keep the protocol registered for the rest of the life of the page, leaking its closure
+    pub const fn forget(self) {
+        std::mem::forget(self);
+    }
+}
+
+impl Drop for Protocol {
+    fn drop(&mut self) {
+        let is_current = PROTOCOL_OWNERS.with_borrow_mut(|owners| {
+            if owners.get(&self.name) == Some(&self.generation) {
+                owners.remove(&self.name);
+                true
+            } else {
+                false
+            }
+        });
+        if is_current {
+            bindings::remove_protocol(&self.name);
+        }
+    }
+}
+
+/// Serve the requests of MapLibre for URLs starting with `{name}://` with the given async
+/// handler, for example to serve tiles generated in Rust with `app://tiles/{z}/{x}/{y}`.
+///
+/// The future returned by the handler is dropped if MapLibre aborts the request, for example
+/// when a tile goes out of view before being loaded. Registering a protocol with the same name
+/// replaces the previous one, whose handle no longer removes the protocol when dropped.
+///
+/// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/functions/addProtocol/>
+pub fn add_protocol<F, Fut, R>(name: impl Into<String>, handler: F) -> Protocol
+where
+    F: Fn(RequestParameters) -> Fut + 'static,
+    Fut: Future<Output = Result<R, Error>> + 'static,
+    R: Into<ProtocolResponse>,
+{
+    let name = name.into();
+    let closure = Closure::<dyn FnMut(JsValue, AbortController) -> Promise>::new(
+        move |parameters: JsValue, abort_controller: AbortController| {
+            let parameters = match RequestParameters::try_from(parameters) {
+                Ok(parameters) => parameters,
+                Err(error) => return Promise::reject(&error.into()),
+            };
+            let response = handler(parameters);
+            let aborted = aborted(&abort_controller);
+            future_to_promise(async move {
+                let response = Abortable::new(response, aborted)
+                    .await
+                    .ok_or_else(|| Error::new("The request was aborted"))??;
+                Ok(response.into().as_js_value()?)
+            })
+        },
+    );
+    let generation = NEXT_GENERATION.fetch_add(1, Ordering::Relaxed);
+    bindings::add_protocol(&name, closure.as_ref().unchecked_ref());
+    PROTOCOL_OWNERS.with_borrow_mut(|owners| owners.insert(name.clone(), generation));
+    Protocol {
+        name,
+        generation,
+        _closure: closure,
+    }
+}
+
+/// Create a future which resolves once the given controller aborts its request
+fn aborted(abort_controller: &AbortController) -> JsFuture {
+    let signal = abort_controller.signal();
+    JsFuture::from(Promise::new(&mut |resolve, _reject| {
+        if signal.aborted() {
+            let _ = resolve.call0(&JsValue::NULL);
+        } else {
+            signal.set_onabort(Some(&resolve));
+        }
+    }))
+}
+
+/// Future which runs the wrapped future until it finishes or the request is aborted, resolving
+/// to `None` in the latter case
+struct Abortable<F> {
+    /// Future serving the request
+    future: Pin<Box<F>>,
+    /// Future which resolves once the request is aborted
+    aborted: JsFuture,
+}
+
+impl<F> Abortable<F> {
+    /// Create an `Abortable` running the given future until `aborted` resolves
+    fn new(future: F, aborted: JsFuture) -> Self {
+        Self {
+            future: Box::pin(future),
+            aborted,
+        }
+    }
+}
+
+impl<F: Future> Future for Abortable<F> {
+    type Output = Option<F::Output>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if Pin::new(&mut self.aborted).poll(cx).is_ready() {
+            return Poll::Ready(None);
+        }
+        self.future.as_mut().poll(cx).map(Some)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        interface::{MapOptions, RequestParameters},
+        test_utils::{gen_html_element, gen_sized_container, load_maplibre_gl},
+    };
+    use serde_json::json;
+    use std::{cell::Cell, rc::Rc};
+    use wasm_bindgen_test::*;
+    use web_sys::js_sys::Function;
+
+    /// Sets its flag when dropped, to check that the future holding it has been dropped
+    struct DropFlag(Rc<Cell<bool>>);
+
+    impl Drop for DropFlag {
+        fn drop(&mut self) {
+            self.0.set(true);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn protocol_serves_style() {
+        load_maplibre_gl().await;
+        let requests = Rc::new(RefCell::new(Vec::new()));
+        let protocol = add_protocol("rust-style", {
+            let requests = Rc::clone(&requests);
+            move |parameters: RequestParameters| {
+                requests.borrow_mut().push(parameters.url);
+                async { Ok(json!({"version": 8, "sources": {}, "layers": []})) }
+            }
+        });
+        let html_element = gen_html_element("div").cloned_ref();
+        let map = MapOptions::new(html_element)
+            .with_style("rust-style://styles/empty")
+            .build_async()
+            .await
+            .expect("The map should load the style served by the protocol");
+
+        assert!(map.loaded());
+        assert_eq!(protocol.name(), "rust-style");
+        assert_eq!(*requests.borrow(), vec!["rust-style://styles/empty"]);
+    }
+
+    #[wasm_bindgen_test]
+    async fn protocol_replaced_is_not_removed_by_old_handle() {
+        load_maplibre_gl().await;
+        let old = add_protocol("rust-replaced", |_| async {
+            Err::<ProtocolResponse, _>(Error::new("The old protocol should not be called"))
+        });
+        let new = add_protocol("rust-replaced", |_| async {
+            Ok(json!({"version": 8, "sources": {}, "layers": []}))
+        });
+        drop(old);
+        let html_element = gen_html_element("div").cloned_ref();
+        let map = MapOptions::new(html_element)
+            .with_style("rust-replaced://styles/empty")
+            .build_async()
+            .await
+            .expect("The map should load the style served by the new protocol");
+
+        assert!(map.loaded());
+        assert_eq!(new.name(), "rust-replaced");
+    }
+
+    #[wasm_bindgen_test]
+    async fn protocol_abort_drops_handler_future() {
+        load_maplibre_gl().await;
+        let dropped = Rc::new(Cell::new(false));
+        let resolve_started = Rc::new(RefCell::new(None::<Function>));
+        let started = Promise::new(&mut |resolve, _reject| {
+            *resolve_started.borrow_mut() = Some(resolve);
+        });
+        let _protocol = add_protocol("rust-abort", {
+            let dropped = Rc::clone(&dropped);
+            let resolve_started = Rc::clone(&resolve_started);
+            move |_| {
+                if let Some(resolve) = resolve_started.borrow_mut().take() {
+                    let _ = resolve.call0(&JsValue::NULL);
+                }
+                let flag = DropFlag(Rc::clone(&dropped));
+                async move {
+                    let _flag = flag;
+                    std::future::pending::<Result<ProtocolResponse, Error>>().await
+                }
+            }
+        });
+        let container = gen_sized_container(400, 300);
+        let map = MapOptions::new(container.cloned_ref())
+            .with_style(json!({"version": 8, "sources": {}, "layers": []}))
+            .build_async()
+            .await
+            .expect("Creating a map should work");
+        map.add_source(
+            "pending",
+            &json!({"type": "raster", "tiles": ["rust-abort://tiles/{z}/{x}/{y}"]}),
+        )
+        .expect("Adding a source should work");
+        map.add_layer(
+            &json!({"id": "pending", "type": "raster", "source": "pending"}),
+            None,
+        )
+        .expect("Adding a layer should work");
+        JsFuture::from(started)
+            .await
+            .expect("The protocol should receive a tile request");
+        assert!(!dropped.get());
+
+        map.remove_layer("pending")
+            .expect("Removing the layer should work");
+        map.remove_source("pending")
+            .expect("Removing the source should abort its tile requests");
+        for _ in 0..10 {
+            if dropped.get() {
+                break;
+            }
+            JsFuture::from(Promise::new(&mut |resolve, _reject| {
+                web_sys::window()
+                    .expect("Should be able to get the window")
+                    .set_timeout_with_callback(&resolve)
+                    .expect("Setting a timeout should work");
+            }))
+            .await
+            .expect("The timeout should resolve");
+        }
+        assert!(dropped.get());
+    }
+
+    #[wasm_bindgen_test]
+    fn protocol_response_conversion() {
+        let response = ProtocolResponse::new(vec![1, 2, 3])
+            .with_cache_control("max-age=60")
+            .as_js_value()
+            .expect("Conversion should work");
+        let data = Reflect::get(&response, &"data".into()).expect("Data should exist");
+
+        assert_eq!(Uint8Array::new(&data).to_vec(), vec![1, 2, 3]);
+        assert_eq!(
+            Reflect::get(&response, &"cacheControl".into()).expect("Should exist"),
+            "max-age=60"
+        );
+    }
+}
@@ -0,0 +1,250 @@
+//! Rusty interface to the options of the feature queries of a `Map` of MapLibre GL JS
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsValue;
+
+use super::Error;
+use crate::interface::{Point, PointLike, style::Expression};
+
+/// Area of the screen in which to query the rendered features
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum QueryGeometry {
+    /// The whole viewport of the map
+    #[default]
+    Viewport,
+    /// A single pixel
+    Point(PointLike),
+    /// The box between two opposite corners
+    Box([PointLike; 2]),
+}
+
+impl QueryGeometry {
+    /// Try converting `QueryGeometry` into the equivalent `JsValue`, which is `undefined` for
+    /// the whole viewport
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn as_js_value(&self) -> Result<JsValue, Error> {
+        match self {
+            Self::Viewport => Ok(JsValue::UNDEFINED),
+            Self::Point(point) => serde_wasm_bindgen::to_value(&[point.x(), point.y()]),
+            Self::Box([first, second]) => {
+                serde_wasm_bindgen::to_value(&[[first.x(), first.y()], [second.x(), second.y()]])
+            }
+        }
+    }
+}
+
+impl From<PointLike> for QueryGeometry {
+    fn from(value: PointLike) -> Self {
+        Self::Point(value)
+    }
+}
+
+impl From<Point> for QueryGeometry {
+    fn from(value: Point) -> Self {
+        Self::Point(value.into())
+    }
+}
+
+impl From<[f64; 2]> for QueryGeometry {
+    fn from(value: [f64; 2]) -> Self {
+        Self::Point(value.into())
+    }
+}
+
+impl From<(f64, f64)> for QueryGeometry {
+    fn from(value: (f64, f64)) -> Self {
+        Self::Point(value.into())
+    }
+}
+
+impl From<[PointLike; 2]> for QueryGeometry {
+    fn from(value: [PointLike; 2]) -> Self {
+        Self::Box(value)
+    }
+}
+
+impl From<[[f64; 2]; 2]> for QueryGeometry {
+    fn from(value: [[f64; 2]; 2]) -> Self {
+        Self::Box(value.map(Into::into))
+    }
+}
+
+/// Options of `Map::query_rendered_features`
+#[derive(Debug, PartialEq, Eq, Clone, Default, Serialize, Deserialize)]
+pub struct QueryRenderedFeaturesOptions {
+    /// Identifiers of the style layers to query. Defaults to all of them
+    #[serde(skip_serializing_if = "Option::is_none")]
+    layers: Option<Vec<String>>,
+    /// Expression filtering the features
+    #[serde(skip_serializing_if = "Option::is_none")]
+    filter: Option<Expression<bool>>,
+    /// Whether to validate the filter. Defaults to `true`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    validate: Option<bool>,
+}
+
+impl QueryRenderedFeaturesOptions {
+    /// Create new empty `QueryRenderedFeaturesOptions`
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only query the style layers with the given identifiers
+    #[must_use]
+    pub fn with_layers(self, layers: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            layers: Some(layers.into_iter().map(Into::into).collect()),
+            ..self
+        }
+    }
+
+    /// Specify the expression filtering the features
+    #[must_use]
+    pub fn with_filter(self, filter: impl Into<Expression<bool>>) -> Self {
+        Self {
+            filter: Some(filter.into()),
+            ..self
+        }
+    }
+
+    /// Validate the filter
+    #[must_use]
+    pub fn with_validate(self) -> Self {
+        Self {
+            validate: Some(true),
+            ..self
+        }
+    }
+
+    /// Don't validate the filter
+    #[must_use]
+    pub fn without_validate(self) -> Self {
+        Self {
+            validate: Some(false),
+            ..self
+        }
+    }
+
+    /// Try converting `QueryRenderedFeaturesOptions` into the equivalent `JsValue`
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn as_js_value(&self) -> Result<JsValue, Error> {
+        self.serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+    }
+}
+
+/// Options of `Map::query_source_features`
+#[derive(Debug, PartialEq, Eq, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuerySourceFeaturesOptions {
+    /// Layer of the vector source to query. Required for vector sources
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source_layer: Option<String>,
+    /// Expression filtering the features
+    #[serde(skip_serializing_if = "Option::is_none")]
+    filter: Option<Expression<bool>>,
+    /// Whether to validate the filter. Defaults to `true`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    validate: Option<bool>,
+}
+
+impl QuerySourceFeaturesOptions {
+    /// Create new empty `QuerySourceFeaturesOptions`
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Specify the layer of the vector source to query
+    #[must_use]
+    pub fn with_source_layer(self, source_layer: impl Into<String>) -> Self {
+        Self {
+            source_layer: Some(source_layer.into()),
+            ..self
+        }
+    }
+
+    /// Specify the expression filtering the features
+    #[must_use]
+    pub fn with_filter(self, filter: impl Into<Expression<bool>>) -> Self {
+        Self {
+            filter: Some(filter.into()),
+            ..self
+        }
+    }
+
+    /// Validate the filter
+    #[must_use]
+    pub fn with_validate(self) -> Self {
+        Self {
+            validate: Some(true),
+            ..self
+        }
+    }
+
+    /// Don't validate the filter
+    #[must_use]
+    pub fn without_validate(self) -> Self {
+        Self {
+            validate: Some(false),
+            ..self
+        }
+    }
+
+    /// Try converting `QuerySourceFeaturesOptions` into the equivalent `JsValue`
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn as_js_value(&self) -> Result<JsValue, Error> {
+        self.serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn query_options_serialization() {
+        let rendered = QueryRenderedFeaturesOptions::new()
+            .with_layers(["parcels"])
+            .with_filter(Expression::<String>::get("owner").equals("Ana"))
+            .without_validate();
+        let source = QuerySourceFeaturesOptions::new().with_source_layer("cadastre");
+
+        assert_eq!(
+            serde_json::to_value(&rendered).expect("Serializing should work"),
+            json!({
+                "layers": ["parcels"],
+                "filter": ["==", ["get", "owner"], "Ana"],
+                "validate": false,
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(&source).expect("Serializing should work"),
+            json!({"sourceLayer": "cadastre"})
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn query_geometry_conversion() {
+        assert_eq!(QueryGeometry::default(), QueryGeometry::Viewport);
+        assert_eq!(
+            QueryGeometry::from([1., 2.]),
+            QueryGeometry::Point(PointLike::Array([1., 2.]))
+        );
+        assert_eq!(
+            QueryGeometry::from([[0., 0.], [10., 20.]]),
+            QueryGeometry::Box([PointLike::Array([0., 0.]), PointLike::Array([10., 20.])])
+        );
+    }
+}
@@ -0,0 +1,226 @@
+//! Rusty interface to the `RequestParameters` of the resources requested by MapLibre GL JS
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsValue;
+
+use super::Error;
+
+/// Parameters of a request for a resource made by MapLibre, such as a tile, a style or a sprite.
+///
+/// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/type-aliases/RequestParameters/>
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestParameters {
+    /// URL of the resource
+    pub url: String,
+    /// Headers sent with the request
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub headers: Option<BTreeMap<String, String>>,
+    /// HTTP method of the request. Defaults to `GET`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method: Option<RequestMethod>,
+    /// Body of the request
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// Type of data expected in the response
+    #[serde(default, rename = "type", skip_serializing_if = "Option::is_none")]
+    pub response_type: Option<ResponseType>,
+    /// Whether to send cookies and authentication headers to other origins
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub credentials: Option<RequestCredentials>,
+    /// Whether to collect the resource timing performance metrics of the request
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub collect_resource_timing: Option<bool>,
+}
+
+impl RequestParameters {
+    /// Create `RequestParameters` for the resource at the given URL
+    #[must_use]
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            headers: None,
+            method: None,
+            body: None,
+            response_type: None,
+            credentials: None,
+            collect_resource_timing: None,
+        }
+    }
+
+    /// Add the header with the given name, or replace its value if it already exists
+    #[must_use]
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers
+            .get_or_insert_with(BTreeMap::new)
+            .insert(name.into(), value.into());
+        self
+    }
+
+    /// Specify the HTTP method of the request
+    #[must_use]
+    pub fn with_method(self, method: RequestMethod) -> Self {
+        Self {
+            method: Some(method),
+            ..self
+        }
+    }
+
+    /// Specify the body of the request
+    #[must_use]
+    pub fn with_body(self, body: impl Into<String>) -> Self {
+        Self {
+            body: Some(body.into()),
+            ..self
+        }
+    }
+
+    /// Specify whether to send cookies and authentication headers to other origins
+    #[must_use]
+    pub fn with_credentials(self, credentials: RequestCredentials) -> Self {
+        Self {
+            credentials: Some(credentials),
+            ..self
+        }
+    }
+
+    /// Try converting `RequestParameters` into the equivalent `JsValue`
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn as_js_value(&self) -> Result<JsValue, Error> {
+        self.serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+    }
+}
+
+impl TryFrom<JsValue> for RequestParameters {
+    type Error = Error;
+
+    fn try_from(value: JsValue) -> Result<Self, Self::Error> {
+        serde_wasm_bindgen::from_value(value)
+    }
+}
+
+impl TryFrom<RequestParameters> for JsValue {
+    type Error = Error;
+
+    fn try_from(value: RequestParameters) -> Result<Self, Self::Error> {
+        value.as_js_value()
+    }
+}
+
+impl TryFrom<&RequestParameters> for JsValue {
+    type Error = Error;
+
+    fn try_from(value: &RequestParameters) -> Result<Self, Self::Error> {
+        value.as_js_value()
+    }
+}
+
+/// Type of a resource requested by a `Map`
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub enum ResourceType {
+    /// Style of the map
+    Style,
+    /// TileJSON resource of a source, or GeoJSON data
+    Source,
+    /// Tile of a source
+    Tile,
+    /// Range of glyphs of a font
+    Glyphs,
+    /// Image of the sprite
+    SpriteImage,
+    /// JSON index of the sprite
+    #[serde(rename = "SpriteJSON")]
+    SpriteJson,
+    /// Image of an image source or loaded with `Map::load_image`
+    Image,
+    /// Resource of any other type
+    #[serde(other)]
+    Unknown,
+}
+
+/// HTTP method of a request
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum RequestMethod {
+    /// Retrieve the resource
+    Get,
+    /// Send data to the server
+    Post,
+    /// Replace the resource with the data sent
+    Put,
+}
+
+/// Type of data expected in the response of a request
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ResponseType {
+    /// Text
+    String,
+    /// JSON parsed into an object
+    Json,
+    /// Raw bytes
+    ArrayBuffer,
+    /// Decoded image
+    Image,
+}
+
+/// Whether to send cookies and authentication headers with a request
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RequestCredentials {
+    /// Only send them to the same origin as the page
+    SameOrigin,
+    /// Send them to every origin
+    Include,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn request_parameters_serialization() {
+        let parameters = RequestParameters::new("https://example.com/style.json")
+            .with_header("Authorization", "Bearer token")
+            .with_method(RequestMethod::Post)
+            .with_credentials(RequestCredentials::SameOrigin);
+
+        assert_eq!(
+            serde_json::to_value(&parameters).expect("Serializing should work"),
+            json!({
+                "url": "https://example.com/style.json",
+                "headers": {"Authorization": "Bearer token"},
+                "method": "POST",
+                "credentials": "same-origin",
+            })
+        );
+    }
+
+    #[test]
+    fn request_parameters_deserialization() {
+        let parameters: RequestParameters = serde_json::from_value(json!({
+            "url": "app://tiles/1/0/0",
+            "type": "arrayBuffer",
+            "headers": {},
+        }))
+        .expect("Deserializing should work");
+
+        assert_eq!(parameters.response_type, Some(ResponseType::ArrayBuffer));
+        assert_eq!(parameters.headers, Some(BTreeMap::new()));
+        assert_eq!(
+            serde_json::from_value::<Vec<ResourceType>>(json!(["SpriteJSON", "Tile", "Other"]))
+                .expect("Deserializing should work"),
+            vec![
+                ResourceType::SpriteJson,
+                ResourceType::Tile,
+                ResourceType::Unknown
+            ]
+        );
+    }
+}
@@ -0,0 +1,137 @@
+//! Submodule for the `CanvasSource` wrapper and associated tests
+
+use web_sys::HtmlCanvasElement;
+
+use super::{Error, Source, image_source::corner_coordinates_to_js};
+use crate::{bindings, interface::LngLatLike};
+
+/// Source of the content of an HTML canvas placed on the map by the longitude and latitude of
+/// its corners, obtained by converting the `Source` returned by `Map::get_source`
+#[derive(Debug, Clone, PartialEq)]
+pub struct CanvasSource {
+    /// JS object that the struct wraps
+    js_value: bindings::CanvasSource,
+}
+
+impl CanvasSource {
+    /// Get the identifier of the source
+    #[must_use]
+    pub fn id(&self) -> String {
+        self.js_value.id()
+    }
+
+    /// Get the longitude and latitude of the top left, top right, bottom right and bottom left
+    /// corners of the canvas
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn coordinates(&self) -> Result<[[f64; 2]; 4], Error> {
+        serde_wasm_bindgen::from_value(self.js_value.coordinates())
+    }
+
+    /// Place the canvas on the given top left, top right, bottom right and bottom left corners
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors and errors generated from JS
+    pub fn set_coordinates(&self, coordinates: [impl Into<LngLatLike>; 4]) -> Result<(), Error> {
+        self.js_value
+            .set_coordinates(&corner_coordinates_to_js(coordinates)?)?;
+        Ok(())
+    }
+
+    /// Render the canvas on every frame
+    pub fn play(&self) {
+        self.js_value.play();
+    }
+
+    /// Stop rendering the canvas on every frame, showing a static copy of it
+    pub fn pause(&self) {
+        self.js_value.pause();
+    }
+
+    /// Get the HTML canvas element, once the source has loaded
+    #[must_use]
+    pub fn get_canvas(&self) -> Option<HtmlCanvasElement> {
+        self.js_value.get_canvas()
+    }
+
+    /// Get the underlying JS object
+    #[must_use]
+    pub fn into_inner(self) -> bindings::CanvasSource {
+        self.js_value
+    }
+}
+
+impl From<bindings::CanvasSource> for CanvasSource {
+    fn from(value: bindings::CanvasSource) -> Self {
+        Self { js_value: value }
+    }
+}
+
+impl TryFrom<Source> for CanvasSource {
+    type Error = Error;
+
+    fn try_from(value: Source) -> Result<Self, Self::Error> {
+        value
+            .downcast::<bindings::CanvasSource>("canvas")
+            .map(Into::into)
+    }
+}
+
+impl From<CanvasSource> for Source {
+    fn from(value: CanvasSource) -> Self {
+        bindings::Source::from(value.js_value).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        interface::{MapOptions, sources::CanvasSourceSpecification},
+        test_utils::{gen_html_element, gen_html_element_with_id, load_maplibre_gl},
+    };
+    use serde_json::json;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    async fn canvas_source_play_pause() {
+        load_maplibre_gl().await;
+        let _canvas = gen_html_element_with_id("canvas", "weather-canvas");
+        let html_element = gen_html_element("div").cloned_ref();
+        let map = MapOptions::new(html_element)
+            .with_style(json!({"version": 8, "sources": {}, "layers": []}))
+            .build_async()
+            .await
+            .expect("Creating a map should work");
+        map.add_source(
+            "weather",
+            &CanvasSourceSpecification::new(
+                "weather-canvas",
+                [[-10., 10.], [10., 10.], [10., -10.], [-10., -10.]],
+            )
+            .without_animate(),
+        )
+        .expect("Adding a source should work");
+        let source = CanvasSource::try_from(map.get_source("weather").expect("Should exist"))
+            .expect("The source should be a canvas source");
+
+        source.play();
+        source.pause();
+        source
+            .set_coordinates([(-20., 20.), (20., 20.), (20., -20.), (-20., -20.)])
+            .expect("Setting the coordinates should work");
+
+        assert_eq!(source.id(), "weather");
+        assert_eq!(
+            source.coordinates().expect("Should get the coordinates"),
+            [[-20., 20.], [20., 20.], [20., -20.], [-20., -20.]]
+        );
+        assert_eq!(
+            source.get_canvas().map(|canvas| canvas.id()).as_deref(),
+            Some("weather-canvas")
+        );
+    }
+}
@@ -0,0 +1,82 @@
+//! Submodule for `CanvasSourceSpecification` and associated tests
+
+use serde::{Deserialize, Serialize};
+
+use super::image_source_specification::corner_coordinates;
+use crate::interface::LngLatLike;
+
+/// Specification of a source of the content of an HTML canvas placed on the map by the
+/// longitude and latitude of its corners.
+///
+/// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/type-aliases/CanvasSourceSpecification/>
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(remote = "Self", tag = "type", rename = "canvas", deny_unknown_fields)]
+pub struct CanvasSourceSpecification {
+    /// Identifier of the canvas element
+    pub canvas: String,
+    /// Longitude and latitude of the top left, top right, bottom right and bottom left corners
+    /// of the canvas
+    pub coordinates: [[f64; 2]; 4],
+    /// Whether the canvas is animated, rendering it on every frame. Defaults to `true`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub animate: Option<bool>,
+}
+
+impl_source_specification_serde!(CanvasSourceSpecification, "canvas");
+
+impl CanvasSourceSpecification {
+    /// Create a `CanvasSourceSpecification` with the canvas with the given identifier placed on
+    /// the given top left, top right, bottom right and bottom left corners
+    #[must_use]
+    pub fn new(canvas: impl Into<String>, coordinates: [impl Into<LngLatLike>; 4]) -> Self {
+        Self {
+            canvas: canvas.into(),
+            coordinates: corner_coordinates(coordinates),
+            animate: None,
+        }
+    }
+
+    /// Render the canvas on every frame
+    #[must_use]
+    pub fn with_animate(self) -> Self {
+        Self {
+            animate: Some(true),
+            ..self
+        }
+    }
+
+    /// Render a static copy of the canvas
+    #[must_use]
+    pub fn without_animate(self) -> Self {
+        Self {
+            animate: Some(false),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn canvas_source_specification_serialization() {
+        let source = CanvasSourceSpecification::new(
+            "weather-canvas",
+            [[-10., 10.], [10., 10.], [10., -10.], [-10., -10.]],
+        )
+        .without_animate();
+
+        assert_eq!(
+            serde_json::to_value(&source).expect("Serializing should work"),
+            json!({
+                "type": "canvas",
+                "canvas": "weather-canvas",
+                "coordinates": [[-10.0, 10.0], [10.0, 10.0], [10.0, -10.0], [-10.0, -10.0]],
+                "animate": false,
+            })
+        );
+    }
+}
@@ -0,0 +1,50 @@
+//! Submodule for `GeoJSONFeature` and associated tests
+
+use serde::{Deserialize, Serialize};
+
+use super::GeoJSONFeatureId;
+
+/// GeoJSON feature with properties of type `P`, as returned by the queries of a
+/// `GeoJSONSource`.
+///
+/// The properties of the features returned by the cluster queries include `cluster`,
+/// `cluster_id`, `point_count` and `point_count_abbreviated` when they are clusters.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename = "Feature")]
+pub struct GeoJSONFeature<P = serde_json::Value> {
+    /// Identifier of the feature, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<GeoJSONFeatureId>,
+    /// GeoJSON geometry of the feature
+    pub geometry: serde_json::Value,
+    /// Properties of the feature
+    pub properties: P,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn geojson_feature_deserialization() {
+        /// Properties of the features of the test
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Vehicle {
+            /// Name of the vehicle
+            name: String,
+        }
+
+        let feature: GeoJSONFeature<Vehicle> = serde_json::from_value(json!({
+            "type": "Feature",
+            "id": 7,
+            "geometry": {"type": "Point", "coordinates": [1, 2]},
+            "properties": {"name": "bus"},
+        }))
+        .expect("Deserializing should work");
+
+        assert_eq!(feature.id, Some(GeoJSONFeatureId::Number(7)));
+        assert_eq!(feature.geometry["coordinates"], json!([1, 2]));
+        assert_eq!(feature.properties.name, "bus");
+    }
+}
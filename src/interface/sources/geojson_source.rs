@@ -0,0 +1,295 @@
+//! Submodule for the `GeoJSONSource` wrapper and associated tests
+
+use serde::{Serialize, de::DeserializeOwned};
+
+use super::{Error, GeoJSONFeature, GeoJSONSourceDiff, Source, SourceQuery};
+use crate::bindings;
+
+/// Source of GeoJSON data of the style of a `Map`, obtained by converting the `Source` returned
+/// by `Map::get_source`
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeoJSONSource {
+    /// JS object that the struct wraps
+    js_value: bindings::GeoJSONSource,
+}
+
+impl GeoJSONSource {
+    /// Get the identifier of the source
+    #[must_use]
+    pub fn id(&self) -> String {
+        self.js_value.id()
+    }
+
+    /// Replace the data of the source with the given GeoJSON object or URL of a GeoJSON file
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors and errors generated from JS
+    pub fn set_data(&self, data: &impl Serialize) -> Result<(), Error> {
+        let data = data.serialize(&serde_wasm_bindgen::Serializer::json_compatible())?;
+        self.js_value.set_data(&data)?;
+        Ok(())
+    }
+
+    /// Add, remove and update features of the source without sending the whole data again.
+    /// The features must have identifiers, either their own or set with `promoteId`
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors and errors generated from JS
+    pub fn update_data(&self, diff: &GeoJSONSourceDiff) -> Result<(), Error> {
+        self.js_value.update_data(&diff.as_js_value()?)?;
+        Ok(())
+    }
+
+    /// Get the GeoJSON data of the source once any pending update has been applied
+    pub fn get_data<T: DeserializeOwned>(&self) -> SourceQuery<T> {
+        SourceQuery::new(self.js_value.get_data())
+    }
+
+    /// Get the zoom level at which the cluster with the given identifier expands into several
+    /// children
+    pub fn get_cluster_expansion_zoom(&self, cluster_id: f64) -> SourceQuery<f64> {
+        SourceQuery::new(self.js_value.get_cluster_expansion_zoom(cluster_id))
+    }
+
+    /// Get the children of the cluster with the given identifier on the next zoom level, which
+    /// may be clusters or points
+    pub fn get_cluster_children<P: DeserializeOwned>(
+        &self,
+        cluster_id: f64,
+    ) -> SourceQuery<Vec<GeoJSONFeature<P>>> {
+        SourceQuery::new(self.js_value.get_cluster_children(cluster_id))
+    }
+
+    /// Get at most `limit` points of the cluster with the given identifier, skipping the first
+    /// `offset` ones
+    pub fn get_cluster_leaves<P: DeserializeOwned>(
+        &self,
+        cluster_id: f64,
+        limit: u32,
+        offset: u32,
+    ) -> SourceQuery<Vec<GeoJSONFeature<P>>> {
+        SourceQuery::new(self.js_value.get_cluster_leaves(cluster_id, limit, offset))
+    }
+
+    /// Get the underlying JS object
+    #[must_use]
+    pub fn into_inner(self) -> bindings::GeoJSONSource {
+        self.js_value
+    }
+}
+
+impl From<bindings::GeoJSONSource> for GeoJSONSource {
+    fn from(value: bindings::GeoJSONSource) -> Self {
+        Self { js_value: value }
+    }
+}
+
+impl TryFrom<Source> for GeoJSONSource {
+    type Error = Error;
+
+    fn try_from(value: Source) -> Result<Self, Self::Error> {
+        value
+            .downcast::<bindings::GeoJSONSource>("geojson")
+            .map(Into::into)
+    }
+}
+
+impl From<GeoJSONSource> for Source {
+    fn from(value: GeoJSONSource) -> Self {
+        bindings::Source::from(value.js_value).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        interface::{
+            MapGeoJsonFeature, MapOptions,
+            query_options::QuerySourceFeaturesOptions,
+            sources::{GeoJSONFeatureDiff, GeoJSONSourceSpecification},
+        },
+        test_utils::{gen_html_element, gen_sized_container, load_maplibre_gl},
+    };
+    use serde_json::{Value, json};
+    use wasm_bindgen_futures::JsFuture;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    async fn geojson_source_set_update_get_data() {
+        load_maplibre_gl().await;
+        let html_element = gen_html_element("div").cloned_ref();
+        let map = MapOptions::new(html_element)
+            .with_style(json!({"version": 8, "sources": {}, "layers": []}))
+            .build_async()
+            .await
+            .expect("Creating a map should work");
+        map.add_source(
+            "vehicles",
+            &GeoJSONSourceSpecification::new(json!({"type": "FeatureCollection", "features": []})),
+        )
+        .expect("Adding a source should work");
+        let source = GeoJSONSource::try_from(map.get_source("vehicles").expect("Should exist"))
+            .expect("The source should be a GeoJSON source");
+
+        source
+            .set_data(&json!({"type": "FeatureCollection", "features": [{
+                "type": "Feature",
+                "id": 1,
+                "geometry": {"type": "Point", "coordinates": [0, 0]},
+                "properties": {"speed": 10},
+            }]}))
+            .expect("Setting the data should work");
+        source
+            .update_data(
+                &GeoJSONSourceDiff::new()
+                    .with_update([GeoJSONFeatureDiff::new(1).with_property("speed", 20)]),
+            )
+            .expect("Updating the data should work");
+        let data: Value = source
+            .get_data()
+            .await
+            .expect("Getting the data should work");
+
+        assert_eq!(source.id(), "vehicles");
+        assert_eq!(data["features"][0]["properties"]["speed"], json!(20));
+    }
+
+    #[wasm_bindgen_test]
+    async fn geojson_source_from_other_source() {
+        load_maplibre_gl().await;
+        let html_element = gen_html_element("div").cloned_ref();
+        let map = MapOptions::new(html_element)
+            .with_style(json!({"version": 8, "sources": {}, "layers": []}))
+            .build_async()
+            .await
+            .expect("Creating a map should work");
+        map.add_source(
+            "satellite",
+            &json!({"type": "raster", "tiles": ["https://example.com/{z}/{x}/{y}.png"]}),
+        )
+        .expect("Adding a source should work");
+
+        assert!(
+            GeoJSONSource::try_from(map.get_source("satellite").expect("Should exist")).is_err()
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn geojson_source_unknown_cluster() {
+        load_maplibre_gl().await;
+        let html_element = gen_html_element("div").cloned_ref();
+        let map = MapOptions::new(html_element)
+            .with_style(json!({"version": 8, "sources": {}, "layers": []}))
+            .build_async()
+            .await
+            .expect("Creating a map should work");
+        let points: Vec<Value> = (0..4)
+            .map(|index| {
+                json!({
+                    "type": "Feature",
+                    "geometry": {"type": "Point", "coordinates": [0.001 * f64::from(index), 0]},
+                    "properties": {"index": index},
+                })
+            })
+            .collect();
+        map.add_source(
+            "incidents",
+            &GeoJSONSourceSpecification::new(
+                json!({"type": "FeatureCollection", "features": points}),
+            )
+            .with_cluster()
+            .with_cluster_max_zoom(14),
+        )
+        .expect("Adding a source should work");
+        let source = GeoJSONSource::try_from(map.get_source("incidents").expect("Should exist"))
+            .expect("The source should be a GeoJSON source");
+
+        let children: Result<Vec<GeoJSONFeature>, _> = source.get_cluster_children(-1.).await;
+        let leaves: Result<Vec<GeoJSONFeature>, _> = source.get_cluster_leaves(-1., 10, 0).await;
+
+        assert!(source.get_cluster_expansion_zoom(-1.).await.is_err());
+        assert!(children.is_err());
+        assert!(leaves.is_err());
+    }
+
+    #[wasm_bindgen_test]
+    async fn geojson_source_cluster_queries() {
+        load_maplibre_gl().await;
+        let container = gen_sized_container(400, 300);
+        let map = MapOptions::new(container.cloned_ref())
+            .with_style(json!({"version": 8, "sources": {}, "layers": []}))
+            .with_center([0., 0.])
+            .with_zoom(0.)
+            .build_async()
+            .await
+            .expect("Creating a map should work");
+        let points: Vec<Value> = (0..4)
+            .map(|index| {
+                json!({
+                    "type": "Feature",
+                    "geometry": {"type": "Point", "coordinates": [0.001 * f64::from(index), 0]},
+                    "properties": {"index": index},
+                })
+            })
+            .collect();
+        map.add_source(
+            "incidents",
+            &GeoJSONSourceSpecification::new(
+                json!({"type": "FeatureCollection", "features": points}),
+            )
+            .with_cluster()
+            .with_cluster_max_zoom(14),
+        )
+        .expect("Adding a source should work");
+        map.add_layer(
+            &json!({"id": "incidents", "type": "circle", "source": "incidents"}),
+            None,
+        )
+        .expect("Adding a layer should work");
+        JsFuture::from(map.clone().into_inner().once_promise("idle"))
+            .await
+            .expect("The map should become idle");
+        let source = GeoJSONSource::try_from(map.get_source("incidents").expect("Should exist"))
+            .expect("The source should be a GeoJSON source");
+
+        let clusters: Vec<MapGeoJsonFeature> = map
+            .query_source_features("incidents", &QuerySourceFeaturesOptions::new())
+            .expect("Querying the source should work");
+        let cluster_id = clusters
+            .iter()
+            .find_map(|feature| feature.properties["cluster_id"].as_f64())
+            .expect("The points should be clustered");
+        let expansion_zoom = source
+            .get_cluster_expansion_zoom(cluster_id)
+            .await
+            .expect("Getting the expansion zoom should work");
+        let children: Vec<GeoJSONFeature> = source
+            .get_cluster_children(cluster_id)
+            .await
+            .expect("Getting the children should work");
+        let leaves: Vec<GeoJSONFeature> = source
+            .get_cluster_leaves(cluster_id, 10, 0)
+            .await
+            .expect("Getting the leaves should work");
+        let mut indices: Vec<i64> = leaves
+            .iter()
+            .filter_map(|leaf| leaf.properties["index"].as_i64())
+            .collect();
+        indices.sort_unstable();
+
+        assert!(expansion_zoom > 0.);
+        assert_ne!(children.len(), 0);
+        assert_eq!(indices, vec![0, 1, 2, 3]);
+        assert_eq!(
+            source
+                .get_cluster_leaves::<Value>(cluster_id, 2, 1)
+                .await
+                .expect("Getting a page of leaves should work")
+                .len(),
+            2
+        );
+    }
+}
@@ -0,0 +1,261 @@
+//! Submodule for `GeoJSONSourceDiff` and associated types and tests
+
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsValue;
+
+use super::Error;
+
+/// Identifier of a feature of a GeoJSON source, either given by the feature itself, generated
+/// with `generateId` or promoted from one of its properties with `promoteId`
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GeoJSONFeatureId {
+    /// Numeric identifier
+    Number(i64),
+    /// String identifier
+    String(String),
+}
+
+impl Display for GeoJSONFeatureId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Number(id) => write!(f, "{id}"),
+            Self::String(id) => write!(f, "{id}"),
+        }
+    }
+}
+
+impl From<i64> for GeoJSONFeatureId {
+    fn from(value: i64) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl From<&str> for GeoJSONFeatureId {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+impl From<String> for GeoJSONFeatureId {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+/// Incremental change of the features of a GeoJSON source, applied with
+/// `GeoJSONSource::update_data` without sending the whole data again. The features must have
+/// identifiers for the diff to apply to them.
+///
+/// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/type-aliases/GeoJSONSourceDiff/>
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GeoJSONSourceDiff {
+    /// Whether to remove every feature before applying the rest of the diff
+    #[serde(skip_serializing_if = "Option::is_none")]
+    remove_all: Option<bool>,
+    /// Identifiers of the features to remove
+    #[serde(skip_serializing_if = "Option::is_none")]
+    remove: Option<Vec<GeoJSONFeatureId>>,
+    /// GeoJSON features to add
+    #[serde(skip_serializing_if = "Option::is_none")]
+    add: Option<Vec<serde_json::Value>>,
+    /// Changes of the existing features
+    #[serde(skip_serializing_if = "Option::is_none")]
+    update: Option<Vec<GeoJSONFeatureDiff>>,
+}
+
+impl GeoJSONSourceDiff {
+    /// Create an empty `GeoJSONSourceDiff`
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remove every feature before applying the rest of the diff
+    #[must_use]
+    pub fn with_remove_all(self) -> Self {
+        Self {
+            remove_all: Some(true),
+            ..self
+        }
+    }
+
+    /// Remove the features with the given identifiers
+    #[must_use]
+    pub fn with_remove(self, ids: impl IntoIterator<Item = impl Into<GeoJSONFeatureId>>) -> Self {
+        Self {
+            remove: Some(ids.into_iter().map(Into::into).collect()),
+            ..self
+        }
+    }
+
+    /// Add the given GeoJSON features, replacing existing features with the same identifier
+    #[must_use]
+    pub fn with_add(self, features: impl IntoIterator<Item = serde_json::Value>) -> Self {
+        Self {
+            add: Some(features.into_iter().collect()),
+            ..self
+        }
+    }
+
+    /// Apply the given changes to existing features
+    #[must_use]
+    pub fn with_update(self, diffs: impl IntoIterator<Item = GeoJSONFeatureDiff>) -> Self {
+        Self {
+            update: Some(diffs.into_iter().collect()),
+            ..self
+        }
+    }
+
+    /// Try converting `GeoJSONSourceDiff` into the equivalent `JsValue`
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn as_js_value(&self) -> Result<JsValue, Error> {
+        self.serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+    }
+}
+
+/// Change of the geometry and properties of an existing feature of a GeoJSON source.
+///
+/// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/type-aliases/GeoJSONFeatureDiff/>
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GeoJSONFeatureDiff {
+    /// Identifier of the feature to change
+    id: GeoJSONFeatureId,
+    /// GeoJSON geometry replacing the one of the feature
+    #[serde(skip_serializing_if = "Option::is_none")]
+    new_geometry: Option<serde_json::Value>,
+    /// Whether to remove every property before applying the rest of the changes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    remove_all_properties: Option<bool>,
+    /// Names of the properties to remove
+    #[serde(skip_serializing_if = "Option::is_none")]
+    remove_properties: Option<Vec<String>>,
+    /// Properties to add or replace
+    #[serde(skip_serializing_if = "Option::is_none")]
+    add_or_update_properties: Option<Vec<PropertyUpdate>>,
+}
+
+/// Property added or replaced by a `GeoJSONFeatureDiff`
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+struct PropertyUpdate {
+    /// Name of the property
+    key: String,
+    /// New value of the property
+    value: serde_json::Value,
+}
+
+impl GeoJSONFeatureDiff {
+    /// Create a `GeoJSONFeatureDiff` without changes of the feature with the given identifier
+    #[must_use]
+    pub fn new(id: impl Into<GeoJSONFeatureId>) -> Self {
+        Self {
+            id: id.into(),
+            new_geometry: None,
+            remove_all_properties: None,
+            remove_properties: None,
+            add_or_update_properties: None,
+        }
+    }
+
+    /// Replace the geometry of the feature with the given GeoJSON geometry
+    #[must_use]
+    pub fn with_new_geometry(self, geometry: serde_json::Value) -> Self {
+        Self {
+            new_geometry: Some(geometry),
+            ..self
+        }
+    }
+
+    /// Remove every property before applying the rest of the changes
+    #[must_use]
+    pub fn with_remove_all_properties(self) -> Self {
+        Self {
+            remove_all_properties: Some(true),
+            ..self
+        }
+    }
+
+    /// Remove the properties with the given names
+    #[must_use]
+    pub fn with_remove_properties(self, keys: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            remove_properties: Some(keys.into_iter().map(Into::into).collect()),
+            ..self
+        }
+    }
+
+    /// Add the property with the given name, or replace its value if it already exists
+    #[must_use]
+    pub fn with_property(
+        mut self,
+        key: impl Into<String>,
+        value: impl Into<serde_json::Value>,
+    ) -> Self {
+        self.add_or_update_properties
+            .get_or_insert_with(Vec::new)
+            .push(PropertyUpdate {
+                key: key.into(),
+                value: value.into(),
+            });
+        self
+    }
+
+    /// Get the identifier of the feature to change
+    #[must_use]
+    pub const fn id(&self) -> &GeoJSONFeatureId {
+        &self.id
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn geojson_source_diff_serialization() {
+        let diff = GeoJSONSourceDiff::new()
+            .with_remove([1, 2])
+            .with_add([json!({
+                "type": "Feature",
+                "id": 3,
+                "geometry": {"type": "Point", "coordinates": [0, 0]},
+                "properties": {},
+            })])
+            .with_update([GeoJSONFeatureDiff::new("bus-4")
+                .with_new_geometry(json!({"type": "Point", "coordinates": [1, 1]}))
+                .with_remove_properties(["delayed"])
+                .with_property("speed", 42)]);
+
+        assert_eq!(
+            serde_json::to_value(&diff).expect("Serializing should work"),
+            json!({
+                "remove": [1, 2],
+                "add": [{
+                    "type": "Feature",
+                    "id": 3,
+                    "geometry": {"type": "Point", "coordinates": [0, 0]},
+                    "properties": {},
+                }],
+                "update": [{
+                    "id": "bus-4",
+                    "newGeometry": {"type": "Point", "coordinates": [1, 1]},
+                    "removeProperties": ["delayed"],
+                    "addOrUpdateProperties": [{"key": "speed", "value": 42}],
+                }],
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(GeoJSONSourceDiff::new().with_remove_all())
+                .expect("Serializing should work"),
+            json!({"removeAll": true})
+        );
+    }
+}
@@ -0,0 +1,318 @@
+//! Submodule for `GeoJSONSourceSpecification` and associated tests
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsValue;
+
+use super::Error;
+use crate::interface::style::Expression;
+
+/// Specification of a source of GeoJSON data, given either inline or as the URL of a GeoJSON
+/// file.
+///
+/// MapLibre docs: <https://maplibre.org/maplibre-style-spec/sources/#geojson>
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(
+    remote = "Self",
+    tag = "type",
+    rename = "geojson",
+    deny_unknown_fields,
+    rename_all = "camelCase"
+)]
+pub struct GeoJSONSourceSpecification {
+    /// GeoJSON object or URL of a GeoJSON file
+    pub data: serde_json::Value,
+    /// Maximum zoom level at which to create vector tiles. Defaults to `18`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maxzoom: Option<f64>,
+    /// Attribution displayed when the map shows data from the source
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attribution: Option<String>,
+    /// Size of the tile buffer on each side, between `0` and `512`. Defaults to `128`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub buffer: Option<u32>,
+    /// Expression filtering the features before they are tiled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<Expression<bool>>,
+    /// Douglas-Peucker simplification tolerance, higher means simpler geometries. Defaults to
+    /// `0.375`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tolerance: Option<f64>,
+    /// Whether to cluster the point features. Defaults to `false`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cluster: Option<bool>,
+    /// Radius of each cluster in pixels. Defaults to `50`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cluster_radius: Option<f64>,
+    /// Maximum zoom level at which to cluster points. Defaults to one zoom level less than
+    /// `maxzoom`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cluster_max_zoom: Option<f64>,
+    /// Minimum number of points to form a cluster. Defaults to `2`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cluster_min_points: Option<u32>,
+    /// Custom properties aggregated on the clusters, as an object mapping each property name to
+    /// an array with a reduce expression and a map expression
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cluster_properties: Option<serde_json::Value>,
+    /// Whether to calculate line distance metrics, required by `line-gradient`. Defaults to
+    /// `false`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_metrics: Option<bool>,
+    /// Whether to generate the identifiers of the features from their index, overriding any
+    /// existing identifier. Defaults to `false`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generate_id: Option<bool>,
+    /// Name of the property to use as identifier of the features
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub promote_id: Option<String>,
+}
+
+impl_source_specification_serde!(GeoJSONSourceSpecification, "geojson");
+
+impl GeoJSONSourceSpecification {
+    /// Create a `GeoJSONSourceSpecification` with the given GeoJSON object or URL
+    #[must_use]
+    pub fn new(data: impl Into<serde_json::Value>) -> Self {
+        Self {
+            data: data.into(),
+            maxzoom: None,
+            attribution: None,
+            buffer: None,
+            filter: None,
+            tolerance: None,
+            cluster: None,
+            cluster_radius: None,
+            cluster_max_zoom: None,
+            cluster_min_points: None,
+            cluster_properties: None,
+            line_metrics: None,
+            generate_id: None,
+            promote_id: None,
+        }
+    }
+
+    /// Specify the maximum zoom level at which to create vector tiles
+    #[must_use]
+    pub fn with_maxzoom(self, maxzoom: impl Into<f64>) -> Self {
+        Self {
+            maxzoom: Some(maxzoom.into()),
+            ..self
+        }
+    }
+
+    /// Specify the attribution displayed when the map shows data from the source
+    #[must_use]
+    pub fn with_attribution(self, attribution: impl Into<String>) -> Self {
+        Self {
+            attribution: Some(attribution.into()),
+            ..self
+        }
+    }
+
+    /// Specify the size of the tile buffer on each side
+    #[must_use]
+    pub fn with_buffer(self, buffer: u32) -> Self {
+        Self {
+            buffer: Some(buffer),
+            ..self
+        }
+    }
+
+    /// Specify the expression filtering the features before they are tiled
+    #[must_use]
+    pub fn with_filter(self, filter: impl Into<Expression<bool>>) -> Self {
+        Self {
+            filter: Some(filter.into()),
+            ..self
+        }
+    }
+
+    /// Specify the simplification tolerance
+    #[must_use]
+    pub fn with_tolerance(self, tolerance: impl Into<f64>) -> Self {
+        Self {
+            tolerance: Some(tolerance.into()),
+            ..self
+        }
+    }
+
+    /// Cluster the point features
+    #[must_use]
+    pub fn with_cluster(self) -> Self {
+        Self {
+            cluster: Some(true),
+            ..self
+        }
+    }
+
+    /// Don't cluster the point features
+    #[must_use]
+    pub fn without_cluster(self) -> Self {
+        Self {
+            cluster: Some(false),
+            ..self
+        }
+    }
+
+    /// Specify the radius of each cluster in pixels
+    #[must_use]
+    pub fn with_cluster_radius(self, cluster_radius: impl Into<f64>) -> Self {
+        Self {
+            cluster_radius: Some(cluster_radius.into()),
+            ..self
+        }
+    }
+
+    /// Specify the maximum zoom level at which to cluster points
+    #[must_use]
+    pub fn with_cluster_max_zoom(self, cluster_max_zoom: impl Into<f64>) -> Self {
+        Self {
+            cluster_max_zoom: Some(cluster_max_zoom.into()),
+            ..self
+        }
+    }
+
+    /// Specify the minimum number of points to form a cluster
+    #[must_use]
+    pub fn with_cluster_min_points(self, cluster_min_points: u32) -> Self {
+        Self {
+            cluster_min_points: Some(cluster_min_points),
+            ..self
+        }
+    }
+
+    /// Specify the custom properties aggregated on the clusters
+    #[must_use]
+    pub fn with_cluster_properties(self, cluster_properties: serde_json::Value) -> Self {
+        Self {
+            cluster_properties: Some(cluster_properties),
+            ..self
+        }
+    }
+
+    /// Calculate line distance metrics
+    #[must_use]
+    pub fn with_line_metrics(self) -> Self {
+        Self {
+            line_metrics: Some(true),
+            ..self
+        }
+    }
+
+    /// Don't calculate line distance metrics
+    #[must_use]
+    pub fn without_line_metrics(self) -> Self {
+        Self {
+            line_metrics: Some(false),
+            ..self
+        }
+    }
+
+    /// Generate the identifiers of the features from their index
+    #[must_use]
+    pub fn with_generate_id(self) -> Self {
+        Self {
+            generate_id: Some(true),
+            ..self
+        }
+    }
+
+    /// Don't generate the identifiers of the features
+    #[must_use]
+    pub fn without_generate_id(self) -> Self {
+        Self {
+            generate_id: Some(false),
+            ..self
+        }
+    }
+
+    /// Specify the name of the property to use as identifier of the features
+    #[must_use]
+    pub fn with_promote_id(self, promote_id: impl Into<String>) -> Self {
+        Self {
+            promote_id: Some(promote_id.into()),
+            ..self
+        }
+    }
+
+    /// Try converting `GeoJSONSourceSpecification` into the equivalent `JsValue`
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn as_js_value(&self) -> Result<JsValue, Error> {
+        self.serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+    }
+}
+
+impl TryFrom<JsValue> for GeoJSONSourceSpecification {
+    type Error = Error;
+
+    fn try_from(value: JsValue) -> Result<Self, Self::Error> {
+        serde_wasm_bindgen::from_value(value)
+    }
+}
+
+impl TryFrom<GeoJSONSourceSpecification> for JsValue {
+    type Error = Error;
+
+    fn try_from(value: GeoJSONSourceSpecification) -> Result<Self, Self::Error> {
+        value.as_js_value()
+    }
+}
+
+impl TryFrom<&GeoJSONSourceSpecification> for JsValue {
+    type Error = Error;
+
+    fn try_from(value: &GeoJSONSourceSpecification) -> Result<Self, Self::Error> {
+        value.as_js_value()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+    use wasm_bindgen_test::*;
+
+    #[test]
+    fn geojson_source_specification_serialization() {
+        let specification = GeoJSONSourceSpecification::new("https://example.com/vehicles.json")
+            .with_cluster()
+            .with_cluster_radius(40)
+            .with_cluster_max_zoom(14)
+            .with_tolerance(0.5)
+            .with_buffer(64)
+            .with_line_metrics()
+            .with_promote_id("vehicle_id");
+
+        assert_eq!(
+            serde_json::to_value(&specification).expect("Serializing should work"),
+            json!({
+                "type": "geojson",
+                "data": "https://example.com/vehicles.json",
+                "buffer": 64,
+                "tolerance": 0.5,
+                "cluster": true,
+                "clusterRadius": 40.0,
+                "clusterMaxZoom": 14.0,
+                "lineMetrics": true,
+                "promoteId": "vehicle_id",
+            })
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn geojson_source_specification_js_round_trip() {
+        let specification =
+            GeoJSONSourceSpecification::new(json!({"type": "FeatureCollection", "features": []}))
+                .with_generate_id();
+        let js_value = specification
+            .as_js_value()
+            .expect("Should be able to convert to JsValue");
+        let retrieved =
+            GeoJSONSourceSpecification::try_from(js_value).expect("Should be able to back convert");
+
+        assert_eq!(retrieved, specification);
+    }
+}
@@ -0,0 +1,234 @@
+//! Submodule for the `ImageSource` wrapper and associated tests
+
+use serde::Serialize;
+use wasm_bindgen::JsValue;
+
+use super::{Error, Source, image_source_specification::corner_coordinates};
+use crate::{bindings, interface::LngLatLike};
+
+/// Source of an image placed on the map by the longitude and latitude of its corners, obtained
+/// by converting the `Source` returned by `Map::get_source`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageSource {
+    /// JS object that the struct wraps
+    js_value: bindings::ImageSource,
+}
+
+/// Options of `ImageSource::update_image`
+#[derive(Serialize)]
+struct UpdateImageOptions {
+    /// URL of the new image
+    url: String,
+    /// New corners of the image, keeping the current ones if not given
+    #[serde(skip_serializing_if = "Option::is_none")]
+    coordinates: Option<[[f64; 2]; 4]>,
+}
+
+impl ImageSource {
+    /// Get the identifier of the source
+    #[must_use]
+    pub fn id(&self) -> String {
+        self.js_value.id()
+    }
+
+    /// Get the URL of the image
+    #[must_use]
+    pub fn url(&self) -> Option<String> {
+        self.js_value.url()
+    }
+
+    /// Get the longitude and latitude of the top left, top right, bottom right and bottom left
+    /// corners of the image
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn coordinates(&self) -> Result<[[f64; 2]; 4], Error> {
+        serde_wasm_bindgen::from_value(self.js_value.coordinates())
+    }
+
+    /// Place the image on the given top left, top right, bottom right and bottom left corners
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors and errors generated from JS
+    pub fn set_coordinates(&self, coordinates: [impl Into<LngLatLike>; 4]) -> Result<(), Error> {
+        self.js_value
+            .set_coordinates(&corner_coordinates_to_js(coordinates)?)?;
+        Ok(())
+    }
+
+    /// Replace the image with the one at the given URL and, if given, place it on new corners
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors and errors generated from JS
+    pub fn update_image(
+        &self,
+        url: impl Into<String>,
+        coordinates: Option<[impl Into<LngLatLike>; 4]>,
+    ) -> Result<(), Error> {
+        let options = UpdateImageOptions {
+            url: url.into(),
+            coordinates: coordinates.map(corner_coordinates),
+        };
+        self.js_value.update_image(
+            &options.serialize(&serde_wasm_bindgen::Serializer::json_compatible())?,
+        )?;
+        Ok(())
+    }
+
+    /// Get the underlying JS object
+    #[must_use]
+    pub fn into_inner(self) -> bindings::ImageSource {
+        self.js_value
+    }
+}
+
+/// Convert the four corners of an image, video or canvas source into the equivalent `JsValue`
+pub(super) fn corner_coordinates_to_js(
+    coordinates: [impl Into<LngLatLike>; 4],
+) -> Result<JsValue, Error> {
+    corner_coordinates(coordinates).serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+}
+
+impl From<bindings::ImageSource> for ImageSource {
+    fn from(value: bindings::ImageSource) -> Self {
+        Self { js_value: value }
+    }
+}
+
+impl TryFrom<Source> for ImageSource {
+    type Error = Error;
+
+    fn try_from(value: Source) -> Result<Self, Self::Error> {
+        value
+            .downcast::<bindings::ImageSource>("image")
+            .map(Into::into)
+    }
+}
+
+impl From<ImageSource> for Source {
+    fn from(value: ImageSource) -> Self {
+        bindings::Source::from(value.js_value).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        interface::{MapOptions, sources::ImageSourceSpecification},
+        test_utils::{gen_html_element, gen_sized_container, load_maplibre_gl},
+    };
+    use serde_json::json;
+    use wasm_bindgen_futures::JsFuture;
+    use wasm_bindgen_test::*;
+
+    /// Image of a single transparent pixel, encoded as a PNG data URL
+    const PIXEL_URL: &str = "data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAQAAAC1HAwCAAAAC0lEQVR42mNkYAAAAAYAAjCB0C8AAAAASUVORK5CYII=";
+
+    #[wasm_bindgen_test]
+    async fn image_source_set_coordinates() {
+        load_maplibre_gl().await;
+        let html_element = gen_html_element("div").cloned_ref();
+        let map = MapOptions::new(html_element)
+            .with_style(json!({"version": 8, "sources": {}, "layers": []}))
+            .build_async()
+            .await
+            .expect("Creating a map should work");
+        map.add_source(
+            "orthophoto",
+            &ImageSourceSpecification::new(
+                "https://example.com/orthophoto.png",
+                [[-80.4, 37.9], [-71.5, 37.9], [-71.5, 34.8], [-80.4, 34.8]],
+            ),
+        )
+        .expect("Adding a source should work");
+        let source = ImageSource::try_from(map.get_source("orthophoto").expect("Should exist"))
+            .expect("The source should be an image source");
+
+        source
+            .set_coordinates([(-10., 10.), (10., 10.), (10., -10.), (-10., -10.)])
+            .expect("Setting the coordinates should work");
+
+        assert_eq!(source.id(), "orthophoto");
+        assert_eq!(
+            source.url().as_deref(),
+            Some("https://example.com/orthophoto.png")
+        );
+        assert_eq!(
+            source.coordinates().expect("Should get the coordinates"),
+            [[-10., 10.], [10., 10.], [10., -10.], [-10., -10.]]
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn image_source_from_other_source() {
+        load_maplibre_gl().await;
+        let html_element = gen_html_element("div").cloned_ref();
+        let map = MapOptions::new(html_element)
+            .with_style(json!({"version": 8, "sources": {}, "layers": []}))
+            .build_async()
+            .await
+            .expect("Creating a map should work");
+        map.add_source(
+            "vehicles",
+            &json!({"type": "geojson", "data": {"type": "FeatureCollection", "features": []}}),
+        )
+        .expect("Adding a source should work");
+
+        assert!(ImageSource::try_from(map.get_source("vehicles").expect("Should exist")).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    async fn image_source_update_image() {
+        load_maplibre_gl().await;
+        let container = gen_sized_container(400, 300);
+        let map = MapOptions::new(container.cloned_ref())
+            .with_style(json!({"version": 8, "sources": {}, "layers": []}))
+            .build_async()
+            .await
+            .expect("Creating a map should work");
+        map.add_source(
+            "orthophoto",
+            &ImageSourceSpecification::new(
+                PIXEL_URL,
+                [[-80.4, 37.9], [-71.5, 37.9], [-71.5, 34.8], [-80.4, 34.8]],
+            ),
+        )
+        .expect("Adding a source should work");
+        let source = ImageSource::try_from(map.get_source("orthophoto").expect("Should exist"))
+            .expect("The source should be an image source");
+        let updated_url = format!("{PIXEL_URL}#updated");
+
+        source
+            .update_image(
+                updated_url.clone(),
+                Some([(-10., 10.), (10., 10.), (10., -10.), (-10., -10.)]),
+            )
+            .expect("Updating the image should work");
+        JsFuture::from(map.clone().into_inner().once_promise("idle"))
+            .await
+            .expect("The map should become idle");
+
+        assert_eq!(source.url(), Some(updated_url));
+        assert_eq!(
+            source.coordinates().expect("Should get the coordinates"),
+            [[-10., 10.], [10., 10.], [10., -10.], [-10., -10.]]
+        );
+
+        source
+            .update_image(PIXEL_URL, None::<[LngLatLike; 4]>)
+            .expect("Updating only the image should work");
+        JsFuture::from(map.clone().into_inner().once_promise("idle"))
+            .await
+            .expect("The map should become idle");
+
+        assert_eq!(source.url().as_deref(), Some(PIXEL_URL));
+        assert_eq!(
+            source.coordinates().expect("Should get the coordinates"),
+            [[-10., 10.], [10., 10.], [10., -10.], [-10., -10.]]
+        );
+    }
+}
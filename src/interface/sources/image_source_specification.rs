@@ -0,0 +1,66 @@
+//! Submodule for `ImageSourceSpecification` and associated tests
+
+use serde::{Deserialize, Serialize};
+
+use crate::interface::LngLatLike;
+
+/// Specification of a source of an image placed on the map by the longitude and latitude of its
+/// corners.
+///
+/// MapLibre docs: <https://maplibre.org/maplibre-style-spec/sources/#image>
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(remote = "Self", tag = "type", rename = "image", deny_unknown_fields)]
+pub struct ImageSourceSpecification {
+    /// URL of the image
+    pub url: String,
+    /// Longitude and latitude of the top left, top right, bottom right and bottom left corners
+    /// of the image
+    pub coordinates: [[f64; 2]; 4],
+}
+
+impl_source_specification_serde!(ImageSourceSpecification, "image");
+
+impl ImageSourceSpecification {
+    /// Create an `ImageSourceSpecification` with the image at the given URL placed on the given
+    /// top left, top right, bottom right and bottom left corners
+    #[must_use]
+    pub fn new(url: impl Into<String>, coordinates: [impl Into<LngLatLike>; 4]) -> Self {
+        Self {
+            url: url.into(),
+            coordinates: corner_coordinates(coordinates),
+        }
+    }
+}
+
+/// Convert the four corners of an image, video or canvas source into the arrays expected by
+/// MapLibre
+pub(super) fn corner_coordinates(coordinates: [impl Into<LngLatLike>; 4]) -> [[f64; 2]; 4] {
+    coordinates.map(|corner| {
+        let corner = corner.into();
+        [corner.lng(), corner.lat()]
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn image_source_specification_serialization() {
+        let source = ImageSourceSpecification::new(
+            "https://example.com/orthophoto.png",
+            [[-80.4, 37.9], [-71.5, 37.9], [-71.5, 34.8], [-80.4, 34.8]],
+        );
+
+        assert_eq!(
+            serde_json::to_value(&source).expect("Serializing should work"),
+            json!({
+                "type": "image",
+                "url": "https://example.com/orthophoto.png",
+                "coordinates": [[-80.4, 37.9], [-71.5, 37.9], [-71.5, 34.8], [-80.4, 34.8]],
+            })
+        );
+    }
+}
@@ -0,0 +1,128 @@
+//! Rusty interface to the bindings of the sources of the style of a `Map` of MapLibre GL JS
+
+/// Implement `Serialize` and `Deserialize` for a source specification deriving them with
+/// `#[serde(remote = "Self")]`. Serde ignores the `type` tag of structs when deserializing, so
+/// it is checked and removed beforehand to let `deny_unknown_fields` reject only unknown keys
+macro_rules! impl_source_specification_serde {
+    ($specification:ty, $tag:literal) => {
+        impl serde::Serialize for $specification {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                Self::serialize(self, serializer)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $specification {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let mut object =
+                    serde_json::Map::<String, serde_json::Value>::deserialize(deserializer)?;
+                match object.remove("type") {
+                    None => {}
+                    Some(serde_json::Value::String(tag)) if tag == $tag => {}
+                    Some(tag) => {
+                        return Err(serde::de::Error::custom(format!(
+                            "Expected a source of type {}, found {tag}",
+                            $tag
+                        )));
+                    }
+                }
+                Self::deserialize(serde_json::Value::Object(object))
+                    .map_err(serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+mod canvas_source;
+mod canvas_source_specification;
+mod geojson_feature;
+mod geojson_source;
+mod geojson_source_diff;
+mod geojson_source_specification;
+mod image_source;
+mod image_source_specification;
+mod raster_dem_source_specification;
+mod raster_source_specification;
+mod source_query;
+mod source_specification;
+mod tile_json;
+mod vector_source_specification;
+mod video_source;
+mod video_source_specification;
+
+pub use canvas_source::CanvasSource;
+pub use canvas_source_specification::CanvasSourceSpecification;
+pub use geojson_feature::GeoJSONFeature;
+pub use geojson_source::GeoJSONSource;
+pub use geojson_source_diff::{GeoJSONFeatureDiff, GeoJSONFeatureId, GeoJSONSourceDiff};
+pub use geojson_source_specification::GeoJSONSourceSpecification;
+pub use image_source::ImageSource;
+pub use image_source_specification::ImageSourceSpecification;
+pub use raster_dem_source_specification::{RasterDemEncoding, RasterDemSourceSpecification};
+pub use raster_source_specification::RasterSourceSpecification;
+pub use source_query::SourceQuery;
+pub use source_specification::{PromoteId, SourceSpecification, TileScheme};
+pub use tile_json::{TileJson, TileJsonError, TileJsonVectorLayer};
+pub use vector_source_specification::{VectorEncoding, VectorSourceSpecification};
+pub use video_source::VideoSource;
+pub use video_source_specification::VideoSourceSpecification;
+
+use serde::de::DeserializeOwned;
+use wasm_bindgen::JsCast;
+
+use super::Error;
+use crate::bindings;
+
+/// Data source of the style of a `Map`, as returned by `Map::get_source`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Source {
+    /// JS object that the struct wraps
+    js_value: bindings::Source,
+}
+
+impl Source {
+    /// Get the identifier of the source
+    #[must_use]
+    pub fn id(&self) -> String {
+        self.js_value.id()
+    }
+
+    /// Get the type of the source, such as `geojson` or `vector`
+    #[must_use]
+    pub fn source_type(&self) -> String {
+        self.js_value.source_type()
+    }
+
+    /// Get the specification of the source
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn specification<T: DeserializeOwned>(&self) -> Result<T, Error> {
+        serde_wasm_bindgen::from_value(self.js_value.serialize())
+    }
+
+    /// Convert the source into the bindings of a more specific source after checking its type
+    fn downcast<T: JsCast>(self, expected_type: &str) -> Result<T, Error> {
+        let source_type = self.source_type();
+        if source_type != expected_type {
+            return Err(Error::new(format!(
+                "Source {} is of type {source_type}, not {expected_type}",
+                self.id()
+            )));
+        }
+
+        Ok(self.js_value.unchecked_into::<T>())
+    }
+
+    /// Get the underlying JS object
+    #[must_use]
+    pub fn into_inner(self) -> bindings::Source {
+        self.js_value
+    }
+}
+
+impl From<bindings::Source> for Source {
+    fn from(value: bindings::Source) -> Self {
+        Self { js_value: value }
+    }
+}
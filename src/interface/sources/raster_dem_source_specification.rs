@@ -0,0 +1,228 @@
+//! Submodule for `RasterDemSourceSpecification` and associated types and tests
+
+use serde::{Deserialize, Serialize};
+
+use super::TileJson;
+
+/// Specification of a source of raster tiles encoding elevation, given either as the URL of a TileJSON resource or
+/// as a list of tile URLs.
+///
+/// MapLibre docs: <https://maplibre.org/maplibre-style-spec/sources/#raster-dem>
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+#[serde(
+    remote = "Self",
+    tag = "type",
+    rename = "raster-dem",
+    deny_unknown_fields,
+    rename_all = "camelCase"
+)]
+pub struct RasterDemSourceSpecification {
+    /// URL of a TileJSON resource describing the source
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// URL templates of the tiles, containing `{z}`, `{x}` and `{y}`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tiles: Option<Vec<String>>,
+    /// Bounds of the tiles as `[west, south, east, north]`. Defaults to the whole world
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bounds: Option<[f64; 4]>,
+    /// Size of the tiles in pixels. Defaults to `512`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tile_size: Option<u32>,
+    /// Minimum zoom level of the tiles. Defaults to `0`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minzoom: Option<f64>,
+    /// Maximum zoom level of the tiles, overzoomed above it. Defaults to `22`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maxzoom: Option<f64>,
+    /// Attribution displayed when the map shows data from the source
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attribution: Option<String>,
+    /// Whether to discard the tiles from the cache when they are no longer displayed. Defaults
+    /// to `false`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub volatile: Option<bool>,
+    /// Encoding of the elevation in the tiles. Defaults to `mapbox`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<RasterDemEncoding>,
+    /// Factor of the red channel in the `custom` encoding
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub red_factor: Option<f64>,
+    /// Factor of the green channel in the `custom` encoding
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub green_factor: Option<f64>,
+    /// Factor of the blue channel in the `custom` encoding
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blue_factor: Option<f64>,
+    /// Shift added to the elevation in the `custom` encoding
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_shift: Option<f64>,
+}
+
+impl_source_specification_serde!(RasterDemSourceSpecification, "raster-dem");
+
+impl RasterDemSourceSpecification {
+    /// Create a `RasterDemSourceSpecification` described by the TileJSON resource at the given URL
+    #[must_use]
+    pub fn from_url(url: impl Into<String>) -> Self {
+        Self {
+            url: Some(url.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Create a `RasterDemSourceSpecification` with the given URL templates of the tiles
+    #[must_use]
+    pub fn from_tiles(tiles: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            tiles: Some(tiles.into_iter().map(Into::into).collect()),
+            ..Self::default()
+        }
+    }
+
+    /// Create a `RasterDemSourceSpecification` with the tiles, bounds, zoom levels and attribution
+    /// of a TileJSON resource
+    #[must_use]
+    pub fn from_tile_json(tile_json: &TileJson) -> Self {
+        Self {
+            tiles: Some(tile_json.tiles.clone()),
+            bounds: tile_json.bounds,
+            minzoom: tile_json.minzoom.map(f64::from),
+            maxzoom: tile_json.maxzoom.map(f64::from),
+            attribution: tile_json.attribution.clone(),
+            ..Self::default()
+        }
+    }
+
+    /// Specify the bounds of the tiles as `[west, south, east, north]`
+    #[must_use]
+    pub fn with_bounds(self, bounds: [f64; 4]) -> Self {
+        Self {
+            bounds: Some(bounds),
+            ..self
+        }
+    }
+
+    /// Specify the size of the tiles in pixels
+    #[must_use]
+    pub fn with_tile_size(self, tile_size: u32) -> Self {
+        Self {
+            tile_size: Some(tile_size),
+            ..self
+        }
+    }
+
+    /// Specify the minimum zoom level of the tiles
+    #[must_use]
+    pub fn with_minzoom(self, minzoom: impl Into<f64>) -> Self {
+        Self {
+            minzoom: Some(minzoom.into()),
+            ..self
+        }
+    }
+
+    /// Specify the maximum zoom level of the tiles
+    #[must_use]
+    pub fn with_maxzoom(self, maxzoom: impl Into<f64>) -> Self {
+        Self {
+            maxzoom: Some(maxzoom.into()),
+            ..self
+        }
+    }
+
+    /// Specify the attribution displayed when the map shows data from the source
+    #[must_use]
+    pub fn with_attribution(self, attribution: impl Into<String>) -> Self {
+        Self {
+            attribution: Some(attribution.into()),
+            ..self
+        }
+    }
+
+    /// Discard the tiles from the cache when they are no longer displayed
+    #[must_use]
+    pub fn with_volatile(self) -> Self {
+        Self {
+            volatile: Some(true),
+            ..self
+        }
+    }
+
+    /// Keep the tiles in the cache when they are no longer displayed
+    #[must_use]
+    pub fn without_volatile(self) -> Self {
+        Self {
+            volatile: Some(false),
+            ..self
+        }
+    }
+
+    /// Specify the encoding of the elevation in the tiles
+    #[must_use]
+    pub fn with_encoding(self, encoding: RasterDemEncoding) -> Self {
+        Self {
+            encoding: Some(encoding),
+            ..self
+        }
+    }
+
+    /// Use the `custom` encoding, computing the elevation as `red * red_factor + green *
+    /// green_factor + blue * blue_factor + base_shift`
+    #[must_use]
+    pub fn with_custom_encoding(
+        self,
+        red_factor: f64,
+        green_factor: f64,
+        blue_factor: f64,
+        base_shift: f64,
+    ) -> Self {
+        Self {
+            encoding: Some(RasterDemEncoding::Custom),
+            red_factor: Some(red_factor),
+            green_factor: Some(green_factor),
+            blue_factor: Some(blue_factor),
+            base_shift: Some(base_shift),
+            ..self
+        }
+    }
+}
+
+/// Encoding of the elevation in the tiles of a `raster-dem` source
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RasterDemEncoding {
+    /// Terrarium format PNG tiles
+    Terrarium,
+    /// Mapbox Terrain RGB tiles
+    Mapbox,
+    /// Custom encoding given by the factors of each channel and a base shift
+    Custom,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn raster_dem_source_specification_serialization() {
+        let source = RasterDemSourceSpecification::from_url("https://example.com/dem.json")
+            .with_tile_size(256)
+            .with_custom_encoding(256., 1., 1. / 256., -32768.);
+
+        assert_eq!(
+            serde_json::to_value(&source).expect("Serializing should work"),
+            json!({
+                "type": "raster-dem",
+                "url": "https://example.com/dem.json",
+                "tileSize": 256,
+                "encoding": "custom",
+                "redFactor": 256.0,
+                "greenFactor": 1.0,
+                "blueFactor": 0.003_906_25,
+                "baseShift": -32768.0,
+            })
+        );
+    }
+}
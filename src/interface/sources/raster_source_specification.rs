@@ -0,0 +1,186 @@
+//! Submodule for `RasterSourceSpecification` and associated tests
+
+use serde::{Deserialize, Serialize};
+
+use super::{TileJson, TileScheme};
+
+/// Specification of a source of raster tiles, given either as the URL of a TileJSON resource or
+/// as a list of tile URLs.
+///
+/// MapLibre docs: <https://maplibre.org/maplibre-style-spec/sources/#raster>
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+#[serde(
+    remote = "Self",
+    tag = "type",
+    rename = "raster",
+    deny_unknown_fields,
+    rename_all = "camelCase"
+)]
+pub struct RasterSourceSpecification {
+    /// URL of a TileJSON resource describing the source
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// URL templates of the tiles, containing `{z}`, `{x}` and `{y}`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tiles: Option<Vec<String>>,
+    /// Bounds of the tiles as `[west, south, east, north]`. Defaults to the whole world
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bounds: Option<[f64; 4]>,
+    /// Scheme of the coordinates of the tiles. Defaults to `xyz`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheme: Option<TileScheme>,
+    /// Size of the tiles in pixels. Defaults to `512`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tile_size: Option<u32>,
+    /// Minimum zoom level of the tiles. Defaults to `0`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minzoom: Option<f64>,
+    /// Maximum zoom level of the tiles, overzoomed above it. Defaults to `22`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maxzoom: Option<f64>,
+    /// Attribution displayed when the map shows data from the source
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attribution: Option<String>,
+    /// Whether to discard the tiles from the cache when they are no longer displayed. Defaults
+    /// to `false`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub volatile: Option<bool>,
+}
+
+impl_source_specification_serde!(RasterSourceSpecification, "raster");
+
+impl RasterSourceSpecification {
+    /// Create a `RasterSourceSpecification` described by the TileJSON resource at the given URL
+    #[must_use]
+    pub fn from_url(url: impl Into<String>) -> Self {
+        Self {
+            url: Some(url.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Create a `RasterSourceSpecification` with the given URL templates of the tiles
+    #[must_use]
+    pub fn from_tiles(tiles: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            tiles: Some(tiles.into_iter().map(Into::into).collect()),
+            ..Self::default()
+        }
+    }
+
+    /// Create a `RasterSourceSpecification` with the tiles, bounds, scheme, zoom levels and
+    /// attribution of a TileJSON resource
+    #[must_use]
+    pub fn from_tile_json(tile_json: &TileJson) -> Self {
+        Self {
+            tiles: Some(tile_json.tiles.clone()),
+            bounds: tile_json.bounds,
+            scheme: tile_json.scheme,
+            minzoom: tile_json.minzoom.map(f64::from),
+            maxzoom: tile_json.maxzoom.map(f64::from),
+            attribution: tile_json.attribution.clone(),
+            ..Self::default()
+        }
+    }
+
+    /// Specify the bounds of the tiles as `[west, south, east, north]`
+    #[must_use]
+    pub fn with_bounds(self, bounds: [f64; 4]) -> Self {
+        Self {
+            bounds: Some(bounds),
+            ..self
+        }
+    }
+
+    /// Specify the scheme of the coordinates of the tiles
+    #[must_use]
+    pub fn with_scheme(self, scheme: TileScheme) -> Self {
+        Self {
+            scheme: Some(scheme),
+            ..self
+        }
+    }
+
+    /// Specify the size of the tiles in pixels
+    #[must_use]
+    pub fn with_tile_size(self, tile_size: u32) -> Self {
+        Self {
+            tile_size: Some(tile_size),
+            ..self
+        }
+    }
+
+    /// Specify the minimum zoom level of the tiles
+    #[must_use]
+    pub fn with_minzoom(self, minzoom: impl Into<f64>) -> Self {
+        Self {
+            minzoom: Some(minzoom.into()),
+            ..self
+        }
+    }
+
+    /// Specify the maximum zoom level of the tiles
+    #[must_use]
+    pub fn with_maxzoom(self, maxzoom: impl Into<f64>) -> Self {
+        Self {
+            maxzoom: Some(maxzoom.into()),
+            ..self
+        }
+    }
+
+    /// Specify the attribution displayed when the map shows data from the source
+    #[must_use]
+    pub fn with_attribution(self, attribution: impl Into<String>) -> Self {
+        Self {
+            attribution: Some(attribution.into()),
+            ..self
+        }
+    }
+
+    /// Discard the tiles from the cache when they are no longer displayed
+    #[must_use]
+    pub fn with_volatile(self) -> Self {
+        Self {
+            volatile: Some(true),
+            ..self
+        }
+    }
+
+    /// Keep the tiles in the cache when they are no longer displayed
+    #[must_use]
+    pub fn without_volatile(self) -> Self {
+        Self {
+            volatile: Some(false),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn raster_source_specification_serialization() {
+        let source = RasterSourceSpecification::from_tiles([
+            "https://tile.openstreetmap.org/{z}/{x}/{y}.png",
+        ])
+        .with_tile_size(256)
+        .with_minzoom(0)
+        .with_maxzoom(19)
+        .with_attribution("© OpenStreetMap contributors");
+
+        assert_eq!(
+            serde_json::to_value(&source).expect("Serializing should work"),
+            json!({
+                "type": "raster",
+                "tiles": ["https://tile.openstreetmap.org/{z}/{x}/{y}.png"],
+                "tileSize": 256,
+                "minzoom": 0.0,
+                "maxzoom": 19.0,
+                "attribution": "© OpenStreetMap contributors",
+            })
+        );
+    }
+}
@@ -0,0 +1,47 @@
+//! Submodule for the `SourceQuery` future which waits for data requested from a source
+
+use std::{
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use serde::de::DeserializeOwned;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::js_sys::Promise;
+
+use super::Error;
+
+/// Future which resolves to the data requested from a source once MapLibre has computed it,
+/// usually in a web worker
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct SourceQuery<T> {
+    /// Promise returned by MapLibre
+    promise: JsFuture,
+    /// Type the data resolved by the promise is deserialized into
+    output: PhantomData<fn() -> T>,
+}
+
+impl<T> SourceQuery<T> {
+    /// Create a `SourceQuery` waiting for the given promise
+    pub(crate) fn new(promise: Promise) -> Self {
+        Self {
+            promise: JsFuture::from(promise),
+            output: PhantomData,
+        }
+    }
+}
+
+impl<T: DeserializeOwned> Future for SourceQuery<T> {
+    type Output = Result<T, Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let result = std::task::ready!(Pin::new(&mut self.promise).poll(cx));
+        Poll::Ready(
+            result
+                .map_err(Error::from)
+                .and_then(serde_wasm_bindgen::from_value),
+        )
+    }
+}
@@ -0,0 +1,219 @@
+//! Submodule for `SourceSpecification` and the types shared by the specifications of sources
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Deserializer, Serialize};
+use wasm_bindgen::JsValue;
+
+use super::{
+    CanvasSourceSpecification, Error, GeoJSONSourceSpecification, ImageSourceSpecification,
+    RasterDemSourceSpecification, RasterSourceSpecification, VectorSourceSpecification,
+    VideoSourceSpecification,
+};
+
+/// Specification of a source of the style, tagged with its type.
+///
+/// MapLibre docs: <https://maplibre.org/maplibre-style-spec/sources/>
+#[derive(Debug, PartialEq, Clone, Serialize)]
+#[serde(untagged)]
+pub enum SourceSpecification {
+    /// Source of vector tiles
+    Vector(Box<VectorSourceSpecification>),
+    /// Source of raster tiles
+    Raster(Box<RasterSourceSpecification>),
+    /// Source of raster tiles encoding elevation
+    RasterDem(Box<RasterDemSourceSpecification>),
+    /// Source of GeoJSON data
+    GeoJSON(Box<GeoJSONSourceSpecification>),
+    /// Source of an image placed on four corners
+    Image(Box<ImageSourceSpecification>),
+    /// Source of a video placed on four corners
+    Video(Box<VideoSourceSpecification>),
+    /// Source of the content of an HTML canvas placed on four corners
+    Canvas(Box<CanvasSourceSpecification>),
+    /// JSON representing a non-validated source specification. Deserializing yields this
+    /// variant only when the type of the source is missing or unknown, while sources of a
+    /// known type that don't match its typed specification fail to deserialize
+    Json(serde_json::Value),
+}
+
+impl SourceSpecification {
+    /// Try converting `SourceSpecification` into the equivalent `JsValue`
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn as_js_value(&self) -> Result<JsValue, Error> {
+        self.serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+    }
+}
+
+impl<'de> Deserialize<'de> for SourceSpecification {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let typed = match value.get("type").and_then(serde_json::Value::as_str) {
+            Some("vector") => serde_json::from_value(value).map(Self::Vector),
+            Some("raster") => serde_json::from_value(value).map(Self::Raster),
+            Some("raster-dem") => serde_json::from_value(value).map(Self::RasterDem),
+            Some("geojson") => serde_json::from_value(value).map(Self::GeoJSON),
+            Some("image") => serde_json::from_value(value).map(Self::Image),
+            Some("video") => serde_json::from_value(value).map(Self::Video),
+            Some("canvas") => serde_json::from_value(value).map(Self::Canvas),
+            _ => return Ok(Self::Json(value)),
+        };
+        typed.map_err(serde::de::Error::custom)
+    }
+}
+
+/// Implement the conversion of each source specification into `SourceSpecification`
+macro_rules! impl_from_source_specification {
+    ($($variant:ident($specification:ty)),* $(,)?) => {
+        $(
+            impl From<$specification> for SourceSpecification {
+                fn from(value: $specification) -> Self {
+                    Self::$variant(Box::new(value))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_source_specification!(
+    Vector(VectorSourceSpecification),
+    Raster(RasterSourceSpecification),
+    RasterDem(RasterDemSourceSpecification),
+    GeoJSON(GeoJSONSourceSpecification),
+    Image(ImageSourceSpecification),
+    Video(VideoSourceSpecification),
+    Canvas(CanvasSourceSpecification),
+);
+
+impl From<serde_json::Value> for SourceSpecification {
+    fn from(value: serde_json::Value) -> Self {
+        Self::Json(value)
+    }
+}
+
+impl TryFrom<JsValue> for SourceSpecification {
+    type Error = Error;
+
+    fn try_from(value: JsValue) -> Result<Self, Self::Error> {
+        serde_wasm_bindgen::from_value(value)
+    }
+}
+
+impl TryFrom<SourceSpecification> for JsValue {
+    type Error = Error;
+
+    fn try_from(value: SourceSpecification) -> Result<Self, Self::Error> {
+        value.as_js_value()
+    }
+}
+
+impl TryFrom<&SourceSpecification> for JsValue {
+    type Error = Error;
+
+    fn try_from(value: &SourceSpecification) -> Result<Self, Self::Error> {
+        value.as_js_value()
+    }
+}
+
+/// Scheme of the coordinates of the tiles
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TileScheme {
+    /// Slippy map tilenames scheme, with the origin at the top left
+    #[default]
+    Xyz,
+    /// Tile Map Service scheme, with the origin at the bottom left
+    Tms,
+}
+
+/// Property of the features of a vector source to use as their identifier
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PromoteId {
+    /// Name of the property used for every source layer
+    Property(String),
+    /// Name of the property used for each source layer, indexed by the name of the layer
+    PerLayer(BTreeMap<String, String>),
+}
+
+impl From<&str> for PromoteId {
+    fn from(value: &str) -> Self {
+        Self::Property(value.to_string())
+    }
+}
+
+impl From<String> for PromoteId {
+    fn from(value: String) -> Self {
+        Self::Property(value)
+    }
+}
+
+impl From<BTreeMap<String, String>> for PromoteId {
+    fn from(value: BTreeMap<String, String>) -> Self {
+        Self::PerLayer(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+    use wasm_bindgen_test::*;
+
+    #[test]
+    fn source_specification_deserialization() {
+        let sources: Vec<SourceSpecification> = serde_json::from_value(json!([
+            {"type": "vector", "url": "https://example.com/tiles.json"},
+            {"type": "raster", "tiles": ["https://example.com/{z}/{x}/{y}.png"], "tileSize": 256},
+            {"type": "raster-dem", "url": "https://example.com/dem.json", "encoding": "terrarium"},
+            {"type": "geojson", "data": "https://example.com/data.geojson"},
+            {"type": "image", "url": "https://example.com/a.png", "coordinates": [[0, 1], [1, 1], [1, 0], [0, 0]]},
+            {"type": "video", "urls": ["https://example.com/a.mp4"], "coordinates": [[0, 1], [1, 1], [1, 0], [0, 0]]},
+            {"type": "canvas", "canvas": "a", "coordinates": [[0, 1], [1, 1], [1, 0], [0, 0]]},
+            {"type": "unknown"},
+        ]))
+        .expect("Deserializing should work");
+
+        assert!(matches!(sources[0], SourceSpecification::Vector(_)));
+        assert!(matches!(sources[1], SourceSpecification::Raster(_)));
+        assert!(matches!(sources[2], SourceSpecification::RasterDem(_)));
+        assert!(matches!(sources[3], SourceSpecification::GeoJSON(_)));
+        assert!(matches!(sources[4], SourceSpecification::Image(_)));
+        assert!(matches!(sources[5], SourceSpecification::Video(_)));
+        assert!(matches!(sources[6], SourceSpecification::Canvas(_)));
+        assert_eq!(sources[7], json!({"type": "unknown"}).into());
+    }
+
+    #[test]
+    fn source_specification_invalid_known_type() {
+        let misspelled = json!({"type": "vector", "urll": "https://example.com/tiles.json"});
+        let missing = json!({"type": "image", "url": "https://example.com/a.png"});
+
+        assert!(serde_json::from_value::<SourceSpecification>(misspelled).is_err());
+        assert!(serde_json::from_value::<SourceSpecification>(missing).is_err());
+        assert!(
+            serde_json::from_value::<RasterSourceSpecification>(
+                json!({"type": "vector", "url": "https://example.com/tiles.json"})
+            )
+            .is_err()
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn source_specification_js_round_trip() {
+        let source: SourceSpecification =
+            RasterSourceSpecification::from_tiles(["https://example.com/{z}/{x}/{y}.png"])
+                .with_tile_size(512)
+                .into();
+        let source_js = source
+            .as_js_value()
+            .expect("Should be able to convert to JsValue");
+        let retrieved =
+            SourceSpecification::try_from(source_js).expect("Should be able to back convert");
+
+        assert_eq!(retrieved, source);
+    }
+}
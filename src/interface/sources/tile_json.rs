@@ -0,0 +1,320 @@
+//! Submodule for `TileJson` and associated types and tests
+
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use super::TileScheme;
+
+/// Maximum zoom level allowed by the TileJSON specification
+const TILE_JSON_MAX_ZOOM: u8 = 30;
+
+/// TileJSON resource describing a tileset, as referenced by the `url` of the specification of
+/// tiled sources.
+///
+/// Parsing it with `str::parse` also validates it.
+///
+/// TileJSON docs: <https://github.com/mapbox/tilejson-spec/tree/master/3.0.0>
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct TileJson {
+    /// Version of the TileJSON specification, such as `3.0.0`
+    pub tilejson: String,
+    /// URL templates of the tiles, containing `{z}`, `{x}` and `{y}`
+    pub tiles: Vec<String>,
+    /// Layers available in the vector tiles
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vector_layers: Option<Vec<TileJsonVectorLayer>>,
+    /// Attribution to display when the tiles are shown
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attribution: Option<String>,
+    /// Bounds of the tiles as `[west, south, east, north]`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bounds: Option<[f64; 4]>,
+    /// Default position as `[longitude, latitude, zoom]`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub center: Option<[f64; 3]>,
+    /// URLs of GeoJSON files with the data of the tiles
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Vec<String>>,
+    /// Description of the tileset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Zoom level from which to generate overzoomed tiles
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fillzoom: Option<u8>,
+    /// URL templates of the UTF-Grid interactivity files
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grids: Option<Vec<String>>,
+    /// Legend to display with the tiles
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub legend: Option<String>,
+    /// Maximum zoom level of the tiles. Defaults to `30`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maxzoom: Option<u8>,
+    /// Minimum zoom level of the tiles. Defaults to `0`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minzoom: Option<u8>,
+    /// Name of the tileset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Scheme of the coordinates of the tiles. Defaults to `xyz`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheme: Option<TileScheme>,
+    /// Mustache template of the interactivity of the UTF-Grid files
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+    /// Version of the tileset, following semantic versioning
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+
+impl TileJson {
+    /// Create a `TileJson` of version `3.0.0` with the given URL templates of the tiles
+    #[must_use]
+    pub fn new(tiles: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            tilejson: "3.0.0".to_string(),
+            tiles: tiles.into_iter().map(Into::into).collect(),
+            vector_layers: None,
+            attribution: None,
+            bounds: None,
+            center: None,
+            data: None,
+            description: None,
+            fillzoom: None,
+            grids: None,
+            legend: None,
+            maxzoom: None,
+            minzoom: None,
+            name: None,
+            scheme: None,
+            template: None,
+            version: None,
+        }
+    }
+
+    /// Validate the values of the fields against the TileJSON specification
+    ///
+    /// # Errors
+    ///
+    /// Returns the first invalid field found
+    pub fn validate(&self) -> Result<(), TileJsonError> {
+        if !self.tilejson.starts_with("2.") && !self.tilejson.starts_with("3.") {
+            return Err(TileJsonError::invalid(
+                "tilejson",
+                format!("unsupported version {}", self.tilejson),
+            ));
+        }
+        if self.tiles.is_empty() {
+            return Err(TileJsonError::invalid(
+                "tiles",
+                "at least one tile URL is required",
+            ));
+        }
+
+        let minzoom = self.minzoom.unwrap_or(0);
+        let maxzoom = self.maxzoom.unwrap_or(TILE_JSON_MAX_ZOOM);
+        validate_zoom_range("minzoom", minzoom, maxzoom)?;
+        if let Some(fillzoom) = self.fillzoom
+            && !(minzoom..=maxzoom).contains(&fillzoom)
+        {
+            return Err(TileJsonError::invalid(
+                "fillzoom",
+                format!("{fillzoom} is outside of the zoom range {minzoom} to {maxzoom}"),
+            ));
+        }
+
+        if let Some([west, south, east, north]) = self.bounds {
+            if !(-180. ..=180.).contains(&west) || !(-180. ..=180.).contains(&east) {
+                return Err(TileJsonError::invalid(
+                    "bounds",
+                    "longitudes must be between -180 and 180",
+                ));
+            }
+            if !(-90. ..=90.).contains(&south) || !(-90. ..=90.).contains(&north) || south > north {
+                return Err(TileJsonError::invalid(
+                    "bounds",
+                    "latitudes must be between -90 and 90, with south below north",
+                ));
+            }
+        }
+
+        if let Some([longitude, latitude, zoom]) = self.center {
+            if !(-180. ..=180.).contains(&longitude) || !(-90. ..=90.).contains(&latitude) {
+                return Err(TileJsonError::invalid(
+                    "center",
+                    "longitude and latitude are out of range",
+                ));
+            }
+            if !(f64::from(minzoom)..=f64::from(maxzoom)).contains(&zoom) {
+                return Err(TileJsonError::invalid(
+                    "center",
+                    format!("zoom {zoom} is outside of the zoom range {minzoom} to {maxzoom}"),
+                ));
+            }
+        }
+
+        for layer in self.vector_layers.iter().flatten() {
+            validate_zoom_range(
+                "vector_layers",
+                layer.minzoom.unwrap_or(minzoom),
+                layer.maxzoom.unwrap_or(maxzoom),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Validate that the zoom levels are ordered and below the maximum allowed
+fn validate_zoom_range(field: &'static str, minzoom: u8, maxzoom: u8) -> Result<(), TileJsonError> {
+    if maxzoom > TILE_JSON_MAX_ZOOM {
+        return Err(TileJsonError::invalid(
+            field,
+            format!("maxzoom {maxzoom} is above {TILE_JSON_MAX_ZOOM}"),
+        ));
+    }
+    if minzoom > maxzoom {
+        return Err(TileJsonError::invalid(
+            field,
+            format!("minzoom {minzoom} is above maxzoom {maxzoom}"),
+        ));
+    }
+    Ok(())
+}
+
+impl FromStr for TileJson {
+    type Err = TileJsonError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tile_json: Self = serde_json::from_str(s)?;
+        tile_json.validate()?;
+        Ok(tile_json)
+    }
+}
+
+/// Layer available in the vector tiles of a `TileJson`
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct TileJsonVectorLayer {
+    /// Identifier of the layer, used as `source-layer` by the style layers
+    pub id: String,
+    /// Description of the type of each field of the features of the layer
+    pub fields: BTreeMap<String, String>,
+    /// Description of the layer
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Minimum zoom level at which the layer is present
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minzoom: Option<u8>,
+    /// Maximum zoom level at which the layer is present
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maxzoom: Option<u8>,
+}
+
+/// Error raised while parsing or validating a `TileJson`
+#[derive(Debug)]
+pub enum TileJsonError {
+    /// The JSON couldn't be parsed into a `TileJson`
+    Parse(serde_json::Error),
+    /// A field holds a value not allowed by the specification
+    Invalid {
+        /// Name of the invalid field
+        field: &'static str,
+        /// Description of the problem
+        message: String,
+    },
+}
+
+impl TileJsonError {
+    /// Create a `TileJsonError` for the given invalid field
+    fn invalid(field: &'static str, message: impl Into<String>) -> Self {
+        Self::Invalid {
+            field,
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for TileJsonError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parse(error) => write!(f, "Failed to parse the TileJSON: {error}"),
+            Self::Invalid { field, message } => write!(f, "Invalid TileJSON {field}: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for TileJsonError {}
+
+impl From<serde_json::Error> for TileJsonError {
+    fn from(value: serde_json::Error) -> Self {
+        Self::Parse(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::interface::sources::VectorSourceSpecification;
+    use serde_json::json;
+
+    #[test]
+    fn tile_json_parse() {
+        let tile_json: TileJson = json!({
+            "tilejson": "3.0.0",
+            "tiles": ["https://example.com/{z}/{x}/{y}.pbf"],
+            "vector_layers": [{"id": "roads", "fields": {"class": "String"}}],
+            "bounds": [-10, 35, 5, 44],
+            "center": [-3.7, 40.4, 6],
+            "minzoom": 0,
+            "maxzoom": 14,
+            "attribution": "© OpenStreetMap contributors",
+        })
+        .to_string()
+        .parse()
+        .expect("Parsing should work");
+        let source = VectorSourceSpecification::from_tile_json(&tile_json);
+
+        assert_eq!(tile_json.vector_layers.map(|layers| layers.len()), Some(1));
+        assert_eq!(source.tiles, Some(tile_json.tiles));
+        assert_eq!(source.bounds, Some([-10., 35., 5., 44.]));
+        assert_eq!(source.maxzoom, Some(14.));
+    }
+
+    #[test]
+    fn tile_json_invalid() {
+        let invalid_field = |value: serde_json::Value| match value.to_string().parse::<TileJson>() {
+            Err(TileJsonError::Invalid { field, .. }) => Some(field),
+            _ => None,
+        };
+
+        assert!(matches!(
+            "{\"tiles\": []}".parse::<TileJson>(),
+            Err(TileJsonError::Parse(_))
+        ));
+        assert_eq!(
+            invalid_field(json!({"tilejson": "1.0.0", "tiles": ["a"]})),
+            Some("tilejson")
+        );
+        assert_eq!(
+            invalid_field(json!({"tilejson": "3.0.0", "tiles": []})),
+            Some("tiles")
+        );
+        assert_eq!(
+            invalid_field(
+                json!({"tilejson": "3.0.0", "tiles": ["a"], "minzoom": 10, "maxzoom": 5})
+            ),
+            Some("minzoom")
+        );
+        assert_eq!(
+            invalid_field(json!({"tilejson": "3.0.0", "tiles": ["a"], "bounds": [0, 50, 10, 40]})),
+            Some("bounds")
+        );
+        assert_eq!(
+            invalid_field(
+                json!({"tilejson": "3.0.0", "tiles": ["a"], "maxzoom": 10, "center": [0, 0, 12]})
+            ),
+            Some("center")
+        );
+    }
+}
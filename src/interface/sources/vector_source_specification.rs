@@ -0,0 +1,207 @@
+//! Submodule for `VectorSourceSpecification` and associated types and tests
+
+use serde::{Deserialize, Serialize};
+
+use super::{PromoteId, TileJson, TileScheme};
+
+/// Specification of a source of vector tiles, given either as the URL of a TileJSON resource or
+/// as a list of tile URLs.
+///
+/// MapLibre docs: <https://maplibre.org/maplibre-style-spec/sources/#vector>
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+#[serde(
+    remote = "Self",
+    tag = "type",
+    rename = "vector",
+    deny_unknown_fields,
+    rename_all = "camelCase"
+)]
+pub struct VectorSourceSpecification {
+    /// URL of a TileJSON resource describing the source
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// URL templates of the tiles, containing `{z}`, `{x}` and `{y}`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tiles: Option<Vec<String>>,
+    /// Bounds of the tiles as `[west, south, east, north]`. Defaults to the whole world
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bounds: Option<[f64; 4]>,
+    /// Scheme of the coordinates of the tiles. Defaults to `xyz`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheme: Option<TileScheme>,
+    /// Minimum zoom level of the tiles. Defaults to `0`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minzoom: Option<f64>,
+    /// Maximum zoom level of the tiles, overzoomed above it. Defaults to `22`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maxzoom: Option<f64>,
+    /// Attribution displayed when the map shows data from the source
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attribution: Option<String>,
+    /// Property of the features to use as their identifier
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub promote_id: Option<PromoteId>,
+    /// Whether to discard the tiles from the cache when they are no longer displayed. Defaults
+    /// to `false`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub volatile: Option<bool>,
+    /// Encoding of the tiles. Defaults to `mvt`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<VectorEncoding>,
+}
+
+impl_source_specification_serde!(VectorSourceSpecification, "vector");
+
+impl VectorSourceSpecification {
+    /// Create a `VectorSourceSpecification` described by the TileJSON resource at the given URL
+    #[must_use]
+    pub fn from_url(url: impl Into<String>) -> Self {
+        Self {
+            url: Some(url.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Create a `VectorSourceSpecification` with the given URL templates of the tiles
+    #[must_use]
+    pub fn from_tiles(tiles: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            tiles: Some(tiles.into_iter().map(Into::into).collect()),
+            ..Self::default()
+        }
+    }
+
+    /// Create a `VectorSourceSpecification` with the tiles, bounds, scheme, zoom levels and
+    /// attribution of a TileJSON resource
+    #[must_use]
+    pub fn from_tile_json(tile_json: &TileJson) -> Self {
+        Self {
+            tiles: Some(tile_json.tiles.clone()),
+            bounds: tile_json.bounds,
+            scheme: tile_json.scheme,
+            minzoom: tile_json.minzoom.map(f64::from),
+            maxzoom: tile_json.maxzoom.map(f64::from),
+            attribution: tile_json.attribution.clone(),
+            ..Self::default()
+        }
+    }
+
+    /// Specify the bounds of the tiles as `[west, south, east, north]`
+    #[must_use]
+    pub fn with_bounds(self, bounds: [f64; 4]) -> Self {
+        Self {
+            bounds: Some(bounds),
+            ..self
+        }
+    }
+
+    /// Specify the scheme of the coordinates of the tiles
+    #[must_use]
+    pub fn with_scheme(self, scheme: TileScheme) -> Self {
+        Self {
+            scheme: Some(scheme),
+            ..self
+        }
+    }
+
+    /// Specify the minimum zoom level of the tiles
+    #[must_use]
+    pub fn with_minzoom(self, minzoom: impl Into<f64>) -> Self {
+        Self {
+            minzoom: Some(minzoom.into()),
+            ..self
+        }
+    }
+
+    /// Specify the maximum zoom level of the tiles
+    #[must_use]
+    pub fn with_maxzoom(self, maxzoom: impl Into<f64>) -> Self {
+        Self {
+            maxzoom: Some(maxzoom.into()),
+            ..self
+        }
+    }
+
+    /// Specify the attribution displayed when the map shows data from the source
+    #[must_use]
+    pub fn with_attribution(self, attribution: impl Into<String>) -> Self {
+        Self {
+            attribution: Some(attribution.into()),
+            ..self
+        }
+    }
+
+    /// Specify the property of the features to use as their identifier
+    #[must_use]
+    pub fn with_promote_id(self, promote_id: impl Into<PromoteId>) -> Self {
+        Self {
+            promote_id: Some(promote_id.into()),
+            ..self
+        }
+    }
+
+    /// Discard the tiles from the cache when they are no longer displayed
+    #[must_use]
+    pub fn with_volatile(self) -> Self {
+        Self {
+            volatile: Some(true),
+            ..self
+        }
+    }
+
+    /// Keep the tiles in the cache when they are no longer displayed
+    #[must_use]
+    pub fn without_volatile(self) -> Self {
+        Self {
+            volatile: Some(false),
+            ..self
+        }
+    }
+
+    /// Specify the encoding of the tiles
+    #[must_use]
+    pub fn with_encoding(self, encoding: VectorEncoding) -> Self {
+        Self {
+            encoding: Some(encoding),
+            ..self
+        }
+    }
+}
+
+/// Encoding of vector tiles
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VectorEncoding {
+    /// Mapbox Vector Tiles
+    Mvt,
+    /// MapLibre Tiles
+    Mlt,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn vector_source_specification_serialization() {
+        let source = VectorSourceSpecification::from_url("https://example.com/tiles.json")
+            .with_scheme(TileScheme::Tms)
+            .with_promote_id("osm_id")
+            .with_encoding(VectorEncoding::Mlt)
+            .with_volatile();
+
+        assert_eq!(
+            serde_json::to_value(&source).expect("Serializing should work"),
+            json!({
+                "type": "vector",
+                "url": "https://example.com/tiles.json",
+                "scheme": "tms",
+                "promoteId": "osm_id",
+                "volatile": true,
+                "encoding": "mlt",
+            })
+        );
+    }
+}
@@ -0,0 +1,206 @@
+//! Submodule for the `VideoSource` wrapper and associated tests
+
+use web_sys::HtmlVideoElement;
+
+use super::{Error, Source, image_source::corner_coordinates_to_js};
+use crate::{bindings, interface::LngLatLike};
+
+/// Source of a video placed on the map by the longitude and latitude of its corners, obtained
+/// by converting the `Source` returned by `Map::get_source`
+#[derive(Debug, Clone, PartialEq)]
+pub struct VideoSource {
+    /// JS object that the struct wraps
+    js_value: bindings::VideoSource,
+}
+
+impl VideoSource {
+    /// Get the identifier of the source
+    #[must_use]
+    pub fn id(&self) -> String {
+        self.js_value.id()
+    }
+
+    /// Get the longitude and latitude of the top left, top right, bottom right and bottom left
+    /// corners of the video
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn coordinates(&self) -> Result<[[f64; 2]; 4], Error> {
+        serde_wasm_bindgen::from_value(self.js_value.coordinates())
+    }
+
+    /// Place the video on the given top left, top right, bottom right and bottom left corners
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors and errors generated from JS
+    pub fn set_coordinates(&self, coordinates: [impl Into<LngLatLike>; 4]) -> Result<(), Error> {
+        self.js_value
+            .set_coordinates(&corner_coordinates_to_js(coordinates)?)?;
+        Ok(())
+    }
+
+    /// Play the video
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors generated from JS, such as when the video hasn't loaded yet
+    pub fn play(&self) -> Result<(), Error> {
+        self.js_value.play()?;
+        Ok(())
+    }
+
+    /// Pause the video
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors generated from JS, such as when the video hasn't loaded yet
+    pub fn pause(&self) -> Result<(), Error> {
+        self.js_value.pause()?;
+        Ok(())
+    }
+
+    /// Move the playback position of the video to the given time in seconds
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors generated from JS, such as when the video hasn't loaded yet
+    pub fn seek(&self, seconds: f64) -> Result<(), Error> {
+        self.js_value.seek(seconds)?;
+        Ok(())
+    }
+
+    /// Get the HTML video element, once the video has loaded
+    #[must_use]
+    pub fn get_video(&self) -> Option<HtmlVideoElement> {
+        self.js_value.get_video()
+    }
+
+    /// Get the underlying JS object
+    #[must_use]
+    pub fn into_inner(self) -> bindings::VideoSource {
+        self.js_value
+    }
+}
+
+impl From<bindings::VideoSource> for VideoSource {
+    fn from(value: bindings::VideoSource) -> Self {
+        Self { js_value: value }
+    }
+}
+
+impl TryFrom<Source> for VideoSource {
+    type Error = Error;
+
+    fn try_from(value: Source) -> Result<Self, Self::Error> {
+        value
+            .downcast::<bindings::VideoSource>("video")
+            .map(Into::into)
+    }
+}
+
+impl From<VideoSource> for Source {
+    fn from(value: VideoSource) -> Self {
+        bindings::Source::from(value.js_value).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        interface::{MapOptions, sources::VideoSourceSpecification},
+        test_utils::{gen_html_element, gen_sized_container, load_maplibre_gl},
+    };
+    use serde_json::json;
+    use wasm_bindgen_futures::JsFuture;
+    use wasm_bindgen_test::*;
+    use web_sys::{HtmlMediaElement, js_sys::Promise};
+
+    #[wasm_bindgen_test]
+    async fn video_source_set_coordinates() {
+        load_maplibre_gl().await;
+        let html_element = gen_html_element("div").cloned_ref();
+        let map = MapOptions::new(html_element)
+            .with_style(json!({"version": 8, "sources": {}, "layers": []}))
+            .build_async()
+            .await
+            .expect("Creating a map should work");
+        map.add_source(
+            "weather",
+            &VideoSourceSpecification::new(
+                ["https://example.com/weather.mp4"],
+                [
+                    [-122.5, 37.8],
+                    [-122.4, 37.8],
+                    [-122.4, 37.7],
+                    [-122.5, 37.7],
+                ],
+            ),
+        )
+        .expect("Adding a source should work");
+        let source = VideoSource::try_from(map.get_source("weather").expect("Should exist"))
+            .expect("The source should be a video source");
+
+        source
+            .set_coordinates([(-10., 10.), (10., 10.), (10., -10.), (-10., -10.)])
+            .expect("Setting the coordinates should work");
+
+        assert_eq!(source.id(), "weather");
+        assert_eq!(
+            source.coordinates().expect("Should get the coordinates"),
+            [[-10., 10.], [10., 10.], [10., -10.], [-10., -10.]]
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn video_source_play_pause_seek() {
+        load_maplibre_gl().await;
+        let container = gen_sized_container(400, 300);
+        let map = MapOptions::new(container.cloned_ref())
+            .with_style(json!({"version": 8, "sources": {}, "layers": []}))
+            .build_async()
+            .await
+            .expect("Creating a map should work");
+        map.add_source(
+            "drone",
+            &VideoSourceSpecification::new(
+                [
+                    "https://static-assets.mapbox.com/mapbox-gl-js/drone.mp4",
+                    "https://static-assets.mapbox.com/mapbox-gl-js/drone.webm",
+                ],
+                [
+                    [-122.5, 37.8],
+                    [-122.4, 37.8],
+                    [-122.4, 37.7],
+                    [-122.5, 37.7],
+                ],
+            ),
+        )
+        .expect("Adding a source should work");
+        let source = VideoSource::try_from(map.get_source("drone").expect("Should exist"))
+            .expect("The source should be a video source");
+        JsFuture::from(map.clone().into_inner().once_promise("idle"))
+            .await
+            .expect("The map should become idle");
+        let video = source.get_video().expect("The video should have loaded");
+        if video.ready_state() < HtmlMediaElement::HAVE_METADATA {
+            let metadata = Promise::new(&mut |resolve, _reject| {
+                video.set_onloadedmetadata(Some(&resolve));
+            });
+            JsFuture::from(metadata)
+                .await
+                .expect("The metadata of the video should load");
+        }
+
+        source.pause().expect("Pausing the video should work");
+        assert!(video.paused());
+
+        source.seek(1.).expect("Seeking the video should work");
+        assert!((video.current_time() - 1.).abs() < 0.001);
+
+        source.play().expect("Playing the video should work");
+        assert!(!video.paused());
+    }
+}
@@ -0,0 +1,69 @@
+//! Submodule for `VideoSourceSpecification` and associated tests
+
+use serde::{Deserialize, Serialize};
+
+use super::image_source_specification::corner_coordinates;
+use crate::interface::LngLatLike;
+
+/// Specification of a source of a video placed on the map by the longitude and latitude of its
+/// corners.
+///
+/// MapLibre docs: <https://maplibre.org/maplibre-style-spec/sources/#video>
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(remote = "Self", tag = "type", rename = "video", deny_unknown_fields)]
+pub struct VideoSourceSpecification {
+    /// URLs of the video in different formats, the first one supported by the browser is used
+    pub urls: Vec<String>,
+    /// Longitude and latitude of the top left, top right, bottom right and bottom left corners
+    /// of the video
+    pub coordinates: [[f64; 2]; 4],
+}
+
+impl_source_specification_serde!(VideoSourceSpecification, "video");
+
+impl VideoSourceSpecification {
+    /// Create a `VideoSourceSpecification` with the video at the given URLs placed on the given
+    /// top left, top right, bottom right and bottom left corners
+    #[must_use]
+    pub fn new(
+        urls: impl IntoIterator<Item = impl Into<String>>,
+        coordinates: [impl Into<LngLatLike>; 4],
+    ) -> Self {
+        Self {
+            urls: urls.into_iter().map(Into::into).collect(),
+            coordinates: corner_coordinates(coordinates),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn video_source_specification_serialization() {
+        let source = VideoSourceSpecification::new(
+            [
+                "https://example.com/weather.mp4",
+                "https://example.com/weather.webm",
+            ],
+            [
+                (-122.5, 37.8),
+                (-122.4, 37.8),
+                (-122.4, 37.7),
+                (-122.5, 37.7),
+            ],
+        );
+
+        assert_eq!(
+            serde_json::to_value(&source).expect("Serializing should work"),
+            json!({
+                "type": "video",
+                "urls": ["https://example.com/weather.mp4", "https://example.com/weather.webm"],
+                "coordinates": [[-122.5, 37.8], [-122.4, 37.8], [-122.4, 37.7], [-122.5, 37.7]],
+            })
+        );
+    }
+}
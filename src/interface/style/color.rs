@@ -0,0 +1,78 @@
+//! Submodule for `Color` and associated tests
+
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+/// Color value of a style property, written as a CSS color string such as `#ff0000`,
+/// `rgba(255, 0, 0, 0.5)` or `red`.
+///
+/// MapLibre docs: <https://maplibre.org/maplibre-style-spec/types/#color>
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Color(String);
+
+impl Color {
+    /// Create a `Color` from a CSS color string
+    #[must_use]
+    pub fn new(css: impl Into<String>) -> Self {
+        Self(css.into())
+    }
+
+    /// Create an opaque `Color` from its red, green and blue components
+    #[must_use]
+    pub fn rgb(red: u8, green: u8, blue: u8) -> Self {
+        Self(format!("rgb({red}, {green}, {blue})"))
+    }
+
+    /// Create a `Color` from its red, green and blue components and its alpha between `0` and
+    /// `1`
+    #[must_use]
+    pub fn rgba(red: u8, green: u8, blue: u8, alpha: f64) -> Self {
+        Self(format!("rgba({red}, {green}, {blue}, {alpha})"))
+    }
+
+    /// Get the CSS color string
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for Color {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<&str> for Color {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<String> for Color {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn color_serialization() {
+        assert_eq!(
+            serde_json::to_value(Color::from("#ff0000")).expect("Serializing should work"),
+            json!("#ff0000")
+        );
+        assert_eq!(
+            serde_json::to_value(Color::rgba(255, 0, 0, 0.5)).expect("Serializing should work"),
+            json!("rgba(255, 0, 0, 0.5)")
+        );
+        assert_eq!(Color::rgb(0, 128, 255).as_str(), "rgb(0, 128, 255)");
+    }
+}
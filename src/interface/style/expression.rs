@@ -0,0 +1,725 @@
+//! Submodule for `Expression` and associated types and tests
+
+use std::{
+    marker::PhantomData,
+    ops::{Add, Div, Mul, Neg, Not, Rem, Sub},
+};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error as _};
+use serde_json::Value;
+
+use super::Color;
+
+/// Expression computing a value of type `T` from the zoom level, the properties of a feature or
+/// other expressions, serialized in the JSON array form of the style specification.
+///
+/// The result type prevents using an expression where a value of another type is expected, so
+/// that an `Expression<f64>` cannot be given to a property holding a `Color`.
+///
+/// MapLibre docs: <https://maplibre.org/maplibre-style-spec/expressions/>
+pub struct Expression<T> {
+    /// JSON array form of the expression
+    json: Value,
+    /// Type of the value computed by the expression
+    output: PhantomData<fn() -> T>,
+}
+
+/// Create an `Expression` applying the operator `name` to the given arguments
+fn operator<T>(name: &str, arguments: impl IntoIterator<Item = Value>) -> Expression<T> {
+    let mut json = vec![Value::from(name)];
+    json.extend(arguments);
+    Expression::raw(Value::Array(json))
+}
+
+impl<T> Expression<T> {
+    /// Create an `Expression` from its JSON array form without checking it
+    #[must_use]
+    pub const fn raw(json: Value) -> Self {
+        Self {
+            json,
+            output: PhantomData,
+        }
+    }
+
+    /// Get the JSON array form of the expression
+    #[must_use]
+    pub const fn as_json(&self) -> &Value {
+        &self.json
+    }
+
+    /// Convert the expression into its JSON array form
+    #[must_use]
+    pub fn into_json(self) -> Value {
+        self.json
+    }
+
+    /// Create an `Expression` with a literal value. Arrays and objects are wrapped in a
+    /// `literal` expression so that they are not evaluated
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` can't be represented as JSON, such as a map with non-string keys. Use
+    /// `Expression::try_literal` for values which may not be representable
+    #[must_use]
+    pub fn literal(value: T) -> Self
+    where
+        T: Serialize,
+    {
+        Self::try_literal(value).expect("Literal values should be representable as JSON")
+    }
+
+    /// Try creating an `Expression` with a literal value. Arrays and objects are wrapped in a
+    /// `literal` expression so that they are not evaluated
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_json` errors if `value` can't be represented as JSON, such as a map
+    /// with non-string keys
+    pub fn try_literal(value: T) -> Result<Self, serde_json::Error>
+    where
+        T: Serialize,
+    {
+        Ok(match serde_json::to_value(value)? {
+            json @ (Value::Array(_) | Value::Object(_)) => operator("literal", [json]),
+            json => Self::raw(json),
+        })
+    }
+
+    /// Create a `get` expression retrieving the property of the feature with the given name
+    #[must_use]
+    pub fn get(property: impl Into<String>) -> Self {
+        operator("get", [Value::from(property.into())])
+    }
+
+    /// Create a `feature-state` expression retrieving the state of the feature with the given
+    /// name
+    #[must_use]
+    pub fn feature_state(property: impl Into<String>) -> Self {
+        operator("feature-state", [Value::from(property.into())])
+    }
+
+    /// Create an `id` expression retrieving the identifier of the feature
+    #[must_use]
+    pub fn id() -> Self {
+        operator("id", [])
+    }
+
+    /// Create a `match` expression comparing `input` to the labels of each branch, evaluating
+    /// to the output of the first matching branch or to `fallback` if none matches. A label is
+    /// either a single number or string, or an array of them
+    #[must_use]
+    pub fn r#match<I, L, E>(
+        input: Expression<I>,
+        branches: impl IntoIterator<Item = (L, E)>,
+        fallback: impl Into<Self>,
+    ) -> Self
+    where
+        L: Into<Value>,
+        E: Into<Self>,
+    {
+        let mut arguments = vec![input.json];
+        for (label, output) in branches {
+            arguments.push(label.into());
+            arguments.push(output.into().json);
+        }
+        arguments.push(fallback.into().json);
+        operator("match", arguments)
+    }
+
+    /// Create a `case` expression evaluating to the output of the first branch whose condition
+    /// is true, or to `fallback` if none is
+    #[must_use]
+    pub fn case<E>(
+        branches: impl IntoIterator<Item = (Expression<bool>, E)>,
+        fallback: impl Into<Self>,
+    ) -> Self
+    where
+        E: Into<Self>,
+    {
+        let mut arguments = Vec::new();
+        for (condition, output) in branches {
+            arguments.push(condition.json);
+            arguments.push(output.into().json);
+        }
+        arguments.push(fallback.into().json);
+        operator("case", arguments)
+    }
+
+    /// Create a `step` expression evaluating to `base` when `input` is below the first stop, or
+    /// to the output of the greatest stop less than or equal to `input` otherwise. Stops must
+    /// be in strictly ascending order
+    #[must_use]
+    pub fn step<E>(
+        input: Expression<f64>,
+        base: impl Into<Self>,
+        stops: impl IntoIterator<Item = (f64, E)>,
+    ) -> Self
+    where
+        E: Into<Self>,
+    {
+        let mut arguments = vec![input.json, base.into().json];
+        for (stop, output) in stops {
+            arguments.push(Value::from(stop));
+            arguments.push(output.into().json);
+        }
+        operator("step", arguments)
+    }
+
+    /// Create an `interpolate` expression producing a continuous result by interpolating
+    /// between the outputs of the stops surrounding `input`. Stops must be in strictly
+    /// ascending order
+    #[must_use]
+    pub fn interpolate<E>(
+        interpolation: Interpolation,
+        input: Expression<f64>,
+        stops: impl IntoIterator<Item = (f64, E)>,
+    ) -> Self
+    where
+        T: Interpolatable,
+        E: Into<Self>,
+    {
+        let mut arguments = vec![interpolation.into_json(), input.json];
+        for (stop, output) in stops {
+            arguments.push(Value::from(stop));
+            arguments.push(output.into().json);
+        }
+        operator("interpolate", arguments)
+    }
+
+    /// Create a `coalesce` expression evaluating to the first of the given expressions that is
+    /// not null
+    #[must_use]
+    pub fn coalesce<E>(values: impl IntoIterator<Item = E>) -> Self
+    where
+        E: Into<Self>,
+    {
+        operator(
+            "coalesce",
+            values.into_iter().map(|value| value.into().json),
+        )
+    }
+
+    /// Create a `==` expression checking whether both expressions evaluate to the same value
+    #[must_use]
+    pub fn equals(self, other: impl Into<Self>) -> Expression<bool> {
+        operator("==", [self.json, other.into().json])
+    }
+
+    /// Create a `!=` expression checking whether the expressions evaluate to different values
+    #[must_use]
+    pub fn not_equals(self, other: impl Into<Self>) -> Expression<bool> {
+        operator("!=", [self.json, other.into().json])
+    }
+
+    /// Create a `to-string` expression converting the result of the expression to a string
+    #[must_use]
+    pub fn into_string(self) -> Expression<String> {
+        operator("to-string", [self.json])
+    }
+
+    /// Create a `to-number` expression converting the result of the expression to a number
+    #[must_use]
+    pub fn into_number(self) -> Expression<f64> {
+        operator("to-number", [self.json])
+    }
+
+    /// Create a `to-boolean` expression converting the result of the expression to a boolean
+    #[must_use]
+    pub fn into_boolean(self) -> Expression<bool> {
+        operator("to-boolean", [self.json])
+    }
+
+    /// Create a `to-color` expression converting the result of the expression to a color
+    #[must_use]
+    pub fn into_color(self) -> Expression<Color> {
+        operator("to-color", [self.json])
+    }
+}
+
+impl Expression<f64> {
+    /// Create a `zoom` expression evaluating to the current zoom level
+    #[must_use]
+    pub fn zoom() -> Self {
+        operator("zoom", [])
+    }
+
+    /// Create a `heatmap-density` expression evaluating to the density of a pixel of a heatmap
+    /// layer
+    #[must_use]
+    pub fn heatmap_density() -> Self {
+        operator("heatmap-density", [])
+    }
+
+    /// Create a `line-progress` expression evaluating to the progress along a line with a
+    /// gradient
+    #[must_use]
+    pub fn line_progress() -> Self {
+        operator("line-progress", [])
+    }
+
+    /// Create a `pi` expression
+    #[must_use]
+    pub fn pi() -> Self {
+        operator("pi", [])
+    }
+
+    /// Create an `e` expression
+    #[must_use]
+    pub fn e() -> Self {
+        operator("e", [])
+    }
+
+    /// Create a `^` expression raising the result of the expression to the given power
+    #[must_use]
+    pub fn pow(self, exponent: impl Into<Self>) -> Self {
+        operator("^", [self.json, exponent.into().json])
+    }
+
+    /// Create a `min` expression evaluating to the minimum of both expressions
+    #[must_use]
+    pub fn min(self, other: impl Into<Self>) -> Self {
+        operator("min", [self.json, other.into().json])
+    }
+
+    /// Create a `max` expression evaluating to the maximum of both expressions
+    #[must_use]
+    pub fn max(self, other: impl Into<Self>) -> Self {
+        operator("max", [self.json, other.into().json])
+    }
+
+    /// Create an `abs` expression
+    #[must_use]
+    pub fn abs(self) -> Self {
+        operator("abs", [self.json])
+    }
+
+    /// Create a `ceil` expression
+    #[must_use]
+    pub fn ceil(self) -> Self {
+        operator("ceil", [self.json])
+    }
+
+    /// Create a `floor` expression
+    #[must_use]
+    pub fn floor(self) -> Self {
+        operator("floor", [self.json])
+    }
+
+    /// Create a `round` expression, rounding halfway values away from zero
+    #[must_use]
+    pub fn round(self) -> Self {
+        operator("round", [self.json])
+    }
+
+    /// Create a `sqrt` expression
+    #[must_use]
+    pub fn sqrt(self) -> Self {
+        operator("sqrt", [self.json])
+    }
+
+    /// Create a `ln` expression computing the natural logarithm
+    #[must_use]
+    pub fn ln(self) -> Self {
+        operator("ln", [self.json])
+    }
+
+    /// Create a `log10` expression computing the base-ten logarithm
+    #[must_use]
+    pub fn log10(self) -> Self {
+        operator("log10", [self.json])
+    }
+
+    /// Create a `<` expression
+    #[must_use]
+    pub fn lt(self, other: impl Into<Self>) -> Expression<bool> {
+        operator("<", [self.json, other.into().json])
+    }
+
+    /// Create a `<=` expression
+    #[must_use]
+    pub fn le(self, other: impl Into<Self>) -> Expression<bool> {
+        operator("<=", [self.json, other.into().json])
+    }
+
+    /// Create a `>` expression
+    #[must_use]
+    pub fn gt(self, other: impl Into<Self>) -> Expression<bool> {
+        operator(">", [self.json, other.into().json])
+    }
+
+    /// Create a `>=` expression
+    #[must_use]
+    pub fn ge(self, other: impl Into<Self>) -> Expression<bool> {
+        operator(">=", [self.json, other.into().json])
+    }
+}
+
+impl Expression<bool> {
+    /// Create a `has` expression checking whether the feature has the property with the given
+    /// name
+    #[must_use]
+    pub fn has(property: impl Into<String>) -> Self {
+        operator("has", [Value::from(property.into())])
+    }
+
+    /// Create an `all` expression checking whether all the conditions are true
+    #[must_use]
+    pub fn all(conditions: impl IntoIterator<Item = Self>) -> Self {
+        operator("all", conditions.into_iter().map(Self::into_json))
+    }
+
+    /// Create an `any` expression checking whether any of the conditions is true
+    #[must_use]
+    pub fn any(conditions: impl IntoIterator<Item = Self>) -> Self {
+        operator("any", conditions.into_iter().map(Self::into_json))
+    }
+}
+
+impl Expression<String> {
+    /// Create a `concat` expression joining the given strings
+    #[must_use]
+    pub fn concat<E>(values: impl IntoIterator<Item = E>) -> Self
+    where
+        E: Into<Self>,
+    {
+        operator("concat", values.into_iter().map(|value| value.into().json))
+    }
+
+    /// Create an `upcase` expression converting the string to uppercase
+    #[must_use]
+    pub fn upcase(self) -> Self {
+        operator("upcase", [self.json])
+    }
+
+    /// Create a `downcase` expression converting the string to lowercase
+    #[must_use]
+    pub fn downcase(self) -> Self {
+        operator("downcase", [self.json])
+    }
+
+    /// Create a `geometry-type` expression evaluating to the type of geometry of the feature
+    #[must_use]
+    pub fn geometry_type() -> Self {
+        operator("geometry-type", [])
+    }
+}
+
+impl Expression<Color> {
+    /// Create an `rgb` expression from red, green and blue components between `0` and `255`
+    #[must_use]
+    pub fn rgb(
+        red: impl Into<Expression<f64>>,
+        green: impl Into<Expression<f64>>,
+        blue: impl Into<Expression<f64>>,
+    ) -> Self {
+        operator(
+            "rgb",
+            [red.into().json, green.into().json, blue.into().json],
+        )
+    }
+
+    /// Create an `rgba` expression from red, green and blue components between `0` and `255`
+    /// and an alpha component between `0` and `1`
+    #[must_use]
+    pub fn rgba(
+        red: impl Into<Expression<f64>>,
+        green: impl Into<Expression<f64>>,
+        blue: impl Into<Expression<f64>>,
+        alpha: impl Into<Expression<f64>>,
+    ) -> Self {
+        operator(
+            "rgba",
+            [
+                red.into().json,
+                green.into().json,
+                blue.into().json,
+                alpha.into().json,
+            ],
+        )
+    }
+}
+
+/// Implement a binary arithmetic operator of `Expression<f64>`
+macro_rules! impl_arithmetic_operator {
+    ($trait:ident, $method:ident, $operator:literal) => {
+        impl<R: Into<Self>> $trait<R> for Expression<f64> {
+            type Output = Self;
+
+            fn $method(self, rhs: R) -> Self {
+                operator($operator, [self.json, rhs.into().json])
+            }
+        }
+    };
+}
+
+impl_arithmetic_operator!(Add, add, "+");
+impl_arithmetic_operator!(Sub, sub, "-");
+impl_arithmetic_operator!(Mul, mul, "*");
+impl_arithmetic_operator!(Div, div, "/");
+impl_arithmetic_operator!(Rem, rem, "%");
+
+impl Neg for Expression<f64> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        operator("-", [self.json])
+    }
+}
+
+impl Not for Expression<bool> {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        operator("!", [self.json])
+    }
+}
+
+/// Create an `Expression` with a literal value with `Expression::literal`
+///
+/// # Panics
+///
+/// Panics if `value` can't be represented as JSON, such as a map with non-string keys. Use
+/// `Expression::try_literal` for values which may not be representable
+impl<T: Serialize> From<T> for Expression<T> {
+    fn from(value: T) -> Self {
+        Self::literal(value)
+    }
+}
+
+impl From<&str> for Expression<String> {
+    fn from(value: &str) -> Self {
+        Self::literal(value.to_string())
+    }
+}
+
+impl From<&str> for Expression<Color> {
+    fn from(value: &str) -> Self {
+        Self::literal(Color::from(value))
+    }
+}
+
+impl<T> Clone for Expression<T> {
+    fn clone(&self) -> Self {
+        Self::raw(self.json.clone())
+    }
+}
+
+impl<T> PartialEq for Expression<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.json == other.json
+    }
+}
+
+impl<T> Eq for Expression<T> {}
+
+impl<T> std::fmt::Debug for Expression<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Expression").field(&self.json).finish()
+    }
+}
+
+impl<T> Serialize for Expression<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.json.serialize(serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Expression<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let json = Value::deserialize(deserializer)?;
+        match json.as_array().and_then(|array| array.first()) {
+            Some(Value::String(_)) => Ok(Self::raw(json)),
+            _ => Err(D::Error::custom(
+                "expected an expression array starting with its operator",
+            )),
+        }
+    }
+}
+
+/// Type of the values that can be interpolated by an `interpolate` expression
+pub trait Interpolatable {}
+
+impl Interpolatable for f64 {}
+impl Interpolatable for Color {}
+
+/// Type of interpolation used by an `interpolate` expression
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Interpolation {
+    /// Interpolate linearly between the stops
+    Linear,
+    /// Interpolate exponentially between the stops, with the given base controlling the rate
+    /// at which the output increases
+    Exponential(f64),
+    /// Interpolate using the cubic bezier curve defined by the given control points
+    CubicBezier(f64, f64, f64, f64),
+}
+
+impl Interpolation {
+    /// Convert the interpolation into its JSON array form
+    fn into_json(self) -> Value {
+        match self {
+            Self::Linear => Value::from(["linear"]),
+            Self::Exponential(base) => serde_json::json!(["exponential", base]),
+            Self::CubicBezier(x1, y1, x2, y2) => {
+                serde_json::json!(["cubic-bezier", x1, y1, x2, y2])
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn expression_lookup_serialization() {
+        assert_eq!(
+            Expression::<f64>::get("height").into_json(),
+            json!(["get", "height"])
+        );
+        assert_eq!(Expression::has("name").into_json(), json!(["has", "name"]));
+        assert_eq!(
+            Expression::<bool>::feature_state("hover").into_json(),
+            json!(["feature-state", "hover"])
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn expression_literal_serialization() {
+        assert_eq!(Expression::literal(1.5).into_json(), json!(1.5));
+        assert_eq!(
+            Expression::literal([1., 2.]).into_json(),
+            json!(["literal", [1., 2.]])
+        );
+        assert_eq!(Expression::<String>::from("a").into_json(), json!("a"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Literal values should be representable as JSON")]
+    fn expression_literal_not_representable() {
+        let value = std::collections::BTreeMap::from([(vec![1_u8], 1_u8)]);
+
+        let _ = Expression::literal(value);
+    }
+
+    #[test]
+    fn expression_try_literal() {
+        let value = std::collections::BTreeMap::from([(vec![1_u8], 1_u8)]);
+
+        assert!(Expression::try_literal(value).is_err());
+        assert_eq!(
+            Expression::try_literal([1., 2.])
+                .expect("Arrays should be representable as JSON")
+                .into_json(),
+            json!(["literal", [1., 2.]])
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn expression_decision_serialization() {
+        let matched = Expression::<Color>::r#match(
+            Expression::<String>::get("class"),
+            [("park", "#00ff00"), ("water", "#0000ff")],
+            "#cccccc",
+        );
+        assert_eq!(
+            matched.into_json(),
+            json!([
+                "match",
+                ["get", "class"],
+                "park",
+                "#00ff00",
+                "water",
+                "#0000ff",
+                "#cccccc"
+            ])
+        );
+
+        let case =
+            Expression::<f64>::case([(Expression::has("height"), Expression::get("height"))], 0.);
+        assert_eq!(
+            case.into_json(),
+            json!(["case", ["has", "height"], ["get", "height"], 0.])
+        );
+
+        let coalesce =
+            Expression::<String>::coalesce([Expression::get("name_en"), Expression::get("name")]);
+        assert_eq!(
+            coalesce.into_json(),
+            json!(["coalesce", ["get", "name_en"], ["get", "name"]])
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn expression_ramp_serialization() {
+        let step = Expression::step(Expression::zoom(), 1., [(10., 2.), (15., 4.)]);
+        assert_eq!(
+            step.into_json(),
+            json!(["step", ["zoom"], 1., 10., 2., 15., 4.])
+        );
+
+        let interpolate = Expression::<Color>::interpolate(
+            Interpolation::Exponential(1.5),
+            Expression::heatmap_density(),
+            [(0., "rgba(0, 0, 0, 0)"), (1., "red")],
+        );
+        assert_eq!(
+            interpolate.into_json(),
+            json!([
+                "interpolate",
+                ["exponential", 1.5],
+                ["heatmap-density"],
+                0.,
+                "rgba(0, 0, 0, 0)",
+                1.,
+                "red"
+            ])
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn expression_operator_serialization() {
+        let math = (Expression::zoom() * 2. - 1.).max(Expression::get("min"));
+        assert_eq!(
+            math.into_json(),
+            json!(["max", ["-", ["*", ["zoom"], 2.], 1.], ["get", "min"]])
+        );
+
+        let condition = !Expression::all([
+            Expression::<f64>::get("rank").lt(5.),
+            Expression::geometry_type().equals("Point"),
+        ]);
+        assert_eq!(
+            condition.into_json(),
+            json!([
+                "!",
+                [
+                    "all",
+                    ["<", ["get", "rank"], 5.],
+                    ["==", ["geometry-type"], "Point"]
+                ]
+            ])
+        );
+
+        let label = Expression::concat([Expression::get("name"), " ".into()]).upcase();
+        assert_eq!(
+            label.into_json(),
+            json!(["upcase", ["concat", ["get", "name"], " "]])
+        );
+
+        let color = Expression::rgb(Expression::get("red"), 0., 0.);
+        assert_eq!(color.into_json(), json!(["rgb", ["get", "red"], 0., 0.]));
+    }
+
+    #[wasm_bindgen_test]
+    fn expression_deserialization() {
+        let expression: Expression<f64> =
+            serde_json::from_value(json!(["get", "height"])).expect("Deserializing should work");
+        assert_eq!(expression, Expression::get("height"));
+
+        assert!(serde_json::from_value::<Expression<f64>>(json!([1, 2])).is_err());
+        assert!(serde_json::from_value::<Expression<f64>>(json!(1)).is_err());
+    }
+}
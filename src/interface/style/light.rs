@@ -0,0 +1,34 @@
+//! Submodule for `LightSpecification` and associated types
+
+use serde::{Deserialize, Serialize};
+
+use super::{Color, PropertyValue};
+
+/// Global light source of the style, used by extruded layers
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LightSpecification {
+    /// Whether the light is anchored to the map or to the viewport. Defaults to `viewport`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub anchor: Option<PropertyValue<LightAnchor>>,
+    /// Position of the light as radial coordinate, azimuthal angle and polar angle. Defaults to
+    /// `[1.15, 210, 30]`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<PropertyValue<[f64; 3]>>,
+    /// Color tint for lighting extruded geometries. Defaults to `#ffffff`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<PropertyValue<Color>>,
+    /// Intensity of the lighting, between `0` and `1`. Defaults to `0.5`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub intensity: Option<PropertyValue<f64>>,
+}
+
+/// Reference frame of the position of the light
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LightAnchor {
+    /// The position of the light is relative to the map and follows its rotation
+    Map,
+    /// The position of the light is relative to the viewport
+    Viewport,
+}
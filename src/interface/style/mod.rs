@@ -0,0 +1,302 @@
+//! Rusty interface to the MapLibre Style Specification, describing the visual appearance of a
+//! `Map`
+
+mod color;
+mod expression;
+mod light;
+mod projection;
+mod property_value;
+mod sky;
+mod sprite;
+mod terrain;
+mod transition;
+mod validation;
+
+pub use color::Color;
+pub use expression::{Expression, Interpolatable, Interpolation};
+pub use light::{LightAnchor, LightSpecification};
+pub use projection::ProjectionSpecification;
+pub use property_value::PropertyValue;
+pub use sky::SkySpecification;
+pub use sprite::{SpriteSheet, SpriteSpecification};
+pub use terrain::TerrainSpecification;
+pub use transition::TransitionSpecification;
+pub use validation::{StyleValidationError, validate_style};
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsValue;
+
+use super::{Error, layers::LayerSpecification, sources::SourceSpecification};
+
+/// Version of the style specification supported by MapLibre
+pub const STYLE_SPECIFICATION_VERSION: u8 = 8;
+
+/// Root of a MapLibre style, defining the sources of data and the layers drawing them.
+///
+/// MapLibre docs: <https://maplibre.org/maplibre-style-spec/root/>
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct StyleSpecification {
+    /// Version of the style specification. Must be `8`
+    pub version: u8,
+    /// Name of the style
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Arbitrary properties useful to track with the style
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Value>,
+    /// Default longitude and latitude of the map center
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub center: Option<[f64; 2]>,
+    /// Default altitude of the map center in meters above sea level
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub center_altitude: Option<f64>,
+    /// Default zoom level of the map
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zoom: Option<f64>,
+    /// Default bearing of the map in degrees counter-clockwise from north
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bearing: Option<f64>,
+    /// Default pitch of the map in degrees away from the plane of the screen
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pitch: Option<f64>,
+    /// Default roll of the map in degrees counter-clockwise about the camera boresight
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub roll: Option<f64>,
+    /// Global light source
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub light: Option<LightSpecification>,
+    /// Sky, horizon and fog rendered when the map is pitched
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sky: Option<SkySpecification>,
+    /// Projection used to render the map
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub projection: Option<ProjectionSpecification>,
+    /// 3D terrain
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub terrain: Option<TerrainSpecification>,
+    /// Sources of data used by the layers, indexed by their identifier
+    pub sources: BTreeMap<String, SourceSpecification>,
+    /// Location of the sprites used by the style
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sprite: Option<SpriteSpecification>,
+    /// URL template to load signed-distance-field glyphs, containing `{fontstack}` and `{range}`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub glyphs: Option<String>,
+    /// Default timing of the transitions between values of the paint properties
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transition: Option<TransitionSpecification>,
+    /// Layers of the style, drawn in order from bottom to top
+    pub layers: Vec<LayerSpecification>,
+}
+
+impl StyleSpecification {
+    /// Create an empty `StyleSpecification` without sources or layers
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a source with the given identifier, replacing any existing source with it
+    #[must_use]
+    pub fn with_source(
+        mut self,
+        id: impl Into<String>,
+        source: impl Into<SourceSpecification>,
+    ) -> Self {
+        self.sources.insert(id.into(), source.into());
+        self
+    }
+
+    /// Add a layer on top of the existing ones
+    #[must_use]
+    pub fn with_layer(mut self, layer: impl Into<LayerSpecification>) -> Self {
+        self.layers.push(layer.into());
+        self
+    }
+
+    /// Try converting `StyleSpecification` into the equivalent `JsValue`
+    ///
+    /// # Errors
+    ///
+    /// Propagates `serde_wasm_bindgen` conversion errors
+    pub fn as_js_value(&self) -> Result<JsValue, Error> {
+        self.serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+    }
+}
+
+impl Default for StyleSpecification {
+    fn default() -> Self {
+        Self {
+            version: STYLE_SPECIFICATION_VERSION,
+            name: None,
+            metadata: None,
+            center: None,
+            center_altitude: None,
+            zoom: None,
+            bearing: None,
+            pitch: None,
+            roll: None,
+            light: None,
+            sky: None,
+            projection: None,
+            terrain: None,
+            sources: BTreeMap::new(),
+            sprite: None,
+            glyphs: None,
+            transition: None,
+            layers: Vec::new(),
+        }
+    }
+}
+
+impl TryFrom<JsValue> for StyleSpecification {
+    type Error = Error;
+
+    fn try_from(value: JsValue) -> Result<Self, Self::Error> {
+        serde_wasm_bindgen::from_value(value)
+    }
+}
+
+impl TryFrom<StyleSpecification> for JsValue {
+    type Error = Error;
+
+    fn try_from(value: StyleSpecification) -> Result<Self, Self::Error> {
+        value.as_js_value()
+    }
+}
+
+impl TryFrom<&StyleSpecification> for JsValue {
+    type Error = Error;
+
+    fn try_from(value: &StyleSpecification) -> Result<Self, Self::Error> {
+        value.as_js_value()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_utils::{get_key_list_from_object, get_value_from_object};
+
+    use super::*;
+    use crate::interface::{layers::RasterLayerSpecification, sources::RasterSourceSpecification};
+    use serde_json::json;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn style_specification_empty() {
+        let style_js = StyleSpecification::new()
+            .as_js_value()
+            .expect("Conversion from StyleSpecification to JS should work");
+        let mut keys = get_key_list_from_object(&style_js);
+        keys.sort();
+        let version: f64 = get_value_from_object(&style_js, "version")
+            .try_into()
+            .expect("Back conversion should work");
+
+        assert_eq!(keys, vec!["layers", "sources", "version"]);
+        assert!((version - 8.).abs() < 0.001);
+    }
+
+    #[wasm_bindgen_test]
+    fn style_specification_renamed_fields() {
+        let style = StyleSpecification {
+            center_altitude: Some(100.),
+            sky: Some(SkySpecification {
+                sky_color: Some(Color::from("#199EF3").into()),
+                fog_ground_blend: Some(0.5.into()),
+                ..Default::default()
+            }),
+            projection: Some(ProjectionSpecification::new("globe")),
+            terrain: Some(TerrainSpecification::new("dem")),
+            sprite: Some("https://example.com/sprite".into()),
+            transition: Some(TransitionSpecification {
+                duration: Some(500.),
+                delay: None,
+            }),
+            ..StyleSpecification::new()
+        };
+
+        assert_eq!(
+            serde_json::to_value(&style).expect("Serializing should work"),
+            json!({
+                "version": 8,
+                "centerAltitude": 100.0,
+                "sky": {"sky-color": "#199EF3", "fog-ground-blend": 0.5},
+                "projection": {"type": "globe"},
+                "terrain": {"source": "dem"},
+                "sources": {},
+                "sprite": "https://example.com/sprite",
+                "transition": {"duration": 500.0},
+                "layers": [],
+            })
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn style_specification_round_trip() {
+        let style = StyleSpecification::new()
+            .with_source(
+                "satellite",
+                RasterSourceSpecification::from_tiles([
+                    "https://tiles.maps.eox.at/wmts/1.0.0/s2cloudless-2020_3857/default/g/{z}/{y}/{x}.jpg",
+                ])
+                .with_tile_size(256),
+            )
+            .with_layer(RasterLayerSpecification::new("satellite", "satellite"));
+        let style_js = style
+            .as_js_value()
+            .expect("Conversion from StyleSpecification to JS should work");
+        let retrieved: StyleSpecification =
+            style_js.try_into().expect("Back conversion should work");
+
+        assert_eq!(retrieved, style);
+    }
+
+    #[test]
+    fn style_specification_rejects_unknown_fields() {
+        let misspelled = json!({
+            "version": 8,
+            "sources": {},
+            "layer": [],
+        });
+        let nested = json!({
+            "version": 8,
+            "sources": {},
+            "layers": [],
+            "transition": {"duraton": 500},
+        });
+        let layer = json!({
+            "version": 8,
+            "sources": {},
+            "layers": [{"id": "background", "type": "background", "filtr": true}],
+        });
+        let paint = json!({
+            "version": 8,
+            "sources": {"polygons": {"type": "geojson", "data": "polygons.geojson"}},
+            "layers": [{
+                "id": "polygons",
+                "type": "fill",
+                "source": "polygons",
+                "paint": {"fill-colr": "red"},
+            }],
+        });
+        let layout = json!({
+            "version": 8,
+            "sources": {},
+            "layers": [{
+                "id": "background",
+                "type": "background",
+                "layout": {"visibilty": "none"},
+            }],
+        });
+
+        assert!(serde_json::from_value::<StyleSpecification>(misspelled).is_err());
+        assert!(serde_json::from_value::<StyleSpecification>(nested).is_err());
+        assert!(serde_json::from_value::<StyleSpecification>(layer).is_err());
+        assert!(serde_json::from_value::<StyleSpecification>(paint).is_err());
+        assert!(serde_json::from_value::<StyleSpecification>(layout).is_err());
+    }
+}
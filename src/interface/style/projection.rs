@@ -0,0 +1,23 @@
+//! Submodule for `ProjectionSpecification`
+
+use serde::{Deserialize, Serialize};
+
+use super::PropertyValue;
+
+/// Projection used to render the map
+#[derive(Debug, PartialEq, Eq, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProjectionSpecification {
+    /// Name of the projection, such as `mercator` or `globe`. Defaults to `mercator`
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub projection_type: Option<PropertyValue<String>>,
+}
+
+impl ProjectionSpecification {
+    /// Create a `ProjectionSpecification` with the given projection name
+    pub fn new(projection_type: impl Into<String>) -> Self {
+        Self {
+            projection_type: Some(PropertyValue::Literal(projection_type.into())),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+//! Submodule for `PropertyValue` and associated tests
+
+use serde::{Deserialize, Serialize};
+
+use super::Expression;
+
+/// Value of a style property, either a literal or an expression evaluated by MapLibre
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PropertyValue<T> {
+    /// Literal value of the property
+    Literal(T),
+    /// Expression computing the value of the property
+    Expression(Expression<T>),
+}
+
+impl<T> From<T> for PropertyValue<T> {
+    fn from(value: T) -> Self {
+        Self::Literal(value)
+    }
+}
+
+impl<T> From<Expression<T>> for PropertyValue<T> {
+    fn from(value: Expression<T>) -> Self {
+        Self::Expression(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::interface::style::Interpolation;
+    use serde_json::json;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn property_value_literal_round_trip() {
+        let value: PropertyValue<f64> = 0.5.into();
+        let json = serde_json::to_value(&value).expect("Serializing should work");
+        let retrieved: PropertyValue<f64> =
+            serde_json::from_value(json.clone()).expect("Deserializing should work");
+
+        assert_eq!(json, json!(0.5));
+        assert_eq!(retrieved, value);
+    }
+
+    #[wasm_bindgen_test]
+    fn property_value_expression_round_trip() {
+        let value: PropertyValue<f64> = Expression::interpolate(
+            Interpolation::Linear,
+            Expression::zoom(),
+            [(0., 0.), (10., 1.)],
+        )
+        .into();
+        let json = serde_json::to_value(&value).expect("Serializing should work");
+        let retrieved: PropertyValue<f64> =
+            serde_json::from_value(json).expect("Deserializing should work");
+
+        assert_eq!(retrieved, value);
+    }
+}
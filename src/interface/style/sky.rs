@@ -0,0 +1,32 @@
+//! Submodule for `SkySpecification`
+
+use serde::{Deserialize, Serialize};
+
+use super::{Color, PropertyValue};
+
+/// Sky, horizon and fog rendered when the map is pitched
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct SkySpecification {
+    /// Base color of the sky
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sky_color: Option<PropertyValue<Color>>,
+    /// Base color at the horizon
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub horizon_color: Option<PropertyValue<Color>>,
+    /// Base color of the fog
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fog_color: Option<PropertyValue<Color>>,
+    /// How to blend the fog over the 3D terrain, between `0` and `1`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fog_ground_blend: Option<PropertyValue<f64>>,
+    /// How to blend the fog color and the horizon color, between `0` and `1`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub horizon_fog_blend: Option<PropertyValue<f64>>,
+    /// How to blend the sky color and the horizon color, between `0` and `1`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sky_horizon_blend: Option<PropertyValue<f64>>,
+    /// How to blend the atmosphere, between `0` and `1`. Only used with the globe projection
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub atmosphere_blend: Option<PropertyValue<f64>>,
+}
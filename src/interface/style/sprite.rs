@@ -0,0 +1,41 @@
+//! Submodule for `SpriteSpecification`
+
+use serde::{Deserialize, Serialize};
+
+/// Location of the sprites used by the style
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SpriteSpecification {
+    /// Base URL of a single sprite sheet
+    Url(String),
+    /// Multiple sprite sheets, each referenced from the style with its prefix
+    Multiple(Vec<SpriteSheet>),
+}
+
+/// Sprite sheet with an identifier to reference its images from the style
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SpriteSheet {
+    /// Identifier of the sprite sheet, used as the prefix of its images
+    pub id: String,
+    /// Base URL of the sprite sheet
+    pub url: String,
+}
+
+impl From<&str> for SpriteSpecification {
+    fn from(value: &str) -> Self {
+        Self::Url(value.to_string())
+    }
+}
+
+impl From<String> for SpriteSpecification {
+    fn from(value: String) -> Self {
+        Self::Url(value)
+    }
+}
+
+impl From<Vec<SpriteSheet>> for SpriteSpecification {
+    fn from(value: Vec<SpriteSheet>) -> Self {
+        Self::Multiple(value)
+    }
+}
@@ -0,0 +1,24 @@
+//! Submodule for `TerrainSpecification`
+
+use serde::{Deserialize, Serialize};
+
+/// 3D terrain rendered from a `raster-dem` source
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TerrainSpecification {
+    /// Identifier of the `raster-dem` source providing the elevation
+    pub source: String,
+    /// Exaggeration of the elevation. Defaults to `1`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exaggeration: Option<f64>,
+}
+
+impl TerrainSpecification {
+    /// Create a `TerrainSpecification` using the `raster-dem` source with the given identifier
+    pub fn new(source: impl Into<String>) -> Self {
+        Self {
+            source: source.into(),
+            exaggeration: None,
+        }
+    }
+}
@@ -0,0 +1,15 @@
+//! Submodule for `TransitionSpecification`
+
+use serde::{Deserialize, Serialize};
+
+/// Timing of the transitions between values of the paint properties
+#[derive(Debug, PartialEq, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TransitionSpecification {
+    /// Duration of the transition in milliseconds. Defaults to `300`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<f64>,
+    /// Delay in milliseconds before the transition starts. Defaults to `0`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delay: Option<f64>,
+}
@@ -0,0 +1,623 @@
+//! Submodule for `validate_style` and associated types and tests
+
+use std::{collections::HashSet, fmt::Display};
+
+use serde::{
+    Deserializer,
+    de::{DeserializeOwned, Error as _, Visitor, value::Error as FieldNamesError},
+};
+use serde_json::{Map as JsonObject, Value};
+
+use super::{Expression, STYLE_SPECIFICATION_VERSION};
+use crate::interface::layers::{
+    BackgroundLayerSpecification, BackgroundLayout, BackgroundPaint, CircleLayerSpecification,
+    CircleLayout, CirclePaint, ColorReliefLayerSpecification, ColorReliefLayout, ColorReliefPaint,
+    FillExtrusionLayerSpecification, FillExtrusionLayout, FillExtrusionPaint,
+    FillLayerSpecification, FillLayout, FillPaint, HeatmapLayerSpecification, HeatmapLayout,
+    HeatmapPaint, HillshadeLayerSpecification, HillshadeLayout, HillshadePaint,
+    LineLayerSpecification, LineLayout, LinePaint, RasterLayerSpecification, RasterLayout,
+    RasterPaint, SymbolLayerSpecification, SymbolLayout, SymbolPaint,
+};
+
+/// Types of source supported by MapLibre
+const SOURCE_TYPES: &[&str] = &[
+    "vector",
+    "raster",
+    "raster-dem",
+    "geojson",
+    "image",
+    "video",
+    "canvas",
+];
+
+/// Error found while validating a style, located by the JSON path of the offending value such
+/// as `layers[2].paint.fill-color`
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct StyleValidationError {
+    /// JSON path of the offending value, empty for the root of the style
+    path: String,
+    /// Description of the error
+    message: String,
+}
+
+impl StyleValidationError {
+    /// Create a `StyleValidationError` for the value at the given path
+    pub(crate) fn new(path: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            message: message.into(),
+        }
+    }
+
+    /// Get the JSON path of the offending value, empty for the root of the style
+    #[must_use]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Get the description of the error
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for StyleValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.path, self.message)
+        }
+    }
+}
+
+impl std::error::Error for StyleValidationError {}
+
+/// Validate a style against the MapLibre Style Specification without needing a browser.
+///
+/// Checks the version, the types of the sources, that the layers have unique identifiers,
+/// only the fields of their type holding legal values, and reference existing sources of a
+/// suitable type, and that the layout and paint properties exist for the type of each layer
+/// and hold a literal of the right type or an expression.
+/// Expressions themselves are not evaluated. Returns every error found, so an empty list means
+/// that the style is valid.
+#[must_use]
+pub fn validate_style(style: &Value) -> Vec<StyleValidationError> {
+    let mut errors = Vec::new();
+    let Some(style) = style.as_object() else {
+        errors.push(StyleValidationError::new("", "style must be an object"));
+        return errors;
+    };
+
+    if style.get("version").and_then(Value::as_u64) != Some(STYLE_SPECIFICATION_VERSION.into()) {
+        errors.push(StyleValidationError::new(
+            "version",
+            format!("version must be {STYLE_SPECIFICATION_VERSION}"),
+        ));
+    }
+
+    let empty_sources = JsonObject::new();
+    let sources = match style.get("sources") {
+        Some(Value::Object(sources)) => sources,
+        Some(_) => {
+            errors.push(StyleValidationError::new(
+                "sources",
+                "sources must be an object",
+            ));
+            &empty_sources
+        }
+        None => {
+            errors.push(StyleValidationError::new(
+                "sources",
+                "missing required property",
+            ));
+            &empty_sources
+        }
+    };
+    for (id, source) in sources {
+        validate_source(id, source, &mut errors);
+    }
+
+    match style.get("layers") {
+        Some(Value::Array(layers)) => {
+            let mut ids = HashSet::new();
+            for (index, layer) in layers.iter().enumerate() {
+                validate_layer(index, layer, sources, &mut ids, &mut errors);
+            }
+        }
+        Some(_) => errors.push(StyleValidationError::new(
+            "layers",
+            "layers must be an array",
+        )),
+        None => errors.push(StyleValidationError::new(
+            "layers",
+            "missing required property",
+        )),
+    }
+
+    errors
+}
+
+/// Validate the source with the given identifier
+fn validate_source(id: &str, source: &Value, errors: &mut Vec<StyleValidationError>) {
+    let path = format!("sources.{id}");
+    match source.get("type") {
+        Some(Value::String(source_type)) if SOURCE_TYPES.contains(&source_type.as_str()) => {}
+        Some(Value::String(source_type)) => errors.push(StyleValidationError::new(
+            format!("{path}.type"),
+            format!("unknown source type \"{source_type}\""),
+        )),
+        Some(_) => errors.push(StyleValidationError::new(
+            format!("{path}.type"),
+            "source type must be a string",
+        )),
+        None if source.is_object() => errors.push(StyleValidationError::new(
+            format!("{path}.type"),
+            "missing required property",
+        )),
+        None => errors.push(StyleValidationError::new(path, "source must be an object")),
+    }
+}
+
+/// Validate the layer at the given index of the layers of the style
+fn validate_layer(
+    index: usize,
+    layer: &Value,
+    sources: &JsonObject<String, Value>,
+    ids: &mut HashSet<String>,
+    errors: &mut Vec<StyleValidationError>,
+) {
+    let path = format!("layers[{index}]");
+    let Some(layer) = layer.as_object() else {
+        errors.push(StyleValidationError::new(path, "layer must be an object"));
+        return;
+    };
+
+    match layer.get("id") {
+        Some(Value::String(id)) => {
+            if !ids.insert(id.clone()) {
+                errors.push(StyleValidationError::new(
+                    format!("{path}.id"),
+                    format!("duplicate layer id \"{id}\""),
+                ));
+            }
+        }
+        Some(_) => errors.push(StyleValidationError::new(
+            format!("{path}.id"),
+            "layer id must be a string",
+        )),
+        None => errors.push(StyleValidationError::new(
+            format!("{path}.id"),
+            "missing required property",
+        )),
+    }
+
+    let rules = match layer.get("type") {
+        Some(Value::String(layer_type)) => {
+            let rules = LayerRules::of(layer_type);
+            if rules.is_none() {
+                errors.push(StyleValidationError::new(
+                    format!("{path}.type"),
+                    format!("unknown layer type \"{layer_type}\""),
+                ));
+            }
+            rules
+        }
+        Some(_) => {
+            errors.push(StyleValidationError::new(
+                format!("{path}.type"),
+                "layer type must be a string",
+            ));
+            None
+        }
+        None => {
+            errors.push(StyleValidationError::new(
+                format!("{path}.type"),
+                "missing required property",
+            ));
+            None
+        }
+    };
+    let Some(rules) = rules else {
+        return;
+    };
+
+    validate_layer_fields(&path, layer, rules.fields, errors);
+    if let Some(source_types) = rules.source_types {
+        validate_layer_source(&path, layer, sources, source_types, errors);
+    }
+    if let Some(layout) = layer.get("layout") {
+        (rules.layout)(layout, &format!("{path}.layout"), errors);
+    }
+    if let Some(paint) = layer.get("paint") {
+        (rules.paint)(paint, &format!("{path}.paint"), errors);
+    }
+}
+
+/// Validate that the layer only has the given fields besides its type, and that its zoom levels,
+/// source layer and filter hold legal values
+fn validate_layer_fields(
+    path: &str,
+    layer: &JsonObject<String, Value>,
+    fields: &[&str],
+    errors: &mut Vec<StyleValidationError>,
+) {
+    // Vector like layers have every field that a layer can have
+    let all_fields = field_names::<FillLayerSpecification>();
+    for (name, value) in layer {
+        if name == "type" {
+            continue;
+        }
+        if !fields.contains(&name.as_str()) {
+            let message = if all_fields.contains(&name.as_str()) {
+                format!("property \"{name}\" not allowed for this layer type")
+            } else {
+                format!("unknown property \"{name}\"")
+            };
+            errors.push(StyleValidationError::new(format!("{path}.{name}"), message));
+            continue;
+        }
+
+        let valid = match name.as_str() {
+            "minzoom" | "maxzoom" => value
+                .as_f64()
+                .is_some_and(|zoom| (0.0..=24.0).contains(&zoom)),
+            "source-layer" => value.is_string(),
+            "filter" => serde_json::from_value::<Expression<bool>>(value.clone()).is_ok(),
+            _ => true,
+        };
+        if !valid {
+            errors.push(StyleValidationError::new(
+                format!("{path}.{name}"),
+                format!("invalid value {value} for property \"{name}\""),
+            ));
+        }
+    }
+}
+
+/// Validate that the source referenced by the layer exists and has one of the given types
+fn validate_layer_source(
+    path: &str,
+    layer: &JsonObject<String, Value>,
+    sources: &JsonObject<String, Value>,
+    source_types: &[&str],
+    errors: &mut Vec<StyleValidationError>,
+) {
+    let path = format!("{path}.source");
+    let source_id = match layer.get("source") {
+        Some(Value::String(source_id)) => source_id,
+        Some(_) => {
+            errors.push(StyleValidationError::new(path, "source must be a string"));
+            return;
+        }
+        None => {
+            errors.push(StyleValidationError::new(path, "missing required property"));
+            return;
+        }
+    };
+
+    let Some(source) = sources.get(source_id) else {
+        errors.push(StyleValidationError::new(
+            path,
+            format!("source \"{source_id}\" not found"),
+        ));
+        return;
+    };
+
+    if let Some(source_type) = source.get("type").and_then(Value::as_str)
+        && SOURCE_TYPES.contains(&source_type)
+        && !source_types.contains(&source_type)
+    {
+        errors.push(StyleValidationError::new(
+            path,
+            format!(
+                "layer requires a source of type {}, but \"{source_id}\" is of type \"{source_type}\"",
+                source_types.join(" or ")
+            ),
+        ));
+    }
+}
+
+/// Check of the layout or paint properties of a layer, given the properties and their path
+type PropertiesCheck = fn(&Value, &str, &mut Vec<StyleValidationError>);
+
+/// Checks specific to a type of layer
+struct LayerRules {
+    /// Check of the layout properties
+    layout: PropertiesCheck,
+    /// Check of the paint properties
+    paint: PropertiesCheck,
+    /// Types of source the layer can draw, or `None` if the layer doesn't have a source
+    source_types: Option<&'static [&'static str]>,
+    /// Serialized names of the fields of the layer, except its type
+    fields: &'static [&'static str],
+}
+
+impl LayerRules {
+    /// Get the rules of the layer type with the given name, if it exists
+    fn of(layer_type: &str) -> Option<Self> {
+        /// Source types of layers drawing features
+        const VECTOR: Option<&[&str]> = Some(&["vector", "geojson"]);
+        /// Source types of layers drawing raster images
+        const RASTER: Option<&[&str]> = Some(&["raster", "image", "video", "canvas"]);
+        /// Source types of layers drawing elevation
+        const RASTER_DEM: Option<&[&str]> = Some(&["raster-dem"]);
+
+        let (layout, paint, source_types, fields): (PropertiesCheck, PropertiesCheck, _, _) =
+            match layer_type {
+                "background" => (
+                    check_properties::<BackgroundLayout>,
+                    check_properties::<BackgroundPaint>,
+                    None,
+                    field_names::<BackgroundLayerSpecification>(),
+                ),
+                "fill" => (
+                    check_properties::<FillLayout>,
+                    check_properties::<FillPaint>,
+                    VECTOR,
+                    field_names::<FillLayerSpecification>(),
+                ),
+                "line" => (
+                    check_properties::<LineLayout>,
+                    check_properties::<LinePaint>,
+                    VECTOR,
+                    field_names::<LineLayerSpecification>(),
+                ),
+                "symbol" => (
+                    check_properties::<SymbolLayout>,
+                    check_properties::<SymbolPaint>,
+                    VECTOR,
+                    field_names::<SymbolLayerSpecification>(),
+                ),
+                "circle" => (
+                    check_properties::<CircleLayout>,
+                    check_properties::<CirclePaint>,
+                    VECTOR,
+                    field_names::<CircleLayerSpecification>(),
+                ),
+                "heatmap" => (
+                    check_properties::<HeatmapLayout>,
+                    check_properties::<HeatmapPaint>,
+                    VECTOR,
+                    field_names::<HeatmapLayerSpecification>(),
+                ),
+                "fill-extrusion" => (
+                    check_properties::<FillExtrusionLayout>,
+                    check_properties::<FillExtrusionPaint>,
+                    VECTOR,
+                    field_names::<FillExtrusionLayerSpecification>(),
+                ),
+                "raster" => (
+                    check_properties::<RasterLayout>,
+                    check_properties::<RasterPaint>,
+                    RASTER,
+                    field_names::<RasterLayerSpecification>(),
+                ),
+                "hillshade" => (
+                    check_properties::<HillshadeLayout>,
+                    check_properties::<HillshadePaint>,
+                    RASTER_DEM,
+                    field_names::<HillshadeLayerSpecification>(),
+                ),
+                "color-relief" => (
+                    check_properties::<ColorReliefLayout>,
+                    check_properties::<ColorReliefPaint>,
+                    RASTER_DEM,
+                    field_names::<ColorReliefLayerSpecification>(),
+                ),
+                _ => return None,
+            };
+
+        Some(Self {
+            layout,
+            paint,
+            source_types,
+            fields,
+        })
+    }
+}
+
+/// Check that every property exists in the typed properties `P` and holds a legal value
+fn check_properties<P: DeserializeOwned>(
+    properties: &Value,
+    path: &str,
+    errors: &mut Vec<StyleValidationError>,
+) {
+    let Some(properties) = properties.as_object() else {
+        errors.push(StyleValidationError::new(
+            path,
+            "properties must be an object",
+        ));
+        return;
+    };
+
+    let names = field_names::<P>();
+    for (name, value) in properties {
+        if !names.contains(&name.as_str()) {
+            errors.push(StyleValidationError::new(
+                format!("{path}.{name}"),
+                format!("unknown property \"{name}\""),
+            ));
+            continue;
+        }
+
+        let single = Value::Object(JsonObject::from_iter([(name.clone(), value.clone())]));
+        if serde_json::from_value::<P>(single).is_err() {
+            errors.push(StyleValidationError::new(
+                format!("{path}.{name}"),
+                format!("invalid value {value} for property \"{name}\""),
+            ));
+        }
+    }
+}
+
+/// Get the serialized names of the fields of the struct `T`
+fn field_names<T: DeserializeOwned>() -> &'static [&'static str] {
+    let mut names: &'static [&'static str] = &[];
+    // Deserializing always fails after recording the names
+    let _ = T::deserialize(FieldNames(&mut names));
+    names
+}
+
+/// Deserializer recording the names of the fields of the struct deserialized with it
+struct FieldNames<'a>(&'a mut &'static [&'static str]);
+
+impl<'de> Deserializer<'de> for FieldNames<'_> {
+    type Error = FieldNamesError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Self::Error> {
+        Err(Self::Error::custom("only structs have field names"))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        _visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        *self.0 = fields;
+        Err(Self::Error::custom("field names recorded"))
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+        option unit unit_struct newtype_struct seq tuple tuple_struct map enum identifier
+        ignored_any
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    /// Get the paths of the errors found in the style
+    fn error_paths(style: &Value) -> Vec<String> {
+        validate_style(style)
+            .iter()
+            .map(|error| error.path().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn validate_valid_style() {
+        let style = json!({
+            "version": 8,
+            "sources": {
+                "osm": {"type": "vector", "url": "https://example.com/tiles.json"},
+                "satellite": {"type": "raster", "tiles": ["https://example.com/{z}/{x}/{y}.png"]},
+            },
+            "layers": [
+                {"id": "background", "type": "background", "paint": {"background-color": "#fff"}},
+                {"id": "imagery", "type": "raster", "source": "satellite",
+                 "paint": {"raster-opacity": 0.8}},
+                {"id": "roads", "type": "line", "source": "osm", "source-layer": "roads",
+                 "filter": ["==", ["get", "class"], "primary"],
+                 "layout": {"line-cap": "round", "visibility": "visible"},
+                 "paint": {"line-width": ["interpolate", ["linear"], ["zoom"], 5, 1, 15, 4],
+                           "line-dasharray": [2, 1]}},
+                {"id": "labels", "type": "symbol", "source": "osm", "source-layer": "places",
+                 "layout": {"text-field": ["get", "name"], "text-font": ["Noto Sans Regular"]}},
+            ],
+        });
+
+        assert_eq!(validate_style(&style), vec![]);
+    }
+
+    #[test]
+    fn validate_style_root() {
+        assert_eq!(error_paths(&json!([])), vec![""]);
+        assert_eq!(
+            error_paths(&json!({"version": 7})),
+            vec!["version", "sources", "layers"]
+        );
+    }
+
+    #[test]
+    fn validate_style_sources() {
+        let style = json!({
+            "version": 8,
+            "sources": {
+                "osm": {"type": "vectors"},
+                "dem": {"type": "raster-dem"},
+                "points": {"data": []},
+            },
+            "layers": [
+                {"id": "missing", "type": "circle", "source": "nowhere"},
+                {"id": "mismatch", "type": "fill", "source": "dem"},
+                {"id": "relief", "type": "hillshade", "source": "dem"},
+                {"id": "sourceless", "type": "line"},
+            ],
+        });
+        let errors = validate_style(&style);
+
+        assert_eq!(
+            errors
+                .iter()
+                .map(StyleValidationError::path)
+                .collect::<Vec<_>>(),
+            vec![
+                "sources.osm.type",
+                "sources.points.type",
+                "layers[0].source",
+                "layers[1].source",
+                "layers[3].source",
+            ]
+        );
+        assert_eq!(
+            errors[2].to_string(),
+            "layers[0].source: source \"nowhere\" not found"
+        );
+    }
+
+    #[test]
+    fn validate_style_layers() {
+        let style = json!({
+            "version": 8,
+            "sources": {"points": {"type": "geojson", "data": null}},
+            "layers": [
+                {"id": "points", "type": "circle", "source": "points",
+                 "layout": {"circle-radius": 5},
+                 "paint": {"circle-radius": "big", "circle-color": ["get", "color"],
+                           "circle-stroke-width": 1}},
+                {"id": "points", "type": "heatmap", "source": "points",
+                 "layout": {"visibility": "hidden"}},
+                {"id": "extruded", "type": "extrusion", "source": "points"},
+                {"type": "background"},
+                {"id": "typo", "type": "fill", "sorce": "points", "filtr": ["has", "name"]},
+                {"id": "sky", "type": "background", "source": "points", "filter": ["has", "name"]},
+                {"id": "zoomed", "type": "circle", "source": "points", "source-layer": 1,
+                 "filter": "all", "minzoom": -1, "maxzoom": "high"},
+                {"id": "overlay", "type": "raster", "source": "points", "filter": ["has", "name"]},
+            ],
+        });
+
+        assert_eq!(
+            error_paths(&style),
+            vec![
+                "layers[0].layout.circle-radius",
+                "layers[0].paint.circle-radius",
+                "layers[1].id",
+                "layers[1].layout.visibility",
+                "layers[2].type",
+                "layers[3].id",
+                "layers[4].filtr",
+                "layers[4].sorce",
+                "layers[4].source",
+                "layers[5].filter",
+                "layers[5].source",
+                "layers[6].filter",
+                "layers[6].maxzoom",
+                "layers[6].minzoom",
+                "layers[6].source-layer",
+                "layers[7].filter",
+                "layers[7].source",
+            ]
+        );
+        assert_eq!(
+            validate_style(&style)[10].to_string(),
+            "layers[5].source: property \"source\" not allowed for this layer type"
+        );
+    }
+}
@@ -0,0 +1,179 @@
+//! Rusty interface to the `transformRequest` callback of MapLibre GL JS
+
+use std::rc::Rc;
+
+use serde::{Serialize, Serializer};
+use wasm_bindgen::prelude::*;
+use web_sys::js_sys::Function;
+
+use super::{RequestParameters, ResourceType};
+
+/// Callback run before a `Map` requests a resource, returning the parameters of the request to
+/// make instead, or `None` to make the original request. Useful to add authentication headers
+/// or to rewrite URLs.
+///
+/// The callback is freed once the last clone of the `TransformRequest` is dropped, which the
+/// `Map` using it keeps alive.
+///
+/// MapLibre docs: <https://maplibre.org/maplibre-gl-js/docs/API/type-aliases/RequestTransformFunction/>
+#[derive(Debug, Clone)]
+pub struct TransformRequest {
+    /// Closure wrapping the Rust callback
+    closure: Rc<Closure<dyn Fn(String, JsValue) -> JsValue>>,
+}
+
+impl TransformRequest {
+    /// Create a `TransformRequest` from a callback receiving the URL and the type of the
+    /// requested resource.
+    ///
+    /// If the returned parameters can't be converted into JS, the error is logged to the console
+    /// and the original request is made
+    pub fn new(
+        transform: impl Fn(&str, ResourceType) -> Option<RequestParameters> + 'static,
+    ) -> Self {
+        let closure = Closure::<dyn Fn(String, JsValue) -> JsValue>::new(
+            move |url: String, resource_type: JsValue| {
+                let resource_type =
+                    serde_wasm_bindgen::from_value(resource_type).unwrap_or(ResourceType::Unknown);
+                let Some(parameters) = transform(&url, resource_type) else {
+                    return JsValue::UNDEFINED;
+                };
+                parameters.as_js_value().unwrap_or_else(|error| {
+                    web_sys::console::error_1(
+                        &format!("Failed to convert the transformed request of {url}: {error}")
+                            .into(),
+                    );
+                    JsValue::UNDEFINED
+                })
+            },
+        );
+        Self {
+            closure: Rc::new(closure),
+        }
+    }
+
+    /// Get the JS function wrapping the callback
+    #[must_use]
+    pub fn as_function(&self) -> &Function {
+        self.closure.as_ref().as_ref().unchecked_ref()
+    }
+}
+
+impl PartialEq for TransformRequest {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.closure, &other.closure)
+    }
+}
+
+impl Eq for TransformRequest {}
+
+impl<F: Fn(&str, ResourceType) -> Option<RequestParameters> + 'static> From<F>
+    for TransformRequest
+{
+    fn from(value: F) -> Self {
+        Self::new(value)
+    }
+}
+
+impl Serialize for TransformRequest {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde_wasm_bindgen::preserve::serialize(self.as_function(), serializer)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        interface::{MapOptions, add_protocol},
+        test_utils::{gen_html_element, load_maplibre_gl},
+    };
+    use serde_json::json;
+    use std::{cell::RefCell, rc::Rc};
+    use wasm_bindgen_futures::JsFuture;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    async fn transform_request_rewrites_requests() {
+        load_maplibre_gl().await;
+        let _protocol = add_protocol(
+            "transform-test",
+            |parameters: RequestParameters| async move {
+                let authorized = parameters
+                    .headers
+                    .is_some_and(|headers| headers.contains_key("Authorization"));
+                if authorized {
+                    Ok(json!({"version": 8, "sources": {}, "layers": []}))
+                } else {
+                    Err(crate::interface::Error::new("Unauthorized"))
+                }
+            },
+        );
+        let requests = Rc::new(RefCell::new(Vec::new()));
+        let html_element = gen_html_element("div").cloned_ref();
+        let map = MapOptions::new(html_element)
+            .with_style("transform-test://style")
+            .with_transform_request(TransformRequest::new({
+                let requests = Rc::clone(&requests);
+                move |url, resource_type| {
+                    requests.borrow_mut().push((url.to_string(), resource_type));
+                    Some(RequestParameters::new(url).with_header("Authorization", "Bearer first"))
+                }
+            }))
+            .build_async()
+            .await
+            .expect("The map should load the style with the transformed request");
+
+        assert_eq!(
+            requests.borrow()[0],
+            ("transform-test://style".to_string(), ResourceType::Style)
+        );
+
+        let refreshed = Rc::new(RefCell::new(Vec::new()));
+        map.set_transform_request({
+            let refreshed = Rc::clone(&refreshed);
+            move |url: &str, resource_type| {
+                refreshed.borrow_mut().push(resource_type);
+                (resource_type == ResourceType::Source).then(|| {
+                    RequestParameters::new(url).with_header("Authorization", "Bearer second")
+                })
+            }
+        });
+        map.add_source(
+            "points",
+            &json!({"type": "geojson", "data": "transform-test://points"}),
+        )
+        .expect("Adding a source should work");
+        map.add_layer(
+            &json!({"id": "points", "type": "circle", "source": "points"}),
+            None,
+        )
+        .expect("Adding a layer should work");
+        let _ = JsFuture::from(map.clone().into_inner().once_promise("idle")).await;
+
+        assert!(refreshed.borrow().contains(&ResourceType::Source));
+    }
+
+    #[wasm_bindgen_test]
+    async fn transform_request_keeps_original_request() {
+        load_maplibre_gl().await;
+        let requested_urls = Rc::new(RefCell::new(Vec::new()));
+        let _protocol = add_protocol("transform-keep", {
+            let requested_urls = Rc::clone(&requested_urls);
+            move |parameters: RequestParameters| {
+                requested_urls.borrow_mut().push(parameters.url);
+                async { Ok(json!({"version": 8, "sources": {}, "layers": []})) }
+            }
+        });
+        let html_element = gen_html_element("div").cloned_ref();
+        let map = MapOptions::new(html_element)
+            .with_style("transform-keep://style")
+            .with_transform_request(TransformRequest::new(|_, _| None))
+            .build_async()
+            .await
+            .expect("The map should load the style with the original request");
+
+        assert!(map.loaded());
+        assert_eq!(*requested_urls.borrow(), vec!["transform-keep://style"]);
+    }
+}
@@ -64,6 +64,23 @@ pub fn gen_html_element<'a>(tag: &str) -> HtmlElementRAII<'a> {
     }
 }
 
+/// Insert a `div` element with a fixed size in pixels, so that a map using it as container
+/// renders its features.
+///
+/// # Panics
+///
+/// This function is only intended to run on tests, errors aren't handled and
+/// panics whenever anything goes wrong.
+#[must_use]
+pub fn gen_sized_container<'a>(width: u32, height: u32) -> HtmlElementRAII<'a> {
+    let element = gen_html_element("div");
+    element
+        .html_element
+        .set_attribute("style", &format!("width: {width}px; height: {height}px"))
+        .expect("Setting the style should work");
+    element
+}
+
 /// Insert an arbitrary HTML element with a given tag and id.
 ///
 /// This function assumes that the document and the body are loaded and
@@ -87,7 +87,7 @@ impl Preprocessor for ExamplesPreprocessor {
 }
 
 fn make_append_example(parent: &mut Chapter, index: u32, path: PathBuf) {
-    let (item, snippet) = make_example(&parent, index, path);
+    let (item, snippet) = make_example(parent, index, path);
     parent.sub_items.push(item);
     parent.content += &snippet;
 }
@@ -109,14 +109,7 @@ async fn execute(input: &Path, output: &Path) {
     .await
     .expect("Launch browser should work");
 
-    let handle = tokio::task::spawn(async move {
-        loop {
-            match handler.next().await {
-                Some(_) => {}
-                None => break,
-            };
-        }
-    });
+    let handle = tokio::task::spawn(async move { while handler.next().await.is_some() {} });
 
     let page = browser.new_page(url).await.expect("Page should load");
     tokio::time::sleep(Duration::from_millis(5000)).await;